@@ -0,0 +1,58 @@
+//! topic0 dispatch table: route each log to the decoders that handle its event instead of running
+//! every decoder on every log.
+//!
+//! Generated `substreams-abis` events only expose `match_log`, so the table is filled lazily: the
+//! first log of each shape (topic0, topic count, data length) is matched against every event once,
+//! and later logs of the same shape reuse the result. `match_log` only depends on that shape, so a
+//! lookup returns exactly the decoders whose events match the log.
+
+use std::collections::HashMap;
+
+use substreams_ethereum::pb::eth::v2::Log;
+
+/// `match_log` of a generated event (e.g. `abi::pair::events::Swap::match_log`).
+pub type LogMatcher = fn(&Log) -> bool;
+
+type Shape = ([u8; 32], usize, usize);
+
+pub struct TopicDispatch<D> {
+    events: Vec<(LogMatcher, D)>,
+    table: HashMap<Shape, Vec<D>>,
+}
+
+impl<D: Copy + PartialEq> TopicDispatch<D> {
+    /// `decoders` lists each decoder with the events it handles, in decoding order.
+    pub fn new(decoders: &[(D, &[LogMatcher])]) -> Self {
+        let events = decoders
+            .iter()
+            .flat_map(|(decoder, events)| events.iter().map(move |event| (*event, *decoder)))
+            .collect();
+        TopicDispatch { events, table: HashMap::new() }
+    }
+
+    /// Decoders handling `log`, in decoding order (empty for logs no decoder handles).
+    pub fn decoders(&mut self, log: &Log) -> &[D] {
+        let Some(topic0) = log.topics.first().and_then(|topic| <[u8; 32]>::try_from(topic.as_slice()).ok()) else {
+            return &[];
+        };
+        let events = &self.events;
+        self.table.entry((topic0, log.topics.len(), log.data.len())).or_insert_with(|| {
+            let mut decoders = Vec::new();
+            for (matches, decoder) in events {
+                if matches(log) && !decoders.contains(decoder) {
+                    decoders.push(*decoder);
+                }
+            }
+            decoders
+        })
+    }
+
+    /// Number of distinct log shapes seen so far.
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+}
@@ -0,0 +1,88 @@
+//! Factory allowlist / denylist for DEX pool-creation events.
+//!
+//! Anyone can deploy a contract emitting `PairCreated`/`PoolCreated`-shaped events, so pool
+//! stores filter pools on the factory that created them. Params are `&`-separated `key=value` pairs:
+//!   - `network=<name>`     allow the factories of that network in the `projects` registry
+//!   - `allow=<address>`    allowed factory (repeatable, or comma-separated)
+//!   - `deny=<address>`     denied factory, always dropped (repeatable, or comma-separated)
+//!   - `unlisted=drop|tag`  drop pools of unlisted factories (default), or keep them with `verified = false`
+//!
+//! Without any allowed factory the allowlist is disabled: every factory that is not denied is
+//! kept and `verified` stays unset. Pools without a factory (CurveFi direct deployments) are unlisted.
+
+use std::collections::HashSet;
+
+use substreams::Hex;
+
+use crate::projects;
+
+/// What to do with a pool, given its factory.
+#[derive(Debug, PartialEq)]
+pub enum FactoryCheck {
+    Drop,
+    /// `verified` is `None` when no allowlist is configured.
+    Keep {
+        verified: Option<bool>,
+    },
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct FactoryFilter {
+    pub allow: HashSet<Vec<u8>>,
+    pub deny: HashSet<Vec<u8>>,
+    /// Keep pools of unlisted factories with `verified = false` instead of dropping them.
+    pub tag_unlisted: bool,
+}
+
+impl FactoryFilter {
+    pub fn from_params(params: &str) -> Self {
+        let mut filter = FactoryFilter::default();
+
+        for (key, value) in params.split('&').filter(|pair| !pair.is_empty()).map(|pair| {
+            pair.split_once('=')
+                .unwrap_or_else(|| panic!("Invalid factory parameter `{}`, expected key=value", pair))
+        }) {
+            match key {
+                "network" => {
+                    let Some(factories) = projects::network_factories(value) else {
+                        panic!("Unsupported factory allowlist network `{}`", value);
+                    };
+                    filter.allow.extend(factories.iter().map(|(factory, _, _)| parse_address(factory)));
+                }
+                "allow" => filter.allow.extend(value.split(',').map(parse_address)),
+                "deny" => filter.deny.extend(value.split(',').map(parse_address)),
+                "unlisted" => {
+                    filter.tag_unlisted = match value {
+                        "drop" => false,
+                        "tag" => true,
+                        _ => panic!("Invalid unlisted `{}`, expected drop or tag", value),
+                    }
+                }
+                _ => panic!("Unknown factory parameter `{}`, supported: network, allow, deny, unlisted", key),
+            }
+        }
+
+        filter
+    }
+
+    pub fn check(&self, factory: &[u8]) -> FactoryCheck {
+        if self.deny.contains(factory) {
+            return FactoryCheck::Drop;
+        }
+        if self.allow.is_empty() {
+            return FactoryCheck::Keep { verified: None };
+        }
+        match (self.allow.contains(factory), self.tag_unlisted) {
+            (true, _) => FactoryCheck::Keep { verified: Some(true) },
+            (false, true) => FactoryCheck::Keep { verified: Some(false) },
+            (false, false) => FactoryCheck::Drop,
+        }
+    }
+}
+
+fn parse_address(address: &str) -> Vec<u8> {
+    match Hex::decode(address.trim().trim_start_matches("0x")) {
+        Ok(bytes) if bytes.len() == 20 => bytes,
+        _ => panic!("Invalid factory address `{}`", address),
+    }
+}
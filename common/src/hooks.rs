@@ -0,0 +1,59 @@
+//! Uniswap V4 hook permissions.
+//!
+//! A hooks contract is deployed to an address whose lowest 14 bits declare which callbacks the
+//! PoolManager invokes (`Hooks.sol`), so permissions are read from the address alone.
+
+use proto::pb::dex::foundational_store::v1::HookPermissions;
+
+pub const BEFORE_INITIALIZE: u32 = 1 << 13;
+pub const AFTER_INITIALIZE: u32 = 1 << 12;
+pub const BEFORE_ADD_LIQUIDITY: u32 = 1 << 11;
+pub const AFTER_ADD_LIQUIDITY: u32 = 1 << 10;
+pub const BEFORE_REMOVE_LIQUIDITY: u32 = 1 << 9;
+pub const AFTER_REMOVE_LIQUIDITY: u32 = 1 << 8;
+pub const BEFORE_SWAP: u32 = 1 << 7;
+pub const AFTER_SWAP: u32 = 1 << 6;
+pub const BEFORE_DONATE: u32 = 1 << 5;
+pub const AFTER_DONATE: u32 = 1 << 4;
+pub const BEFORE_SWAP_RETURNS_DELTA: u32 = 1 << 3;
+pub const AFTER_SWAP_RETURNS_DELTA: u32 = 1 << 2;
+pub const AFTER_ADD_LIQUIDITY_RETURNS_DELTA: u32 = 1 << 1;
+pub const AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA: u32 = 1;
+
+/// All permission bits.
+pub const ALL_HOOK_MASK: u32 = (1 << 14) - 1;
+
+/// Permission bits of a hooks address, `0` for an empty or shorter-than-2-bytes address.
+pub fn hook_flags(hooks: &[u8]) -> u32 {
+    match hooks {
+        [.., high, low] => ((u32::from(*high) << 8) | u32::from(*low)) & ALL_HOOK_MASK,
+        _ => 0,
+    }
+}
+
+/// Decoded permissions of a hooks address.
+pub fn hook_permissions(hooks: &[u8]) -> HookPermissions {
+    let flags = hook_flags(hooks);
+    let has = |flag: u32| flags & flag != 0;
+    HookPermissions {
+        before_initialize: has(BEFORE_INITIALIZE),
+        after_initialize: has(AFTER_INITIALIZE),
+        before_add_liquidity: has(BEFORE_ADD_LIQUIDITY),
+        after_add_liquidity: has(AFTER_ADD_LIQUIDITY),
+        before_remove_liquidity: has(BEFORE_REMOVE_LIQUIDITY),
+        after_remove_liquidity: has(AFTER_REMOVE_LIQUIDITY),
+        before_swap: has(BEFORE_SWAP),
+        after_swap: has(AFTER_SWAP),
+        before_donate: has(BEFORE_DONATE),
+        after_donate: has(AFTER_DONATE),
+        before_swap_returns_delta: has(BEFORE_SWAP_RETURNS_DELTA),
+        after_swap_returns_delta: has(AFTER_SWAP_RETURNS_DELTA),
+        after_add_liquidity_returns_delta: has(AFTER_ADD_LIQUIDITY_RETURNS_DELTA),
+        after_remove_liquidity_returns_delta: has(AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA),
+    }
+}
+
+/// Whether a pool has a hooks contract (non-empty, non-zero address).
+pub fn has_hooks(hooks: &[u8]) -> bool {
+    hooks.iter().any(|byte| *byte != 0)
+}
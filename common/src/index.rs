@@ -0,0 +1,42 @@
+//! Block index keys for `blockIndex` modules.
+//!
+//! Keys follow the foundational `evm_index_events` format so the same `blockFilter` queries work
+//! against either index:
+//!   - `evt_sig:0x<topic0>`  — event signature of a log
+//!   - `evt_addr:0x<address>` — contract emitting a log
+//!
+//! A map module wired with `blockFilter` only runs on blocks whose keys match its query, e.g.
+//! `evt_sig:0xdcbc...09d7 || evt_sig:0xfbde...c8db`.
+
+use std::collections::BTreeSet;
+
+use substreams::pb::sf::substreams::index::v1::Keys;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2::{Block, Log};
+
+/// `evt_sig:0x<topic0>` of a log (none for anonymous logs without topics).
+pub fn event_signature_key(log: &Log) -> Option<String> {
+    log.topics.first().map(|topic0| format!("evt_sig:0x{}", Hex::encode(topic0)))
+}
+
+/// `evt_addr:0x<address>` of a log.
+pub fn event_address_key(log: &Log) -> String {
+    format!("evt_addr:0x{}", Hex::encode(&log.address))
+}
+
+/// Index keys of the logs, sorted and deduplicated.
+pub fn log_keys<'a>(logs: impl IntoIterator<Item = &'a Log>) -> Keys {
+    let mut keys = BTreeSet::new();
+    for log in logs {
+        keys.extend(event_signature_key(log));
+        keys.insert(event_address_key(log));
+    }
+    Keys {
+        keys: keys.into_iter().collect(),
+    }
+}
+
+/// Index keys of every log of the block.
+pub fn block_keys(block: &Block) -> Keys {
+    log_keys(block.logs().map(|view| view.log))
+}
@@ -1,6 +1,12 @@
 pub mod clickhouse;
 pub mod create;
 pub mod debug;
+pub mod dispatch;
+pub mod factories;
+pub mod hooks;
+pub mod index;
+pub mod projects;
+pub mod rpc;
 use sha2::{Digest, Sha256};
 use substreams::{hex, log, scalar::BigInt, Hex};
 
@@ -132,16 +138,57 @@ pub fn bigint_to_u64(bigint: &substreams::scalar::BigInt) -> Option<u64> {
     Some(bigint.to_u64())
 }
 
-pub fn bigint_to_i32(bigint: &substreams::scalar::BigInt) -> Option<i32> {
-    if bigint.lt(&BigInt::zero()) {
-        log::info!("bigint_to_i32: value is negative");
-        return None;
+pub const I24_MIN: i32 = -(1 << 23);
+pub const I24_MAX: i32 = (1 << 23) - 1;
+
+/// Returned by the signed `bigint_to_i*` conversions when a value does not fit the target width.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigIntOverflowError {
+    pub target: &'static str,
+    pub value: String,
+}
+
+impl std::fmt::Display for BigIntOverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bigint {} does not fit into {}", self.value, self.target)
     }
-    if bigint.gt(&BigInt::from(i32::MAX)) {
-        log::info!("bigint_to_i32: value is greater than i32::MAX");
-        return None;
+}
+
+impl std::error::Error for BigIntOverflowError {}
+
+// Goes through the decimal representation so negative values keep their sign
+// regardless of the width of the underlying two's complement encoding.
+fn bigint_to_signed<T: std::str::FromStr>(bigint: &BigInt, target: &'static str) -> Result<T, BigIntOverflowError> {
+    let value = bigint.to_string();
+    value.parse::<T>().map_err(|_| {
+        log::info!("bigint_to_{}: value {} is out of range", target, value);
+        BigIntOverflowError { target, value }
+    })
+}
+
+// Used for Solidity `int24` values (Uniswap V3/V4 and Kyber ticks, tick spacings)
+pub fn bigint_to_i24(bigint: &BigInt) -> Result<i32, BigIntOverflowError> {
+    let value = bigint_to_signed::<i32>(bigint, "i24")?;
+    if !(I24_MIN..=I24_MAX).contains(&value) {
+        log::info!("bigint_to_i24: value {} is out of range", value);
+        return Err(BigIntOverflowError {
+            target: "i24",
+            value: value.to_string(),
+        });
     }
-    Some(bigint.to_i32())
+    Ok(value)
+}
+
+pub fn bigint_to_i32(bigint: &BigInt) -> Result<i32, BigIntOverflowError> {
+    bigint_to_signed(bigint, "i32")
+}
+
+pub fn bigint_to_i64(bigint: &BigInt) -> Result<i64, BigIntOverflowError> {
+    bigint_to_signed(bigint, "i64")
+}
+
+pub fn bigint_to_i128(bigint: &BigInt) -> Result<i128, BigIntOverflowError> {
+    bigint_to_signed(bigint, "i128")
 }
 
 /// Validates if an address is a valid EVM address.
@@ -0,0 +1,88 @@
+//! Factory -> (project, version) registry.
+//!
+//! `dex.swaps.v1.Protocol` identifies the event ABI, not who deployed the pool: SushiSwap,
+//! PancakeSwap, SunSwap and other forks all emit Uniswap V2/V3 events. The factory that created
+//! the pool tells them apart. Addresses are hex-encoded (lowercase, no `0x`), Tron addresses
+//! without the `41` prefix.
+
+use substreams::Hex;
+
+/// Known DEX factories per network: (network, [(factory, project, version)]).
+pub const PROJECTS: &[(&str, &[(&str, &str, &str)])] = &[
+    (
+        "mainnet",
+        &[
+            ("c0a47dfe034b400b47bdad5fecda2621de6c4d95", "uniswap", "v1"),
+            ("5c69bee701ef814a2b6a3edd4b1652cb9cc5aa6f", "uniswap", "v2"),
+            ("1f98431c8ad98523631ae4a59f267346ea31f984", "uniswap", "v3"),
+            ("000000000004444c5dc75cb358380d2e3de08a90", "uniswap", "v4"),
+            ("c0aee478e3658e2610c5f7a4a2e1777ce9e4f2ac", "sushiswap", "v2"),
+            ("1097053fd2ea711dad45caccc45eff7548fcb362", "pancakeswap", "v2"),
+            ("0bfbcf9fa4f9c56b0f40a671ad40e0805a091865", "pancakeswap", "v3"),
+            ("115934131916c8b277dd010ee02de363c09d037c", "shibaswap", "v1"),
+            ("5f1dddbf348ac2fbe22a163e30f99f9ece3dd50a", "kyberswap", "elastic"),
+            ("b9fc157394af804a3578134a6585c0dc9cc990d4", "curve", "metapool"),
+            ("f18056bbd320e96a48e3fbf8bc061322531aac99", "curve", "crypto"),
+            ("6a8cbed756804b16e05e741edabd5cb544ae21bf", "curve", "stableswap-ng"),
+            ("98ee851a00abee0d95d08cf4ca2bdce32aeaaf7f", "curve", "twocrypto-ng"),
+            ("0c0e5f2ff0ff18a3be9b835635039256dc4b4963", "curve", "tricrypto-ng"),
+        ],
+    ),
+    (
+        "base",
+        &[
+            ("8909dc15e40173ff4699343b6eb8132c65e18ec6", "uniswap", "v2"),
+            ("33128a8fc17869897dce68ed026d694621f6fdfd", "uniswap", "v3"),
+            ("498581ff718922c3f8e6a244956af099b2652b2b", "uniswap", "v4"),
+            ("0bfbcf9fa4f9c56b0f40a671ad40e0805a091865", "pancakeswap", "v3"),
+            ("420dd381b31aef6683db6b902084cb0ffece40da", "aerodrome", "v2"),
+            ("5e7bb104d84c7cb9b682aac2f3d509f5f406809a", "aerodrome", "slipstream"),
+        ],
+    ),
+    (
+        "arbitrum-one",
+        &[
+            ("f1d7cc64fb4452f05c498126312ebe29f30fbcf9", "uniswap", "v2"),
+            ("1f98431c8ad98523631ae4a59f267346ea31f984", "uniswap", "v3"),
+            ("c35dadb65012ec5796536bd9864ed8773abc74c4", "sushiswap", "v2"),
+            ("0bfbcf9fa4f9c56b0f40a671ad40e0805a091865", "pancakeswap", "v3"),
+            ("6eccab422d763ac031210895c81787e87b43a652", "camelot", "v2"),
+        ],
+    ),
+    (
+        "bsc",
+        &[
+            ("ca143ce32fe78f1f7019d7d551a6402fc5350c73", "pancakeswap", "v2"),
+            ("0bfbcf9fa4f9c56b0f40a671ad40e0805a091865", "pancakeswap", "v3"),
+            ("c35dadb65012ec5796536bd9864ed8773abc74c4", "sushiswap", "v2"),
+        ],
+    ),
+    (
+        "tron",
+        &[
+            ("eed9e56a5cddaa15ef0c42984884a8afcf1bdebb", "justswap", "v1"), // TXk8rQSAvPvBBNtqSoY6nCfsXWCSSpTVQF
+            ("689abaeeed3f0bb3585773192e23224cac25dd41", "sunswap", "v2"),  // TKWJdrQkqHisa1X8HUdHEfREvTzw4pMAaY
+        ],
+    ),
+];
+
+/// Factories registered for `network`, `None` for an unknown network.
+pub fn network_factories(network: &str) -> Option<&'static [(&'static str, &'static str, &'static str)]> {
+    PROJECTS.iter().find(|(name, _)| *name == network).map(|(_, factories)| *factories)
+}
+
+/// (project, version) of a factory on `network`.
+pub fn get_project(network: &str, factory: &[u8]) -> Option<(&'static str, &'static str)> {
+    let factory = Hex::encode(factory);
+    network_factories(network)?
+        .iter()
+        .find(|(address, _, _)| *address == factory)
+        .map(|(_, project, version)| (*project, *version))
+}
+
+/// `<project>_<version>` label of a factory on `network` (e.g. `sushiswap_v2`), empty when unknown.
+pub fn project_label(network: &str, factory: &[u8]) -> String {
+    get_project(network, factory)
+        .map(|(project, version)| format!("{}_{}", project, version))
+        .unwrap_or_default()
+}
@@ -0,0 +1,74 @@
+//! Fault-tolerant `eth_call` batches.
+//!
+//! A batch that fails (RPC error or a response count that does not match the calls) is retried,
+//! then split in half and each half retried, down to single calls. A failing or reverting
+//! contract therefore only loses its own lookups instead of halting the stream.
+
+use substreams::errors::Error;
+use substreams::log;
+use substreams_ethereum::pb::eth::rpc::RpcResponse;
+use substreams_ethereum::rpc::RpcBatch;
+
+/// Attempts per batch before it is bisected.
+pub const RPC_ATTEMPTS: usize = 3;
+
+/// `chunk_size` module param (number of calls per RPC batch).
+pub fn parse_chunk_size(params: &str) -> Result<usize, Error> {
+    match params.trim().parse::<usize>() {
+        Ok(chunk_size) if chunk_size > 0 => Ok(chunk_size),
+        _ => Err(Error::msg(format!("invalid chunk_size param `{}`, expected a positive integer", params))),
+    }
+}
+
+/// Executes `calls` in batches of `chunk_size`, retrying and bisecting failed batches.
+///
+/// Returns one result per call, in order: its response, or the last error of the single-call
+/// batch that never succeeded.
+pub fn execute_bisect<C, R>(
+    calls: &[C],
+    chunk_size: usize,
+    attempts: usize,
+    execute: &mut impl FnMut(&[C]) -> Result<Vec<R>, String>,
+) -> Vec<Result<R, String>> {
+    let mut results = Vec::with_capacity(calls.len());
+    for chunk in calls.chunks(chunk_size.max(1)) {
+        execute_chunk(chunk, attempts, execute, &mut results);
+    }
+    results
+}
+
+fn execute_chunk<C, R>(chunk: &[C], attempts: usize, execute: &mut impl FnMut(&[C]) -> Result<Vec<R>, String>, results: &mut Vec<Result<R, String>>) {
+    let mut error = String::new();
+    for _ in 0..attempts.max(1) {
+        match execute(chunk) {
+            Ok(responses) if responses.len() == chunk.len() => {
+                results.extend(responses.into_iter().map(Ok));
+                return;
+            }
+            Ok(responses) => error = format!("expected {} responses, got {}", chunk.len(), responses.len()),
+            Err(err) => error = err,
+        }
+    }
+
+    if chunk.len() == 1 {
+        results.push(Err(error));
+        return;
+    }
+    let (left, right) = chunk.split_at(chunk.len() / 2);
+    execute_chunk(left, attempts, execute, results);
+    execute_chunk(right, attempts, execute, results);
+}
+
+/// [`execute_bisect`] over `eth_call`s: `add` appends the call of one item to a batch.
+pub fn execute_batch<C>(calls: &[C], chunk_size: usize, add: impl Fn(RpcBatch, &C) -> RpcBatch) -> Vec<Result<RpcResponse, String>> {
+    let mut batches = 0;
+    let results = execute_bisect(calls, chunk_size, RPC_ATTEMPTS, &mut |chunk: &[C]| {
+        batches += 1;
+        chunk.iter().fold(RpcBatch::new(), &add).execute().map(|responses| responses.responses)
+    });
+    let failed = results.iter().filter(|result| result.is_err()).count();
+    if failed > 0 {
+        log::info!("RPC calls={} batches={} failed={}", calls.len(), batches, failed);
+    }
+    results
+}
@@ -0,0 +1,57 @@
+use common::{bigint_to_i128, bigint_to_i24, bigint_to_i32, bigint_to_i64, BigIntOverflowError, I24_MAX, I24_MIN};
+use substreams::scalar::BigInt;
+
+#[test]
+fn test_i24_keeps_negative_ticks() {
+    assert_eq!(bigint_to_i24(&BigInt::from(-887272)), Ok(-887272));
+    assert_eq!(bigint_to_i24(&BigInt::from(-1)), Ok(-1));
+    assert_eq!(bigint_to_i24(&BigInt::from(0)), Ok(0));
+    assert_eq!(bigint_to_i24(&BigInt::from(887272)), Ok(887272));
+}
+
+#[test]
+fn test_i24_bounds() {
+    assert_eq!(bigint_to_i24(&BigInt::from(I24_MIN)), Ok(I24_MIN));
+    assert_eq!(bigint_to_i24(&BigInt::from(I24_MAX)), Ok(I24_MAX));
+    assert_eq!(
+        bigint_to_i24(&BigInt::from(I24_MAX + 1)),
+        Err(BigIntOverflowError {
+            target: "i24",
+            value: "8388608".to_string()
+        })
+    );
+    assert_eq!(
+        bigint_to_i24(&BigInt::from(I24_MIN - 1)),
+        Err(BigIntOverflowError {
+            target: "i24",
+            value: "-8388609".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_i32_bounds() {
+    assert_eq!(bigint_to_i32(&BigInt::from(i32::MIN)), Ok(i32::MIN));
+    assert_eq!(bigint_to_i32(&BigInt::from(i32::MAX)), Ok(i32::MAX));
+    assert!(bigint_to_i32(&BigInt::from(u64::MAX)).is_err());
+}
+
+#[test]
+fn test_i64_from_signed_bytes() {
+    let value = BigInt::from_signed_bytes_be(&i64::MIN.to_be_bytes());
+    assert_eq!(bigint_to_i64(&value), Ok(i64::MIN));
+    assert!(bigint_to_i64(&BigInt::from(u64::MAX)).is_err());
+}
+
+#[test]
+fn test_i128_bounds() {
+    let min = BigInt::from_signed_bytes_be(&i128::MIN.to_be_bytes());
+    assert_eq!(bigint_to_i128(&min), Ok(i128::MIN));
+
+    // 17-byte two's complement value one below i128::MIN
+    let mut below_min = vec![0xff];
+    below_min.extend_from_slice(&(i128::MAX).to_be_bytes());
+    let err = bigint_to_i128(&BigInt::from_signed_bytes_be(&below_min)).unwrap_err();
+    assert_eq!(err.target, "i128");
+    assert_eq!(err.to_string(), format!("bigint {} does not fit into i128", err.value));
+}
@@ -0,0 +1,51 @@
+use std::cell::Cell;
+
+use common::dispatch::{LogMatcher, TopicDispatch};
+use substreams_ethereum::pb::eth::v2::Log;
+
+thread_local! {
+    static MATCH_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn log(topic0: u8, data_len: usize) -> Log {
+    Log {
+        topics: vec![vec![topic0; 32], vec![0; 32]],
+        data: vec![0; data_len],
+        ..Default::default()
+    }
+}
+
+fn swap(log: &Log) -> bool {
+    MATCH_CALLS.with(|calls| calls.set(calls.get() + 1));
+    log.topics[0] == [0xaa; 32] && log.data.len() == 64
+}
+
+fn mint(log: &Log) -> bool {
+    log.topics[0] == [0xbb; 32]
+}
+
+const V2: &[LogMatcher] = &[swap, mint];
+const FORK: &[LogMatcher] = &[swap];
+
+#[test]
+fn test_routes_logs_to_matching_decoders_in_order() {
+    let mut dispatch = TopicDispatch::new(&[("v2", V2), ("fork", FORK)]);
+
+    assert_eq!(dispatch.decoders(&log(0xaa, 64)), &["v2", "fork"]);
+    assert_eq!(dispatch.decoders(&log(0xbb, 32)), &["v2"]);
+    assert!(dispatch.decoders(&log(0xaa, 96)).is_empty());
+    assert!(dispatch.decoders(&Log::default()).is_empty());
+}
+
+#[test]
+fn test_matches_each_shape_once() {
+    let mut dispatch = TopicDispatch::new(&[("v2", V2), ("fork", FORK)]);
+    MATCH_CALLS.with(|calls| calls.set(0));
+
+    for _ in 0..10 {
+        dispatch.decoders(&log(0xaa, 64));
+    }
+
+    assert_eq!(MATCH_CALLS.with(Cell::get), 2);
+    assert_eq!(dispatch.len(), 1);
+}
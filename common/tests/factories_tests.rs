@@ -0,0 +1,45 @@
+use common::factories::{FactoryCheck, FactoryFilter};
+
+const UNISWAP_V2: &str = "5c69bee701ef814a2b6a3edd4b1652cb9cc5aa6f";
+const SPOOFED: &str = "00000000000000000000000000000000000000aa";
+
+fn address(hex_str: &str) -> Vec<u8> {
+    hex::decode(hex_str).unwrap()
+}
+
+#[test]
+fn test_empty_params_keep_everything_unverified() {
+    let filter = FactoryFilter::from_params("");
+    assert_eq!(filter.check(&address(SPOOFED)), FactoryCheck::Keep { verified: None });
+    assert_eq!(filter.check(&[]), FactoryCheck::Keep { verified: None });
+}
+
+#[test]
+fn test_network_allowlist_drops_unlisted() {
+    let filter = FactoryFilter::from_params("network=mainnet");
+    assert_eq!(filter.check(&address(UNISWAP_V2)), FactoryCheck::Keep { verified: Some(true) });
+    assert_eq!(filter.check(&address(SPOOFED)), FactoryCheck::Drop);
+    assert_eq!(filter.check(&[]), FactoryCheck::Drop);
+}
+
+#[test]
+fn test_unlisted_tag_keeps_unverified() {
+    let filter = FactoryFilter::from_params(&format!("allow=0x{}&unlisted=tag", UNISWAP_V2));
+    assert_eq!(filter.check(&address(UNISWAP_V2)), FactoryCheck::Keep { verified: Some(true) });
+    assert_eq!(filter.check(&address(SPOOFED)), FactoryCheck::Keep { verified: Some(false) });
+}
+
+#[test]
+fn test_deny_wins_over_allow() {
+    let filter = FactoryFilter::from_params(&format!("network=mainnet&deny={}", UNISWAP_V2));
+    assert_eq!(filter.check(&address(UNISWAP_V2)), FactoryCheck::Drop);
+
+    let filter = FactoryFilter::from_params(&format!("deny={},{}", UNISWAP_V2, SPOOFED));
+    assert_eq!(filter.check(&address(SPOOFED)), FactoryCheck::Drop);
+}
+
+#[test]
+#[should_panic(expected = "Unsupported factory allowlist network")]
+fn test_unknown_network_panics() {
+    FactoryFilter::from_params("network=unknown");
+}
@@ -0,0 +1,32 @@
+use common::hooks::{has_hooks, hook_flags, hook_permissions, AFTER_SWAP, BEFORE_INITIALIZE, BEFORE_SWAP, BEFORE_SWAP_RETURNS_DELTA};
+
+fn address(hex_str: &str) -> Vec<u8> {
+    hex::decode(hex_str).unwrap()
+}
+
+#[test]
+fn test_flags_are_the_lowest_14_address_bits() {
+    // Dynamic fee hook: beforeInitialize | beforeSwap | afterSwap.
+    let hooks = address("1111111111111111111111111111111111e020c0");
+    assert_eq!(hook_flags(&hooks), BEFORE_INITIALIZE | BEFORE_SWAP | AFTER_SWAP);
+
+    let permissions = hook_permissions(&hooks);
+    assert!(permissions.before_initialize && permissions.before_swap && permissions.after_swap);
+    assert!(!permissions.after_initialize && !permissions.before_donate);
+}
+
+#[test]
+fn test_returns_delta_flag() {
+    let hooks = address(&format!("{}88", "00".repeat(19)));
+    assert_eq!(hook_flags(&hooks) & BEFORE_SWAP_RETURNS_DELTA, BEFORE_SWAP_RETURNS_DELTA);
+}
+
+#[test]
+fn test_no_hooks() {
+    let zero = [0u8; 20];
+    assert!(!has_hooks(&zero));
+    assert!(!has_hooks(&[]));
+    assert_eq!(hook_flags(&zero), 0);
+    assert_eq!(hook_flags(&[]), 0);
+    assert!(has_hooks(&address("1111111111111111111111111111111111e020c0")));
+}
@@ -0,0 +1,41 @@
+use common::index::{event_signature_key, log_keys};
+use substreams_ethereum::pb::eth::v2::Log;
+
+fn log(address: u8, topic0: u8) -> Log {
+    Log {
+        address: vec![address; 20],
+        topics: vec![vec![topic0; 32], vec![0; 32]],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_keys_are_sorted_and_deduplicated() {
+    let keys = log_keys(&[log(0x11, 0xaa), log(0x11, 0xbb), log(0x22, 0xaa)]).keys;
+
+    assert_eq!(
+        keys,
+        vec![
+            format!("evt_addr:0x{}", "11".repeat(20)),
+            format!("evt_addr:0x{}", "22".repeat(20)),
+            format!("evt_sig:0x{}", "aa".repeat(32)),
+            format!("evt_sig:0x{}", "bb".repeat(32)),
+        ]
+    );
+}
+
+#[test]
+fn test_anonymous_logs_only_index_their_address() {
+    let anonymous = Log {
+        address: vec![0x11; 20],
+        ..Default::default()
+    };
+
+    assert_eq!(event_signature_key(&anonymous), None);
+    assert_eq!(log_keys([&anonymous]).keys, vec![format!("evt_addr:0x{}", "11".repeat(20))]);
+}
+
+#[test]
+fn test_empty_block_has_no_keys() {
+    assert!(log_keys(&[]).keys.is_empty());
+}
@@ -0,0 +1,37 @@
+use common::projects::{get_project, network_factories, project_label};
+
+fn address(hex_str: &str) -> Vec<u8> {
+    hex::decode(hex_str).unwrap()
+}
+
+#[test]
+fn test_forks_resolve_to_their_project() {
+    let uniswap_v2 = address("5c69bee701ef814a2b6a3edd4b1652cb9cc5aa6f");
+    let sushiswap_v2 = address("c0aee478e3658e2610c5f7a4a2e1777ce9e4f2ac");
+    assert_eq!(get_project("mainnet", &uniswap_v2), Some(("uniswap", "v2")));
+    assert_eq!(project_label("mainnet", &sushiswap_v2), "sushiswap_v2");
+}
+
+#[test]
+fn test_same_factory_differs_per_network() {
+    let factory = address("c35dadb65012ec5796536bd9864ed8773abc74c4");
+    assert_eq!(project_label("arbitrum-one", &factory), "sushiswap_v2");
+    assert_eq!(project_label("mainnet", &factory), "");
+}
+
+#[test]
+fn test_unknown_factory_or_network_is_empty() {
+    assert_eq!(project_label("mainnet", &address("00000000000000000000000000000000000000aa")), "");
+    assert_eq!(project_label("mainnet", &[]), "");
+    assert_eq!(project_label("", &address("5c69bee701ef814a2b6a3edd4b1652cb9cc5aa6f")), "");
+    assert!(network_factories("unknown").is_none());
+}
+
+#[test]
+fn test_registry_addresses_are_valid() {
+    for network in ["mainnet", "base", "arbitrum-one", "bsc", "tron"] {
+        for (factory, _, _) in network_factories(network).unwrap() {
+            assert_eq!(address(factory).len(), 20, "{} {}", network, factory);
+        }
+    }
+}
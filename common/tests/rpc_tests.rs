@@ -0,0 +1,62 @@
+use common::rpc::{execute_bisect, parse_chunk_size};
+
+/// Fake RPC: a batch fails when it contains a "reverting" call (odd numbers), otherwise every call
+/// returns its double.
+fn rpc(batches: &mut Vec<Vec<u32>>) -> impl FnMut(&[u32]) -> Result<Vec<u32>, String> + '_ {
+    move |chunk: &[u32]| {
+        batches.push(chunk.to_vec());
+        if chunk.iter().any(|call| call % 2 == 1) {
+            return Err(format!("batch {:?} reverted", chunk));
+        }
+        Ok(chunk.iter().map(|call| call * 2).collect())
+    }
+}
+
+#[test]
+fn test_successful_batches_run_once() {
+    let mut batches = vec![];
+    let results = execute_bisect(&[2, 4, 6], 2, 3, &mut rpc(&mut batches));
+
+    assert_eq!(results, vec![Ok(4), Ok(8), Ok(12)]);
+    assert_eq!(batches, vec![vec![2, 4], vec![6]]);
+}
+
+#[test]
+fn test_bisects_to_isolate_failing_calls() {
+    let mut batches = vec![];
+    let results = execute_bisect(&[2, 3, 4, 6], 4, 2, &mut rpc(&mut batches));
+
+    assert_eq!(results, vec![Ok(4), Err("batch [3] reverted".to_string()), Ok(8), Ok(12)]);
+    // 2 attempts each of [2, 3, 4, 6], [2, 3] and [3], plus [2] and [4, 6].
+    assert_eq!(batches.len(), 8);
+}
+
+#[test]
+fn test_retries_transient_failures() {
+    let mut attempts = 0;
+    let results = execute_bisect(&[1, 2], 10, 3, &mut |chunk: &[u32]| {
+        attempts += 1;
+        if attempts < 3 {
+            return Err("timeout".to_string());
+        }
+        Ok(chunk.to_vec())
+    });
+
+    assert_eq!(results, vec![Ok(1), Ok(2)]);
+    assert_eq!(attempts, 3);
+}
+
+#[test]
+fn test_short_responses_count_as_failures() {
+    let results = execute_bisect(&[1, 2], 10, 1, &mut |chunk: &[u32]| Ok(chunk[..1].to_vec()));
+
+    assert_eq!(results, vec![Ok(1), Ok(2)]);
+}
+
+#[test]
+fn test_parse_chunk_size() {
+    assert_eq!(parse_chunk_size("100").unwrap(), 100);
+    assert_eq!(parse_chunk_size(" 5 ").unwrap(), 5);
+    assert!(parse_chunk_size("0").is_err());
+    assert!(parse_chunk_size("abc").is_err());
+}
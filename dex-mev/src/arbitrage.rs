@@ -0,0 +1,33 @@
+//! Cyclic arbitrage detection.
+//!
+//! A trade reconstructed by `dex_swaps:map_trades` is an arbitrage when its route has at least two
+//! hops and ends in the token it started with; the profit is its output minus its input.
+
+use std::collections::HashMap;
+
+use proto::pb::dex::mev::v1 as pb;
+use proto::pb::dex::swaps::v1 as swaps;
+
+use crate::{profit, TxIndexes};
+
+pub fn detect(events: &swaps::Events, trades: &swaps::Trades, tx_indexes: &TxIndexes) -> Vec<pb::Arbitrage> {
+    let senders: HashMap<&[u8], &[u8]> = events.transactions.iter().map(|tx| (tx.hash.as_slice(), tx.from.as_slice())).collect();
+
+    let mut arbitrages: Vec<pb::Arbitrage> = trades
+        .trades
+        .iter()
+        .filter(|trade| trade.pools.len() >= 2 && trade.input_token == trade.output_token)
+        .map(|trade| pb::Arbitrage {
+            transaction_hash: trade.transaction_hash.clone(),
+            tx_index: tx_indexes.get(trade.transaction_hash.as_slice()).copied().unwrap_or_default(),
+            tx_from: senders.get(trade.transaction_hash.as_slice()).map(|from| from.to_vec()).unwrap_or_default(),
+            log_ordinal: trade.ordinals.first().copied().unwrap_or_default(),
+            token: trade.input_token.clone(),
+            route: trade.route.clone(),
+            pools: trade.pools.clone(),
+            profits: profit(&trade.input_token, &trade.output_amount, &trade.input_amount).into_iter().collect(),
+        })
+        .collect();
+    arbitrages.sort_by_key(|arbitrage| (arbitrage.tx_index, arbitrage.log_ordinal));
+    arbitrages
+}
@@ -0,0 +1,41 @@
+//! MEV detection over normalized DEX swaps (`dex.swaps.v1`).
+//!
+//!   - [`sandwich`]: front-run and back-run by the same sender around victims on one pool.
+//!   - [`arbitrage`]: multi-hop trades (`dex_swaps:map_trades`) starting and ending in the same token.
+//!
+//! Swaps are ordered by (`tx_index`, `log_ordinal`); transaction indexes come from the block since
+//! `dex.swaps.v1.Transaction` does not carry them.
+
+mod arbitrage;
+mod sandwich;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use proto::pb::dex::mev::v1 as pb;
+use proto::pb::dex::swaps::v1 as swaps;
+use substreams::errors::Error;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2::Block;
+
+/// Transaction hash -> index in block.
+pub type TxIndexes<'a> = HashMap<&'a [u8], u32>;
+
+#[substreams::handlers::map]
+pub fn map_events(block: Block, events: swaps::Events, trades: swaps::Trades) -> Result<pb::Events, Error> {
+    let tx_indexes: TxIndexes = block.transaction_traces.iter().map(|tx| (tx.hash.as_slice(), tx.index)).collect();
+
+    Ok(pb::Events {
+        sandwiches: sandwich::detect(&events, &tx_indexes),
+        arbitrages: arbitrage::detect(&events, &trades, &tx_indexes),
+    })
+}
+
+/// `gain - cost` of `token`, `None` if an amount is not an integer.
+fn profit(token: &[u8], gain: &str, cost: &str) -> Option<pb::Profit> {
+    let amount = BigInt::from_str(gain).ok()? - BigInt::from_str(cost).ok()?;
+    Some(pb::Profit {
+        token: token.to_vec(),
+        amount: amount.to_string(),
+    })
+}
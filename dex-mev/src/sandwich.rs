@@ -0,0 +1,185 @@
+//! Sandwich detection.
+//!
+//! Per pool, in block order, a front-run is a swap whose transaction sender later swaps the same
+//! pool in the opposite direction (back-run) from another transaction. Swaps in between, from other
+//! senders and in the front-run direction, are the victims; without victims there is no sandwich.
+//! Searchers are matched by transaction sender, so sandwiches split across EOAs are not detected.
+
+use std::collections::HashMap;
+
+use proto::pb::dex::mev::v1 as pb;
+use proto::pb::dex::swaps::v1 as swaps;
+
+use crate::{profit, TxIndexes};
+
+struct PoolSwap<'a> {
+    tx: &'a swaps::Transaction,
+    tx_index: u32,
+    log: &'a swaps::Log,
+    swap: &'a swaps::Swap,
+}
+
+impl PoolSwap<'_> {
+    fn same_direction(&self, other: &PoolSwap) -> bool {
+        self.swap.input_token == other.swap.input_token && self.swap.output_token == other.swap.output_token
+    }
+
+    fn reverses(&self, other: &PoolSwap) -> bool {
+        self.swap.input_token == other.swap.output_token && self.swap.output_token == other.swap.input_token
+    }
+
+    fn to_leg(&self) -> pb::Leg {
+        pb::Leg {
+            transaction_hash: self.tx.hash.clone(),
+            tx_index: self.tx_index,
+            tx_from: self.tx.from.clone(),
+            log_ordinal: self.log.ordinal,
+            user: self.swap.user.clone(),
+            input_token: self.swap.input_token.clone(),
+            input_amount: self.swap.input_amount.clone(),
+            output_token: self.swap.output_token.clone(),
+            output_amount: self.swap.output_amount.clone(),
+        }
+    }
+}
+
+pub fn detect(events: &swaps::Events, tx_indexes: &TxIndexes) -> Vec<pb::Sandwich> {
+    let mut all: Vec<PoolSwap> = Vec::new();
+    for tx in events.transactions.iter() {
+        let tx_index = tx_indexes.get(tx.hash.as_slice()).copied().unwrap_or_default();
+        for log in tx.logs.iter() {
+            if let Some(swaps::log::Log::Swap(swap)) = &log.log {
+                all.push(PoolSwap { tx, tx_index, log, swap });
+            }
+        }
+    }
+    all.sort_by_key(|swap| (swap.tx_index, swap.log.ordinal));
+
+    // Group by pool, keeping block order within (and across) pools.
+    let mut positions: HashMap<Vec<u8>, usize> = HashMap::new();
+    let mut pools: Vec<Vec<PoolSwap>> = Vec::new();
+    for swap in all {
+        let position = *positions.entry(swap.swap.pool.clone()).or_insert_with(|| {
+            pools.push(Vec::new());
+            pools.len() - 1
+        });
+        pools[position].push(swap);
+    }
+
+    let mut sandwiches: Vec<pb::Sandwich> = pools.iter().flat_map(|swaps| detect_pool(swaps.as_slice())).collect();
+    sandwiches.sort_by_key(|sandwich| sandwich.front_run.as_ref().map(|leg| (leg.tx_index, leg.log_ordinal)));
+    sandwiches
+}
+
+fn detect_pool(swaps: &[PoolSwap]) -> Vec<pb::Sandwich> {
+    let mut sandwiches = Vec::new();
+    let mut used = vec![false; swaps.len()];
+
+    for (i, front) in swaps.iter().enumerate() {
+        if used[i] {
+            continue;
+        }
+        let back = (i + 1..swaps.len()).find(|&j| {
+            let back = &swaps[j];
+            !used[j] && back.tx_index != front.tx_index && back.tx.from == front.tx.from && back.reverses(front)
+        });
+        let Some(j) = back else {
+            continue;
+        };
+        let back = &swaps[j];
+        let victims: Vec<&PoolSwap> = swaps[i + 1..j]
+            .iter()
+            .filter(|victim| victim.tx_index != front.tx_index && victim.tx_index != back.tx_index)
+            .filter(|victim| victim.tx.from != front.tx.from && victim.same_direction(front))
+            .collect();
+        if victims.is_empty() {
+            continue;
+        }
+
+        used[i] = true;
+        used[j] = true;
+        sandwiches.push(create_sandwich(front, &victims, back));
+    }
+
+    sandwiches
+}
+
+fn create_sandwich(front: &PoolSwap, victims: &[&PoolSwap], back: &PoolSwap) -> pb::Sandwich {
+    // Front-run spends token A for token B, back-run sells token B back for token A.
+    let profits = [
+        profit(&front.swap.input_token, &back.swap.output_amount, &front.swap.input_amount),
+        profit(&front.swap.output_token, &front.swap.output_amount, &back.swap.input_amount),
+    ];
+
+    pb::Sandwich {
+        factory: front.swap.factory.clone(),
+        pool: front.swap.pool.clone(),
+        attacker: front.tx.from.clone(),
+        front_run: Some(front.to_leg()),
+        victims: victims.iter().map(|victim| victim.to_leg()).collect(),
+        back_run: Some(back.to_leg()),
+        profits: profits.into_iter().flatten().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transaction(hash: u8, from: u8, input: (u8, &str), output: (u8, &str)) -> swaps::Transaction {
+        swaps::Transaction {
+            hash: vec![hash],
+            from: vec![from],
+            logs: vec![swaps::Log {
+                ordinal: hash as u64,
+                log: Some(swaps::log::Log::Swap(swaps::Swap {
+                    pool: vec![0xaa],
+                    input_token: vec![input.0],
+                    input_amount: input.1.to_string(),
+                    output_token: vec![output.0],
+                    output_amount: output.1.to_string(),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn indexes(events: &swaps::Events) -> TxIndexes {
+        events.transactions.iter().map(|tx| (tx.hash.as_slice(), tx.hash[0] as u32)).collect()
+    }
+
+    #[test]
+    fn detects_sandwich_with_profits() {
+        let events = swaps::Events {
+            transactions: vec![
+                transaction(1, 0xbb, (1, "100"), (2, "50")),
+                transaction(2, 0xcc, (1, "1000"), (2, "400")),
+                transaction(3, 0xbb, (2, "50"), (1, "130")),
+            ],
+        };
+        let sandwiches = detect(&events, &indexes(&events));
+
+        assert_eq!(sandwiches.len(), 1);
+        assert_eq!(sandwiches[0].attacker, vec![0xbb]);
+        assert_eq!(sandwiches[0].victims.len(), 1);
+        assert_eq!(sandwiches[0].victims[0].tx_from, vec![0xcc]);
+        assert_eq!(sandwiches[0].profits[0].token, vec![1]);
+        assert_eq!(sandwiches[0].profits[0].amount, "30");
+        assert_eq!(sandwiches[0].profits[1].amount, "0");
+    }
+
+    #[test]
+    fn no_victim_no_sandwich() {
+        let events = swaps::Events {
+            transactions: vec![
+                transaction(1, 0xbb, (1, "100"), (2, "50")),
+                transaction(2, 0xcc, (2, "10"), (1, "20")),
+                transaction(3, 0xbb, (2, "50"), (1, "130")),
+            ],
+        };
+
+        assert!(detect(&events, &indexes(&events)).is_empty());
+    }
+}
@@ -1,10 +1,18 @@
+use common::index::block_keys;
 use common::logs_with_caller;
 use proto::pb::evm::seaport::v1 as seaport;
 use proto::pb::evm::seaport::v1::Events;
+use substreams::pb::sf::substreams::index::v1::Keys;
 use substreams_abis::dex::seaport::seaport::events;
 use substreams_ethereum::pb::eth::v2::Block;
 use substreams_ethereum::Event;
 
+/// Block index of `evt_sig` / `evt_addr` keys, used as the `blockFilter` of `map_events`.
+#[substreams::handlers::map]
+fn index_events(block: Block) -> Result<Keys, substreams::errors::Error> {
+    Ok(block_keys(&block))
+}
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<Events, substreams::errors::Error> {
     let mut events = Events::default();
@@ -1,20 +1,30 @@
+use common::factories::{FactoryCheck, FactoryFilter};
 use prost::Message;
 use prost_types::Any;
 use proto::pb::dex::foundational_store::v1 as foundational;
+use proto::pb::dex::foundational_store::v1::{pool_v2::Params, Protocol};
 use proto::pb::{
     aerodrome::v1 as aerodrome, balancer::v1 as balancer, bancor::v1 as bancor, curvefi::v1 as curvefi,
     kyber_elastic::v1 as kyber_elastic, sunpump::v1 as sunpump,
     traderjoe::v1 as traderjoe, uniswap,
 };
 use substreams::pb::sf::substreams::foundational_store::model::v2::{Entry, Key, SinkEntries};
+use substreams::store::{StoreGet, StoreGetString, StoreNew, StoreSet, StoreSetString};
+use substreams::Hex;
 
-// The foundational payload is intentionally restricted to shared pool metadata only:
-// tokens[] plus factory when available. Protocol-specific initialization fields like
-// Aerodrome `stable`, TraderJoe `bin_step`, and Kyber `swap_fee_units` /
-// `tick_distance` remain available on the original pool-creation events.
+// `map_entries` writes the v1 `Pool` payload, restricted to shared pool metadata only:
+// tokens[] plus factory when available. `map_entries_v2` writes `PoolV2`, which adds the
+// protocol and its pool-creation params (Uniswap V3/V4 `fee` / `tick_spacing` / `hooks`,
+// Aerodrome `stable`, TraderJoe `bin_step`, Kyber `swap_fee_units` / `tick_distance`).
+// Both read the same events through the same collectors.
+//
+// Entries are written with `if_not_exist`, except Bancor reserve updates, which rewrite pools
+// stored earlier: `map_bancor_entries` / `map_bancor_entries_v2` emit them as their own
+// overwrite batch, to be ingested into the same foundational store.
 
 #[substreams::handlers::map]
 pub fn map_entries(
+    params: String,
     events_sunpump: sunpump::Events,
     events_balancer: balancer::Events,
     events_bancor: bancor::Events,
@@ -27,86 +37,208 @@ pub fn map_entries(
     events_uniswap_v3: uniswap::v3::Events,
     events_uniswap_v4: uniswap::v4::Events,
 ) -> Result<SinkEntries, substreams::errors::Error> {
-    let mut entries = Vec::new();
+    let filter = FactoryFilter::from_params(&params);
+    let mut pools = PoolEntries::new(&filter, Version::V1);
 
-    collect_sunpump(&mut entries, &events_sunpump);
-    collect_balancer(&mut entries, &events_balancer);
-    collect_bancor(&mut entries, &events_bancor);
-    collect_curvefi(&mut entries, &events_curvefi);
-    collect_aerodrome(&mut entries, &events_aerodrome);
-    collect_traderjoe(&mut entries, &events_traderjoe);
-    collect_kyber_elastic(&mut entries, &events_kyber_elastic);
-    collect_uniswap_v1(&mut entries, &events_uniswap_v1);
-    collect_uniswap_v2(&mut entries, &events_uniswap_v2);
-    collect_uniswap_v3(&mut entries, &events_uniswap_v3);
-    collect_uniswap_v4(&mut entries, &events_uniswap_v4);
+    collect_sunpump(&mut pools, &events_sunpump);
+    collect_balancer(&mut pools, &events_balancer);
+    collect_bancor(&mut pools, &events_bancor);
+    collect_curvefi(&mut pools, &events_curvefi);
+    collect_aerodrome(&mut pools, &events_aerodrome);
+    collect_traderjoe(&mut pools, &events_traderjoe);
+    collect_kyber_elastic(&mut pools, &events_kyber_elastic);
+    collect_uniswap_v1(&mut pools, &events_uniswap_v1);
+    collect_uniswap_v2(&mut pools, &events_uniswap_v2);
+    collect_uniswap_v3(&mut pools, &events_uniswap_v3);
+    collect_uniswap_v4(&mut pools, &events_uniswap_v4);
 
-    if entries.is_empty() {
-        return Ok(SinkEntries::default());
-    }
+    Ok(pools.into_sink_entries())
+}
+
+/// Same as `map_entries`, with `PoolV2` values.
+#[substreams::handlers::map]
+pub fn map_entries_v2(
+    params: String,
+    events_sunpump: sunpump::Events,
+    events_balancer: balancer::Events,
+    events_bancor: bancor::Events,
+    events_curvefi: curvefi::Events,
+    events_aerodrome: aerodrome::Events,
+    events_traderjoe: traderjoe::Events,
+    events_kyber_elastic: kyber_elastic::Events,
+    events_uniswap_v1: uniswap::v1::Events,
+    events_uniswap_v2: uniswap::v2::Events,
+    events_uniswap_v3: uniswap::v3::Events,
+    events_uniswap_v4: uniswap::v4::Events,
+) -> Result<SinkEntries, substreams::errors::Error> {
+    let filter = FactoryFilter::from_params(&params);
+    let mut pools = PoolEntries::new(&filter, Version::V2);
 
-    Ok(SinkEntries { entries, if_not_exist: true })
+    collect_sunpump(&mut pools, &events_sunpump);
+    collect_balancer(&mut pools, &events_balancer);
+    collect_bancor(&mut pools, &events_bancor);
+    collect_curvefi(&mut pools, &events_curvefi);
+    collect_aerodrome(&mut pools, &events_aerodrome);
+    collect_traderjoe(&mut pools, &events_traderjoe);
+    collect_kyber_elastic(&mut pools, &events_kyber_elastic);
+    collect_uniswap_v1(&mut pools, &events_uniswap_v1);
+    collect_uniswap_v2(&mut pools, &events_uniswap_v2);
+    collect_uniswap_v3(&mut pools, &events_uniswap_v3);
+    collect_uniswap_v4(&mut pools, &events_uniswap_v4);
+
+    Ok(pools.into_sink_entries())
 }
 
-fn push_pool_entry(entries: &mut Vec<Entry>, pool: &[u8], tokens: Vec<Vec<u8>>, factory: Vec<u8>) {
-    if pool.is_empty() {
-        return;
+/// Bancor converter links, which can be made blocks before the registry lists the reserves.
+#[substreams::handlers::store]
+pub fn store_bancor_converters(events: bancor::Events, store: StoreSetString) {
+    for (ordinal, key, value) in bancor_links(&events) {
+        store.set(ordinal, key, &Hex::encode(value));
     }
+}
 
-    let value = Any {
-        type_url: "type.googleapis.com/dex.foundational_store.v1.Pool".to_string(),
-        value: foundational::Pool { tokens, factory }.encode_to_vec(),
-    };
+/// Bancor reserve updates of converters and anchors stored earlier, as an overwrite batch.
+#[substreams::handlers::map]
+pub fn map_bancor_entries(params: String, events_bancor: bancor::Events, converters: StoreGetString) -> Result<SinkEntries, substreams::errors::Error> {
+    let filter = FactoryFilter::from_params(&params);
+    let mut pools = PoolEntries::new(&filter, Version::V1);
+
+    collect_bancor_reserves(&mut pools, &events_bancor, |key| bancor_link(&converters, key));
 
-    entries.push(Entry {
-        key: Some(Key { bytes: pool.to_vec() }),
-        value: Some(value),
-    });
+    Ok(pools.into_sink_entries())
 }
 
-fn push_balancer_pool(entries: &mut Vec<Entry>, pool: &[u8], factory: &[u8], token_config: &[balancer::TokenConfig]) {
-    push_pool_entry(
-        entries,
-        pool,
-        token_config.iter().map(|config| config.token.clone()).collect(),
-        factory.to_vec(),
-    );
+/// Same as `map_bancor_entries`, with `PoolV2` values.
+#[substreams::handlers::map]
+pub fn map_bancor_entries_v2(params: String, events_bancor: bancor::Events, converters: StoreGetString) -> Result<SinkEntries, substreams::errors::Error> {
+    let filter = FactoryFilter::from_params(&params);
+    let mut pools = PoolEntries::new(&filter, Version::V2);
+
+    collect_bancor_reserves(&mut pools, &events_bancor, |key| bancor_link(&converters, key));
+
+    Ok(pools.into_sink_entries())
+}
+
+/// Payload written for each pool.
+#[derive(Clone, Copy)]
+enum Version {
+    /// `dex.foundational_store.v1.Pool`
+    V1,
+    /// `dex.foundational_store.v1.PoolV2`
+    V2,
+}
+
+/// Pool entries of one payload version, filtered on their factory.
+struct PoolEntries<'a> {
+    filter: &'a FactoryFilter,
+    version: Version,
+    entries: Vec<Entry>,
+    /// Set when the entries update pools stored earlier (Bancor reserves), so the batch overwrites.
+    overwrite: bool,
+}
+
+impl<'a> PoolEntries<'a> {
+    fn new(filter: &'a FactoryFilter, version: Version) -> Self {
+        PoolEntries {
+            filter,
+            version,
+            entries: Vec::new(),
+            overwrite: false,
+        }
+    }
+
+    fn push(&mut self, pool: &[u8], tokens: Vec<Vec<u8>>, factory: Vec<u8>, protocol: Protocol, params: Option<Params>) {
+        if pool.is_empty() {
+            return;
+        }
+        let FactoryCheck::Keep { verified } = self.filter.check(&factory) else {
+            return;
+        };
+
+        let value = match self.version {
+            Version::V1 => Any {
+                type_url: "type.googleapis.com/dex.foundational_store.v1.Pool".to_string(),
+                value: foundational::Pool { tokens, factory, verified }.encode_to_vec(),
+            },
+            Version::V2 => Any {
+                type_url: "type.googleapis.com/dex.foundational_store.v1.PoolV2".to_string(),
+                value: foundational::PoolV2 {
+                    protocol: protocol as i32,
+                    tokens,
+                    factory,
+                    verified,
+                    params,
+                }
+                .encode_to_vec(),
+            },
+        };
+
+        self.entries.push(Entry {
+            key: Some(Key { bytes: pool.to_vec() }),
+            value: Some(value),
+        });
+    }
+
+    fn push_balancer(&mut self, pool: &[u8], factory: &[u8], token_config: &[balancer::TokenConfig]) {
+        let tokens = token_config.iter().map(|config| config.token.clone()).collect();
+        self.push(pool, tokens, factory.to_vec(), Protocol::Balancer, None);
+    }
+
+    fn into_sink_entries(self) -> SinkEntries {
+        if self.entries.is_empty() {
+            return SinkEntries::default();
+        }
+        SinkEntries {
+            entries: self.entries,
+            if_not_exist: !self.overwrite,
+        }
+    }
 }
 
-fn collect_aerodrome(entries: &mut Vec<Entry>, events: &aerodrome::Events) {
+fn collect_aerodrome(pools: &mut PoolEntries, events: &aerodrome::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             if let Some(aerodrome::log::Log::PoolCreated(pool_created)) = &log.log {
-                push_pool_entry(
-                    entries,
+                pools.push(
                     &pool_created.pool,
                     vec![pool_created.token0.clone(), pool_created.token1.clone()],
                     log.address.clone(),
+                    Protocol::Aerodrome,
+                    Some(Params::Aerodrome(foundational::AerodromeParams { stable: pool_created.stable })),
                 );
             }
         }
     }
 }
 
-fn collect_balancer(entries: &mut Vec<Entry>, events: &balancer::Events) {
+fn collect_balancer(pools: &mut PoolEntries, events: &balancer::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             if let Some(balancer::log::Log::PoolRegistered(pool_registered)) = &log.log {
-                push_balancer_pool(entries, &pool_registered.pool, &pool_registered.factory, &pool_registered.token_config);
+                pools.push_balancer(&pool_registered.pool, &pool_registered.factory, &pool_registered.token_config);
             }
         }
     }
 }
 
-fn collect_bancor(entries: &mut Vec<Entry>, events: &bancor::Events) {
+/// A Bancor converter anchor (pool token) listed or delisted by the converter registry in this block.
+struct BancorAnchor {
+    anchor: Vec<u8>,
+    registry: Vec<u8>,
+    reserves: Vec<Vec<u8>>,
+}
+
+/// Bancor converters start without reserve tokens: they are stored when created and their reserves
+/// are written later by `collect_bancor_reserves`.
+fn collect_bancor(pools: &mut PoolEntries, events: &bancor::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             match &log.log {
                 Some(bancor::log::Log::FeaturesAddition(event)) => {
-                    push_pool_entry(entries, &event.address, vec![], log.address.clone());
+                    pools.push(&event.address, vec![], log.address.clone(), Protocol::Bancor, None);
                 }
                 Some(bancor::log::Log::NewConverter(event)) => {
-                    push_pool_entry(entries, &event.converter, vec![], log.address.clone());
+                    pools.push(&event.converter, vec![], log.address.clone(), Protocol::Bancor, None);
                 }
                 _ => {}
             }
@@ -114,23 +246,140 @@ fn collect_bancor(entries: &mut Vec<Entry>, events: &bancor::Events) {
     }
 }
 
-fn collect_curvefi(entries: &mut Vec<Entry>, events: &curvefi::Events) {
+/// Address stored under `key` in `store_bancor_converters`.
+fn bancor_link(converters: &StoreGetString, key: &str) -> Option<Vec<u8>> {
+    converters.get_last(key).and_then(|value| Hex::decode(value).ok())
+}
+
+/// `store_bancor_converters` key of a converter's factory.
+fn bancor_factory_key(converter: &[u8]) -> String {
+    format!("converter:{}", Hex::encode(converter))
+}
+
+/// `store_bancor_converters` key of the converter that activated an anchor.
+fn bancor_anchor_key(anchor: &[u8]) -> String {
+    format!("anchor:{}", Hex::encode(anchor))
+}
+
+/// `store_bancor_converters` key of a contract's owner.
+fn bancor_owner_key(contract: &[u8]) -> String {
+    format!("owner:{}", Hex::encode(contract))
+}
+
+/// Links written to `store_bancor_converters`, as `(ordinal, key, address)`:
+///   - `converter:<converter>` = factory (`NewConverter` / `FeaturesAddition`)
+///   - `anchor:<anchor>` = converter (the converter's `Activation`)
+///   - `owner:<contract>` = new owner (`OwnerUpdate`): legacy `BancorConverterFactory` converters
+///     emit no `Activation`, they only take over the ownership of their anchor
+fn bancor_links(events: &bancor::Events) -> Vec<(u64, String, Vec<u8>)> {
+    let mut links = Vec::new();
+    for trx in &events.transactions {
+        for log in &trx.logs {
+            match &log.log {
+                Some(bancor::log::Log::FeaturesAddition(event)) => {
+                    links.push((log.ordinal, bancor_factory_key(&event.address), log.address.clone()));
+                }
+                Some(bancor::log::Log::NewConverter(event)) => {
+                    links.push((log.ordinal, bancor_factory_key(&event.converter), log.address.clone()));
+                }
+                Some(bancor::log::Log::Activation(event)) => {
+                    links.push((log.ordinal, bancor_anchor_key(&event.anchor), log.address.clone()));
+                }
+                Some(bancor::log::Log::OwnerUpdate(event)) => {
+                    links.push((log.ordinal, bancor_owner_key(&log.address), event.new_owner.clone()));
+                }
+                _ => {}
+            }
+        }
+    }
+    links
+}
+
+/// Reserve tokens only come from the converter registry, which lists the anchor (`SmartTokenAdded` /
+/// `LiquidityPoolAdded`) and each reserve token (`ConvertibleTokenAdded(reserve, anchor)`). The
+/// registry adds or removes all reserves of a converter in a single transaction, so the reserves
+/// listed in a block are the complete set and both the anchor and its converter are rewritten with
+/// them. The converter is the anchor's owner when it is a known converter, else the converter that
+/// activated it, read from `store_bancor_converters` through `links`.
+fn collect_bancor_reserves(pools: &mut PoolEntries, events: &bancor::Events, links: impl Fn(&str) -> Option<Vec<u8>>) {
+    let mut anchors: Vec<BancorAnchor> = Vec::new();
+
+    for trx in &events.transactions {
+        for log in &trx.logs {
+            match &log.log {
+                Some(bancor::log::Log::SmartTokenAdded(event)) => {
+                    bancor_anchor(&mut anchors, &event.smart_token, &log.address);
+                }
+                Some(bancor::log::Log::LiquidityPoolAdded(event)) => {
+                    bancor_anchor(&mut anchors, &event.liquidity_pool, &log.address);
+                }
+                // The registry also lists the anchor as convertible into itself, which is not a reserve.
+                Some(bancor::log::Log::ConvertibleTokenAdded(event)) if event.convertible_token != event.smart_token => {
+                    let anchor = bancor_anchor(&mut anchors, &event.smart_token, &log.address);
+                    if !anchor.reserves.contains(&event.convertible_token) {
+                        anchor.reserves.push(event.convertible_token.clone());
+                    }
+                }
+                Some(bancor::log::Log::ConvertibleTokenRemoved(event)) => {
+                    let anchor = bancor_anchor(&mut anchors, &event.smart_token, &log.address);
+                    anchor.reserves.retain(|reserve| *reserve != event.convertible_token);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let factory_of = |converter: &[u8]| links(&bancor_factory_key(converter));
+    let converter_of = |anchor: &[u8]| {
+        links(&bancor_owner_key(anchor))
+            .filter(|owner| factory_of(owner).is_some())
+            .or_else(|| links(&bancor_anchor_key(anchor)))
+    };
+
+    for anchor in &anchors {
+        let converter = converter_of(&anchor.anchor);
+        let factory = converter.as_deref().and_then(&factory_of).unwrap_or_else(|| anchor.registry.clone());
+        if let Some(converter) = &converter {
+            pools.push(converter, anchor.reserves.clone(), factory.clone(), Protocol::Bancor, None);
+        }
+        pools.push(&anchor.anchor, anchor.reserves.clone(), factory, Protocol::Bancor, None);
+        pools.overwrite = true;
+    }
+}
+
+/// Anchor entry of `anchors`, added on first use.
+fn bancor_anchor<'a>(anchors: &'a mut Vec<BancorAnchor>, anchor: &[u8], registry: &[u8]) -> &'a mut BancorAnchor {
+    let index = match anchors.iter().position(|entry| entry.anchor == anchor) {
+        Some(index) => index,
+        None => {
+            anchors.push(BancorAnchor {
+                anchor: anchor.to_vec(),
+                registry: registry.to_vec(),
+                reserves: Vec::new(),
+            });
+            anchors.len() - 1
+        }
+    };
+    &mut anchors[index]
+}
+
+fn collect_curvefi(pools: &mut PoolEntries, events: &curvefi::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             match &log.log {
                 Some(curvefi::log::Log::Init(init)) => {
                     // CurveFi exposes pool constituents as `coins`; foundational consumers read them
                     // through the shared `tokens[]` field in the normalized payload.
-                    push_pool_entry(entries, &init.address, init.coins.clone(), vec![]);
+                    pools.push(&init.address, init.coins.clone(), vec![], Protocol::Curvefi, None);
                 }
                 Some(curvefi::log::Log::PlainPoolDeployed(event)) => {
-                    push_pool_entry(entries, &event.address, event.coins.clone(), log.address.clone());
+                    pools.push(&event.address, event.coins.clone(), log.address.clone(), Protocol::Curvefi, None);
                 }
                 Some(curvefi::log::Log::MetaPoolDeployed(event)) => {
-                    push_pool_entry(entries, &event.address, vec![event.coin.clone()], log.address.clone());
+                    pools.push(&event.address, vec![event.coin.clone()], log.address.clone(), Protocol::Curvefi, None);
                 }
                 Some(curvefi::log::Log::CryptoPoolDeployed(event)) => {
-                    push_pool_entry(entries, &event.address, event.coins.clone(), log.address.clone());
+                    pools.push(&event.address, event.coins.clone(), log.address.clone(), Protocol::Curvefi, None);
                 }
                 _ => {}
             }
@@ -138,30 +387,34 @@ fn collect_curvefi(entries: &mut Vec<Entry>, events: &curvefi::Events) {
     }
 }
 
-fn collect_kyber_elastic(entries: &mut Vec<Entry>, events: &kyber_elastic::Events) {
+fn collect_kyber_elastic(pools: &mut PoolEntries, events: &kyber_elastic::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             if let Some(kyber_elastic::log::Log::PoolCreated(pool_created)) = &log.log {
-                push_pool_entry(
-                    entries,
+                pools.push(
                     &pool_created.pool,
                     vec![pool_created.token0.clone(), pool_created.token1.clone()],
                     log.address.clone(),
+                    Protocol::KyberElastic,
+                    Some(Params::KyberElastic(foundational::KyberElasticParams {
+                        swap_fee_units: pool_created.swap_fee_units,
+                        tick_distance: pool_created.tick_distance,
+                    })),
                 );
             }
         }
     }
 }
 
-fn collect_sunpump(entries: &mut Vec<Entry>, events: &sunpump::Events) {
+fn collect_sunpump(pools: &mut PoolEntries, events: &sunpump::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             match &log.log {
                 Some(sunpump::log::Log::TokenCreate(event)) => {
-                    push_pool_entry(entries, &event.token_address, vec![], log.address.clone());
+                    pools.push(&event.token_address, vec![], log.address.clone(), Protocol::Sunpump, None);
                 }
                 Some(sunpump::log::Log::TokenCreateLegacy(event)) => {
-                    push_pool_entry(entries, &event.token_address, vec![], log.address.clone());
+                    pools.push(&event.token_address, vec![], log.address.clone(), Protocol::Sunpump, None);
                 }
                 _ => {}
             }
@@ -169,70 +422,89 @@ fn collect_sunpump(entries: &mut Vec<Entry>, events: &sunpump::Events) {
     }
 }
 
-fn collect_traderjoe(entries: &mut Vec<Entry>, events: &traderjoe::Events) {
+fn collect_traderjoe(pools: &mut PoolEntries, events: &traderjoe::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             if let Some(traderjoe::log::Log::LbPairCreated(pair_created)) = &log.log {
-                push_pool_entry(
-                    entries,
+                pools.push(
                     &pair_created.lb_pair,
                     vec![pair_created.token_x.clone(), pair_created.token_y.clone()],
                     log.address.clone(),
+                    Protocol::Traderjoe,
+                    Some(Params::Traderjoe(foundational::TraderJoeParams {
+                        bin_step: pair_created.bin_step,
+                    })),
                 );
             }
         }
     }
 }
 
-fn collect_uniswap_v1(entries: &mut Vec<Entry>, events: &uniswap::v1::Events) {
+fn collect_uniswap_v1(pools: &mut PoolEntries, events: &uniswap::v1::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             if let Some(uniswap::v1::log::Log::NewExchange(new_exchange)) = &log.log {
-                push_pool_entry(entries, &new_exchange.exchange, vec![new_exchange.token.clone()], log.address.clone());
+                pools.push(
+                    &new_exchange.exchange,
+                    vec![new_exchange.token.clone()],
+                    log.address.clone(),
+                    Protocol::UniswapV1,
+                    None,
+                );
             }
         }
     }
 }
 
-fn collect_uniswap_v2(entries: &mut Vec<Entry>, events: &uniswap::v2::Events) {
+fn collect_uniswap_v2(pools: &mut PoolEntries, events: &uniswap::v2::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             if let Some(uniswap::v2::log::Log::PairCreated(pair_created)) = &log.log {
-                push_pool_entry(
-                    entries,
+                pools.push(
                     &pair_created.pair,
                     vec![pair_created.token0.clone(), pair_created.token1.clone()],
                     log.address.clone(),
+                    Protocol::UniswapV2,
+                    None,
                 );
             }
         }
     }
 }
 
-fn collect_uniswap_v3(entries: &mut Vec<Entry>, events: &uniswap::v3::Events) {
+fn collect_uniswap_v3(pools: &mut PoolEntries, events: &uniswap::v3::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             if let Some(uniswap::v3::log::Log::PoolCreated(pool_created)) = &log.log {
-                push_pool_entry(
-                    entries,
+                pools.push(
                     &pool_created.pool,
                     vec![pool_created.token0.clone(), pool_created.token1.clone()],
                     log.address.clone(),
+                    Protocol::UniswapV3,
+                    Some(Params::UniswapV3(foundational::UniswapV3Params {
+                        fee: pool_created.fee as u32,
+                        tick_spacing: pool_created.tick_spacing,
+                    })),
                 );
             }
         }
     }
 }
 
-fn collect_uniswap_v4(entries: &mut Vec<Entry>, events: &uniswap::v4::Events) {
+fn collect_uniswap_v4(pools: &mut PoolEntries, events: &uniswap::v4::Events) {
     for trx in &events.transactions {
         for log in &trx.logs {
             if let Some(uniswap::v4::log::Log::Initialize(initialize)) = &log.log {
-                push_pool_entry(
-                    entries,
+                pools.push(
                     &initialize.id,
                     vec![initialize.currency0.clone(), initialize.currency1.clone()],
                     log.address.clone(),
+                    Protocol::UniswapV4,
+                    Some(Params::UniswapV4(foundational::UniswapV4Params {
+                        fee: initialize.fee as u32,
+                        tick_spacing: initialize.tick_spacing,
+                        hooks: initialize.hooks.clone(),
+                    })),
                 );
             }
         }
@@ -242,6 +514,7 @@ fn collect_uniswap_v4(entries: &mut Vec<Entry>, events: &uniswap::v4::Events) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     fn decode_pool(entry: &Entry) -> foundational::Pool {
         let value = entry.value.as_ref().unwrap();
@@ -251,8 +524,10 @@ mod tests {
 
     #[test]
     fn stores_single_token_without_factory() {
-        let mut entries = Vec::new();
-        push_pool_entry(&mut entries, &[0x10], vec![vec![0xaa]], vec![]);
+        let filter = FactoryFilter::default();
+        let mut pools = PoolEntries::new(&filter, Version::V1);
+        pools.push(&[0x10], vec![vec![0xaa]], vec![], Protocol::Curvefi, None);
+        let entries = pools.entries;
 
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].key.as_ref().unwrap().bytes, vec![0x10]);
@@ -264,9 +539,9 @@ mod tests {
 
     #[test]
     fn extracts_balancer_tokens_from_token_config() {
-        let mut entries = Vec::new();
-        push_balancer_pool(
-            &mut entries,
+        let filter = FactoryFilter::default();
+        let mut pools = PoolEntries::new(&filter, Version::V1);
+        pools.push_balancer(
             &[0x01],
             &[0x02],
             &[
@@ -275,8 +550,233 @@ mod tests {
             ],
         );
 
-        let pool = decode_pool(&entries[0]);
+        let pool = decode_pool(&pools.entries[0]);
         assert_eq!(pool.tokens, vec![vec![0xaa], vec![0xbb]]);
         assert_eq!(pool.factory, vec![0x02]);
     }
+
+    #[test]
+    fn drops_unlisted_factory_and_tags_allowed_one() {
+        let allowed = [0x11; 20];
+        let filter = FactoryFilter::from_params(&format!("allow={}", substreams::Hex::encode(allowed)));
+        let mut pools = PoolEntries::new(&filter, Version::V1);
+        pools.push(&[0x01], vec![vec![0xaa]], [0x22; 20].to_vec(), Protocol::UniswapV2, None);
+        pools.push(&[0x02], vec![vec![0xaa]], allowed.to_vec(), Protocol::UniswapV2, None);
+        let entries = pools.entries;
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key.as_ref().unwrap().bytes, vec![0x02]);
+        assert_eq!(decode_pool(&entries[0]).verified, Some(true));
+    }
+
+    #[test]
+    fn v2_keeps_protocol_and_params() {
+        let filter = FactoryFilter::default();
+        let params = Params::UniswapV4(foundational::UniswapV4Params {
+            fee: 3000,
+            tick_spacing: 60,
+            hooks: vec![0x33; 20],
+        });
+        let mut pools = PoolEntries::new(&filter, Version::V2);
+        pools.push(
+            &[0x44; 32],
+            vec![vec![0xaa], vec![0xbb]],
+            vec![0x55; 20],
+            Protocol::UniswapV4,
+            Some(params.clone()),
+        );
+
+        let value = pools.entries[0].value.as_ref().unwrap();
+        assert_eq!(value.type_url, "type.googleapis.com/dex.foundational_store.v1.PoolV2");
+        let pool = foundational::PoolV2::decode(value.value.as_slice()).unwrap();
+        assert_eq!(pool.protocol(), Protocol::UniswapV4);
+        assert_eq!(pool.tokens, vec![vec![0xaa], vec![0xbb]]);
+        assert_eq!(pool.params, Some(params));
+    }
+
+    fn bancor_log(log: bancor::log::Log) -> bancor::Log {
+        bancor::Log {
+            address: vec![0xee],
+            log: Some(log),
+            ..Default::default()
+        }
+    }
+
+    fn bancor_events(logs: Vec<bancor::Log>) -> bancor::Events {
+        bancor::Events {
+            transactions: vec![bancor::Transaction { logs, ..Default::default() }],
+        }
+    }
+
+    /// `store_bancor_converters` after the given blocks.
+    fn bancor_store(blocks: &[&bancor::Events]) -> HashMap<String, Vec<u8>> {
+        blocks
+            .iter()
+            .flat_map(|events| bancor_links(events))
+            .map(|(_, key, value)| (key, value))
+            .collect()
+    }
+
+    /// Registry listing of `anchor` with the reserves `bnt` and `dai`.
+    fn bancor_listing(anchor: &[u8], bnt: &[u8], dai: &[u8]) -> Vec<bancor::Log> {
+        vec![
+            bancor_log(bancor::log::Log::SmartTokenAdded(bancor::SmartTokenAdded { smart_token: anchor.to_vec() })),
+            bancor_log(bancor::log::Log::LiquidityPoolAdded(bancor::LiquidityPoolAdded {
+                liquidity_pool: anchor.to_vec(),
+            })),
+            bancor_log(bancor::log::Log::ConvertibleTokenAdded(bancor::ConvertibleTokenAdded {
+                convertible_token: bnt.to_vec(),
+                smart_token: anchor.to_vec(),
+            })),
+            bancor_log(bancor::log::Log::ConvertibleTokenAdded(bancor::ConvertibleTokenAdded {
+                convertible_token: dai.to_vec(),
+                smart_token: anchor.to_vec(),
+            })),
+            bancor_log(bancor::log::Log::ConvertibleTokenAdded(bancor::ConvertibleTokenAdded {
+                convertible_token: anchor.to_vec(),
+                smart_token: anchor.to_vec(),
+            })),
+        ]
+    }
+
+    #[test]
+    fn resolves_bancor_reserves_of_converter_and_anchor() {
+        let (converter, anchor, bnt, dai) = (vec![0x01], vec![0x02], vec![0xaa], vec![0xbb]);
+        let mut logs = vec![
+            bancor_log(bancor::log::Log::NewConverter(bancor::NewConverter {
+                converter_type: 3,
+                converter: converter.clone(),
+                owner: vec![],
+            })),
+            bancor::Log {
+                address: converter.clone(),
+                log: Some(bancor::log::Log::Activation(bancor::Activation {
+                    converter_type: 3,
+                    anchor: anchor.clone(),
+                    activated: true,
+                })),
+                ..Default::default()
+            },
+        ];
+        logs.extend(bancor_listing(&anchor, &bnt, &dai));
+        let events = bancor_events(logs);
+        let store = bancor_store(&[&events]);
+        let filter = FactoryFilter::default();
+
+        let mut created = PoolEntries::new(&filter, Version::V1);
+        collect_bancor(&mut created, &events);
+        assert_eq!(created.entries.len(), 1);
+        assert!(decode_pool(&created.entries[0]).tokens.is_empty());
+        assert!(created.into_sink_entries().if_not_exist);
+
+        let mut updates = PoolEntries::new(&filter, Version::V1);
+        collect_bancor_reserves(&mut updates, &events, |key| store.get(key).cloned());
+        assert!(updates.overwrite);
+        assert_eq!(updates.entries.len(), 2);
+        assert_eq!(updates.entries[0].key.as_ref().unwrap().bytes, converter);
+        assert_eq!(updates.entries[1].key.as_ref().unwrap().bytes, anchor);
+        for entry in &updates.entries {
+            let pool = decode_pool(entry);
+            assert_eq!(pool.tokens, vec![bnt.clone(), dai.clone()]);
+            assert_eq!(pool.factory, vec![0xee]);
+        }
+    }
+
+    #[test]
+    fn links_bancor_converter_activated_in_an_earlier_block() {
+        let (converter, anchor, bnt, dai) = (vec![0x01], vec![0x02], vec![0xaa], vec![0xbb]);
+        let activation = bancor_events(vec![bancor::Log {
+            address: converter.clone(),
+            log: Some(bancor::log::Log::Activation(bancor::Activation {
+                converter_type: 3,
+                anchor: anchor.clone(),
+                activated: true,
+            })),
+            ..Default::default()
+        }]);
+        let listing = bancor_events(bancor_listing(&anchor, &bnt, &dai));
+        let store = bancor_store(&[&activation, &listing]);
+        let filter = FactoryFilter::default();
+        let mut pools = PoolEntries::new(&filter, Version::V1);
+        collect_bancor_reserves(&mut pools, &listing, |key| store.get(key).cloned());
+
+        assert_eq!(pools.entries.len(), 2);
+        assert_eq!(pools.entries[0].key.as_ref().unwrap().bytes, converter);
+        assert_eq!(decode_pool(&pools.entries[0]).tokens, vec![bnt, dai]);
+    }
+
+    #[test]
+    fn links_legacy_bancor_converter_through_anchor_ownership() {
+        let (converter, anchor, factory, bnt, dai) = (vec![0x01], vec![0x02], vec![0x03], vec![0xaa], vec![0xbb]);
+        let created = bancor_events(vec![
+            bancor::Log {
+                address: factory.clone(),
+                log: Some(bancor::log::Log::NewConverter(bancor::NewConverter {
+                    converter_type: 0,
+                    converter: converter.clone(),
+                    owner: vec![0x04],
+                })),
+                ..Default::default()
+            },
+            bancor::Log {
+                address: anchor.clone(),
+                log: Some(bancor::log::Log::OwnerUpdate(bancor::OwnerUpdate {
+                    prev_owner: vec![0x04],
+                    new_owner: converter.clone(),
+                })),
+                ..Default::default()
+            },
+        ]);
+        let listing = bancor_events(bancor_listing(&anchor, &bnt, &dai));
+        let store = bancor_store(&[&created, &listing]);
+        let filter = FactoryFilter::default();
+        let mut pools = PoolEntries::new(&filter, Version::V1);
+        collect_bancor_reserves(&mut pools, &listing, |key| store.get(key).cloned());
+
+        assert_eq!(pools.entries.len(), 2);
+        assert_eq!(pools.entries[0].key.as_ref().unwrap().bytes, converter);
+        for entry in &pools.entries {
+            let pool = decode_pool(entry);
+            assert_eq!(pool.tokens, vec![bnt.clone(), dai.clone()]);
+            assert_eq!(pool.factory, factory);
+        }
+    }
+
+    #[test]
+    fn ignores_bancor_anchor_owned_by_unknown_address() {
+        let anchor = vec![0x02];
+        let owner_update = bancor_events(vec![bancor::Log {
+            address: anchor.clone(),
+            log: Some(bancor::log::Log::OwnerUpdate(bancor::OwnerUpdate {
+                prev_owner: vec![],
+                new_owner: vec![0x04],
+            })),
+            ..Default::default()
+        }]);
+        let listing = bancor_events(bancor_listing(&anchor, &[0xaa], &[0xbb]));
+        let store = bancor_store(&[&owner_update, &listing]);
+        let filter = FactoryFilter::default();
+        let mut pools = PoolEntries::new(&filter, Version::V1);
+        collect_bancor_reserves(&mut pools, &listing, |key| store.get(key).cloned());
+
+        assert_eq!(pools.entries.len(), 1);
+        assert_eq!(pools.entries[0].key.as_ref().unwrap().bytes, anchor);
+    }
+
+    #[test]
+    fn removed_bancor_reserves_empty_the_anchor() {
+        let anchor = vec![0x02];
+        let events = bancor_events(vec![bancor_log(bancor::log::Log::ConvertibleTokenRemoved(bancor::ConvertibleTokenRemoved {
+            convertible_token: vec![0xaa],
+            smart_token: anchor.clone(),
+        }))]);
+        let filter = FactoryFilter::default();
+        let mut pools = PoolEntries::new(&filter, Version::V1);
+        collect_bancor_reserves(&mut pools, &events, |_| None);
+
+        assert_eq!(pools.entries.len(), 1);
+        assert_eq!(pools.entries[0].key.as_ref().unwrap().bytes, anchor);
+        assert!(decode_pool(&pools.entries[0]).tokens.is_empty());
+        assert!(!pools.into_sink_entries().if_not_exist);
+    }
 }
@@ -1,9 +1,20 @@
 use crate::PoolEntry;
+use common::dispatch::LogMatcher;
+use proto::pb::dex::foundational_store::v1::{pool_v2::Params, AerodromeParams, Protocol};
 use substreams_abis::dex::aerodrome;
 use substreams_ethereum::pb::eth::v2::Log;
 use substreams_ethereum::Event;
 
+/// Pool-creation events decoded by `collect_aerodrome`.
+pub const EVENTS: &[LogMatcher] = &[aerodrome::poolfactory::events::PoolCreated::match_log];
+
 pub fn collect_aerodrome(log: &Log) -> Option<PoolEntry> {
     let event = aerodrome::poolfactory::events::PoolCreated::match_and_decode(log)?;
-    Some(PoolEntry { address: event.pool.to_vec(), tokens: vec![event.token0.to_vec(), event.token1.to_vec()], factory: log.address.clone() })
+    Some(PoolEntry {
+        address: event.pool.to_vec(),
+        tokens: vec![event.token0.to_vec(), event.token1.to_vec()],
+        factory: log.address.clone(),
+        protocol: Protocol::Aerodrome,
+        params: Some(Params::Aerodrome(AerodromeParams { stable: event.stable })),
+    })
 }
@@ -1,10 +1,15 @@
 use crate::PoolEntry;
+use common::dispatch::LogMatcher;
+use proto::pb::dex::foundational_store::v1::Protocol;
 use substreams_abis::dex::balancer;
 use substreams_ethereum::pb::eth::v2::Log;
 use substreams_ethereum::Event;
 
+/// Pool-creation events decoded by `collect_balancer`.
+pub const EVENTS: &[LogMatcher] = &[balancer::v3::vault::events::PoolRegistered::match_log];
+
 pub fn collect_balancer(log: &Log) -> Option<PoolEntry> {
     let event = balancer::v3::vault::events::PoolRegistered::match_and_decode(log)?;
     let tokens = event.token_config.iter().map(|(token, ..)| token.to_vec()).collect();
-    Some(PoolEntry { address: event.pool.to_vec(), tokens, factory: event.factory.to_vec() })
+    Some(PoolEntry::new(event.pool.to_vec(), tokens, event.factory.to_vec(), Protocol::Balancer))
 }
@@ -1,18 +1,27 @@
 use crate::PoolEntry;
+use common::dispatch::LogMatcher;
+use proto::pb::dex::foundational_store::v1::Protocol;
 use substreams_abis::dex::bancor::{bancorconverterfactory, contractfeatures, converterfactory};
 use substreams_ethereum::pb::eth::v2::Log;
 use substreams_ethereum::Event;
 
+/// Pool-creation events decoded by `collect_bancor`.
+pub const EVENTS: &[LogMatcher] = &[
+    converterfactory::events::NewConverter::match_log,
+    bancorconverterfactory::events::NewConverter::match_log,
+    contractfeatures::events::FeaturesAddition::match_log,
+];
+
 pub fn collect_bancor(log: &Log) -> Option<PoolEntry> {
     if let Some(event) = converterfactory::events::NewConverter::match_and_decode(log) {
-        return Some(PoolEntry { address: event.converter.to_vec(), tokens: vec![], factory: log.address.clone() });
+        return Some(PoolEntry::new(event.converter.to_vec(), vec![], log.address.clone(), Protocol::Bancor));
     }
     // Legacy BancorConverterFactory
     if let Some(event) = bancorconverterfactory::events::NewConverter::match_and_decode(log) {
-        return Some(PoolEntry { address: event.converter.to_vec(), tokens: vec![], factory: log.address.clone() });
+        return Some(PoolEntry::new(event.converter.to_vec(), vec![], log.address.clone(), Protocol::Bancor));
     }
     if let Some(event) = contractfeatures::events::FeaturesAddition::match_and_decode(log) {
-        return Some(PoolEntry { address: event.address.to_vec(), tokens: vec![], factory: log.address.clone() });
+        return Some(PoolEntry::new(event.address.to_vec(), vec![], log.address.clone(), Protocol::Bancor));
     }
     None
 }
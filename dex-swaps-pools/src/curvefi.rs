@@ -1,4 +1,6 @@
 use crate::PoolEntry;
+use common::dispatch::LogMatcher;
+use proto::pb::dex::foundational_store::v1::Protocol;
 use substreams_abis::dex::curvefi;
 use substreams_ethereum::pb::eth::v2::{CallType, Log, TransactionTrace};
 use substreams_ethereum::Event;
@@ -7,17 +9,34 @@ use substreams_ethereum::Event;
 // args as a fixed-size tail (8 static 32-byte slots) after the init bytecode.
 const STABLESWAP_CONSTRUCTOR_INPUT_LEN: usize = 32 * 8;
 
+/// Pool-creation events decoded by `collect_curvefi_factory`.
+pub const FACTORY_EVENTS: &[LogMatcher] = &[
+    curvefi::factory::events::PlainPoolDeployed::match_log,
+    curvefi::factory::events::MetaPoolDeployed::match_log,
+    curvefi::cryptoswapfactory::events::CryptoPoolDeployed::match_log,
+];
+
 /// CurveFi factory events identify the pool by the freshly-CREATE'd contract address (passed
 /// in via `create_address`), which lives in the call traces rather than the event itself.
 pub fn collect_curvefi_factory(log: &Log, create_address: Option<&[u8]>) -> Option<PoolEntry> {
     if let Some(event) = curvefi::factory::events::PlainPoolDeployed::match_and_decode(log) {
-        return Some(PoolEntry { address: create_address?.to_vec(), tokens: event.coins, factory: log.address.clone() });
+        return Some(PoolEntry::new(create_address?.to_vec(), event.coins, log.address.clone(), Protocol::Curvefi));
     }
     if let Some(event) = curvefi::factory::events::MetaPoolDeployed::match_and_decode(log) {
-        return Some(PoolEntry { address: create_address?.to_vec(), tokens: vec![event.coin], factory: log.address.clone() });
+        return Some(PoolEntry::new(
+            create_address?.to_vec(),
+            vec![event.coin],
+            log.address.clone(),
+            Protocol::Curvefi,
+        ));
     }
     if let Some(event) = curvefi::cryptoswapfactory::events::CryptoPoolDeployed::match_and_decode(log) {
-        return Some(PoolEntry { address: create_address?.to_vec(), tokens: event.coins.to_vec(), factory: log.address.clone() });
+        return Some(PoolEntry::new(
+            create_address?.to_vec(),
+            event.coins.to_vec(),
+            log.address.clone(),
+            Protocol::Curvefi,
+        ));
     }
     None
 }
@@ -32,7 +51,12 @@ pub fn collect_curvefi_init(trx: &TransactionTrace) -> Option<PoolEntry> {
     let create_call = trx.calls.iter().find(|c| c.call_type == CallType::Create as i32 && c.depth == 0)?;
     let suffix = trx.input.len().checked_sub(STABLESWAP_CONSTRUCTOR_INPUT_LEN).and_then(|start| trx.input.get(start..))?;
     let constructor = curvefi::stableswap::constructor::Constructor::decode(suffix).ok()?;
-    Some(PoolEntry { address: create_call.address.clone(), tokens: constructor.coins.into_iter().collect(), factory: vec![] })
+    Some(PoolEntry::new(
+        create_call.address.clone(),
+        constructor.coins.into_iter().collect(),
+        vec![],
+        Protocol::Curvefi,
+    ))
 }
 
 /// Whether the transaction is a direct contract deployment (root CREATE / empty `to`).
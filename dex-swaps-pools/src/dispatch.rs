@@ -0,0 +1,57 @@
+use common::dispatch::{LogMatcher, TopicDispatch};
+use substreams_ethereum::pb::eth::v2::Log;
+
+use crate::{aerodrome, balancer, bancor, curvefi, kyber, sunpump, traderjoe, uniswap, PoolEntry};
+
+/// Pool-creation collectors, in the order they were tried before the dispatch table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Collector {
+    UniswapV1,
+    UniswapV2,
+    UniswapV3,
+    UniswapV4,
+    Aerodrome,
+    KyberElastic,
+    Traderjoe,
+    Balancer,
+    Bancor,
+    Sunpump,
+    CurvefiFactory,
+}
+
+pub(crate) const COLLECTORS: &[(Collector, &[LogMatcher])] = &[
+    (Collector::UniswapV1, uniswap::V1_EVENTS),
+    (Collector::UniswapV2, uniswap::V2_EVENTS),
+    (Collector::UniswapV3, uniswap::V3_EVENTS),
+    (Collector::UniswapV4, uniswap::V4_EVENTS),
+    (Collector::Aerodrome, aerodrome::EVENTS),
+    (Collector::KyberElastic, kyber::EVENTS),
+    (Collector::Traderjoe, traderjoe::EVENTS),
+    (Collector::Balancer, balancer::EVENTS),
+    (Collector::Bancor, bancor::EVENTS),
+    (Collector::Sunpump, sunpump::EVENTS),
+    (Collector::CurvefiFactory, curvefi::FACTORY_EVENTS),
+];
+
+/// Dispatch table over every pool collector; build one per block.
+pub(crate) fn dispatch() -> TopicDispatch<Collector> {
+    TopicDispatch::new(COLLECTORS)
+}
+
+impl Collector {
+    pub(crate) fn collect(self, log: &Log, create_address: Option<&[u8]>) -> Option<PoolEntry> {
+        match self {
+            Collector::UniswapV1 => uniswap::collect_uniswap_v1(log),
+            Collector::UniswapV2 => uniswap::collect_uniswap_v2(log),
+            Collector::UniswapV3 => uniswap::collect_uniswap_v3(log),
+            Collector::UniswapV4 => uniswap::collect_uniswap_v4(log),
+            Collector::Aerodrome => aerodrome::collect_aerodrome(log),
+            Collector::KyberElastic => kyber::collect_kyber_elastic(log),
+            Collector::Traderjoe => traderjoe::collect_traderjoe(log),
+            Collector::Balancer => balancer::collect_balancer(log),
+            Collector::Bancor => bancor::collect_bancor(log),
+            Collector::Sunpump => sunpump::collect_sunpump(log),
+            Collector::CurvefiFactory => curvefi::collect_curvefi_factory(log, create_address),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Uniswap V4 hooks registry: every pool initialized with a hooks contract, with the hook
+//! permissions decoded from its address (see [`common::hooks`]).
+
+use common::hooks::{has_hooks, hook_flags, hook_permissions};
+use proto::pb::dex::foundational_store::v1::HookPool;
+use substreams::Hex;
+use substreams_abis::dex::uniswap;
+use substreams_ethereum::pb::eth::v2::Log;
+use substreams_ethereum::Event;
+
+pub fn collect_hook_pool(log: &Log) -> Option<HookPool> {
+    let event = uniswap::v4::poolmanager::events::Initialize::match_and_decode(log)?;
+    if !has_hooks(&event.hooks) {
+        return None;
+    }
+    Some(HookPool {
+        hooks: event.hooks.to_vec(),
+        pool: event.id.to_vec(),
+        factory: log.address.clone(),
+        flags: hook_flags(&event.hooks),
+        permissions: Some(hook_permissions(&event.hooks)),
+    })
+}
+
+/// `store_hooks` key: `<hooks>:<pool>`, so the pools of a hook share the `<hooks>:` prefix.
+pub fn hook_pool_key(hook_pool: &HookPool) -> String {
+    format!("{}:{}", Hex::encode(&hook_pool.hooks), Hex::encode(&hook_pool.pool))
+}
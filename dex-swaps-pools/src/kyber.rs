@@ -1,9 +1,24 @@
 use crate::PoolEntry;
+use common::bigint_to_i24;
+use common::dispatch::LogMatcher;
+use proto::pb::dex::foundational_store::v1::{pool_v2::Params, KyberElasticParams, Protocol};
 use substreams_abis::dex::kyber::elastic;
 use substreams_ethereum::pb::eth::v2::Log;
 use substreams_ethereum::Event;
 
+/// Pool-creation events decoded by `collect_kyber_elastic`.
+pub const EVENTS: &[LogMatcher] = &[elastic::factory::events::PoolCreated::match_log];
+
 pub fn collect_kyber_elastic(log: &Log) -> Option<PoolEntry> {
     let event = elastic::factory::events::PoolCreated::match_and_decode(log)?;
-    Some(PoolEntry { address: event.pool.to_vec(), tokens: vec![event.token0.to_vec(), event.token1.to_vec()], factory: log.address.clone() })
+    Some(PoolEntry {
+        address: event.pool.to_vec(),
+        tokens: vec![event.token0.to_vec(), event.token1.to_vec()],
+        factory: log.address.clone(),
+        protocol: Protocol::KyberElastic,
+        params: Some(Params::KyberElastic(KyberElasticParams {
+            swap_fee_units: event.swap_fee_units.to_u64() as u32,
+            tick_distance: bigint_to_i24(&event.tick_distance).unwrap_or_default(),
+        })),
+    })
 }
@@ -9,27 +9,50 @@
 //! Pool data is extracted directly from a single `sf.ethereum.type.v2.Block` using the
 //! `substreams-abis` event decoders — it does NOT import per-protocol spkg packages.
 //!
-//! Like the foundational store, the payload is intentionally restricted to shared pool
-//! metadata only: `tokens[]` plus `factory` when available. Protocol-specific init fields
-//! (Aerodrome `stable`, TraderJoe `bin_step`, Kyber `swap_fee_units`/`tick_distance`, …)
-//! remain available on the original pool-creation events.
+//! Two stores are written from the same decoded pools:
+//!   - `store_pools`    — `Pool` (v1): shared pool metadata only, `tokens[]` plus `factory`
+//!   - `store_pools_v2` — `PoolV2`: adds the `protocol` and its pool-creation params (Uniswap V3/V4
+//!     `fee`/`tick_spacing`/`hooks`, Aerodrome `stable`, TraderJoe `bin_step`, Kyber
+//!     `swap_fee_units`/`tick_distance`)
+//!
+//! `store_pools` is kept unchanged for existing consumers.
+//!
+//! Pools are filtered on their factory with [`FactoryFilter`] (module params, no allowlist by
+//! default so every decoded protocol is kept with `verified` unset): pools of denied
+//! factories are dropped, and so are pools of unlisted factories with `unlisted=drop`, so spoofed
+//! pools never reach `dex-swaps`. With `unlisted=tag` they are kept with `verified = false`, which
+//! `dex-swaps` copies to `Swap.verified`: `dex-swaps-prices` does not price from them and the
+//! `evm-dex` price and pool aggregates skip them.
+//!
+//! Uniswap V4 hooks are registered by `map_hooks` / `store_hooks` (see [`hooks`]).
+//!
+//! Streams started mid-chain can seed pools created earlier from a snapshot (see [`seed`]).
 //!
 //! Each protocol's pool-creation decoding lives in its own module. A log maps to at most one
 //! pool, so each collector returns a single `Option<PoolEntry>` rather than writing directly.
+//! Logs are routed to collectors through a topic0 dispatch table (see [`dispatch`]), so a log
+//! only reaches the collectors whose events it can match.
 
 mod aerodrome;
 mod balancer;
 mod bancor;
 mod curvefi;
+mod dispatch;
+mod hooks;
 mod kyber;
+mod seed;
 mod sunpump;
 mod traderjoe;
 mod uniswap;
 
-use proto::pb::dex::foundational_store::v1::{Pool, PoolCreated, Pools};
+use common::dispatch::TopicDispatch;
+use common::factories::{FactoryCheck, FactoryFilter};
+use dispatch::Collector;
+use proto::pb::dex::foundational_store::v1::{pool_v2, HookPool, HookPools, Pool, PoolCreated, PoolCreatedV2, PoolV2, Pools, PoolsV2, Protocol};
+use seed::Seed;
 use substreams::pb::substreams::store_delta::Operation;
 use substreams::store::{DeltaProto, Deltas};
-use substreams::store::{StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsProto};
+use substreams::store::{StoreGet, StoreGetInt64, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsInt64, StoreSetIfNotExistsProto};
 use substreams::Hex;
 use substreams_ethereum::pb::eth::v2::{Block, CallType, Log, TransactionTrace};
 
@@ -38,26 +61,77 @@ pub(crate) struct PoolEntry {
     pub address: Vec<u8>,
     pub tokens: Vec<Vec<u8>>,
     pub factory: Vec<u8>,
+    pub protocol: Protocol,
+    /// Pool-creation params, only for protocols that have them (see `PoolV2.params`).
+    pub params: Option<pool_v2::Params>,
 }
 
-#[substreams::handlers::store]
-pub fn store_pools(block: Block, store: StoreSetIfNotExistsProto<Pool>) {
-    for trx in block.transactions() {
-        // CurveFi direct deployments expose pool metadata via the constructor calldata,
-        // not via a log — decode it once per transaction before walking the logs.
-        if let Some(entry) = curvefi::collect_curvefi_init(trx) {
-            set_pool(&store, entry);
+impl PoolEntry {
+    /// Pool without protocol-specific params.
+    pub fn new(address: Vec<u8>, tokens: Vec<Vec<u8>>, factory: Vec<u8>, protocol: Protocol) -> Self {
+        PoolEntry {
+            address,
+            tokens,
+            factory,
+            protocol,
+            params: None,
         }
+    }
+}
 
-        // CurveFi factory events identify the pool by the freshly-CREATE'd contract address,
-        // which lives in the call traces rather than the event itself.
-        let create_address = get_create_address(trx);
+/// Records `seed_block` once the seed is written, so the pool stores can detect a missed seed.
+#[substreams::handlers::store]
+pub fn store_seed(params: String, block: Block, store: StoreSetIfNotExistsInt64) {
+    let (seed, _) = Seed::from_params(&params);
+    if seed.block() == Some(block.number) {
+        store.set_if_not_exists(0, seed::SEED_KEY, &(block.number as i64));
+    }
+}
 
-        for log in trx_logs(trx) {
-            if let Some(entry) = collect_log(log, create_address.as_deref()) {
-                set_pool(&store, entry);
-            }
-        }
+#[substreams::handlers::store]
+pub fn store_pools(params: String, block: Block, seeded: StoreGetInt64, store: StoreSetIfNotExistsProto<Pool>) {
+    let (seed, filter_params) = Seed::from_params(&params);
+    seed.check_written(block.number, seeded.get_last(seed::SEED_KEY).is_some());
+    let filter = FactoryFilter::from_params(&filter_params);
+
+    for entry in collect_pools(&seed, &block) {
+        let Some((key, verified)) = check_pool(&filter, &entry) else {
+            continue;
+        };
+        store.set_if_not_exists(
+            0,
+            key,
+            &Pool {
+                tokens: entry.tokens,
+                factory: entry.factory,
+                verified,
+            },
+        );
+    }
+}
+
+/// Same pools and params as `store_pools`, with the protocol and its pool-creation params.
+#[substreams::handlers::store]
+pub fn store_pools_v2(params: String, block: Block, seeded: StoreGetInt64, store: StoreSetIfNotExistsProto<PoolV2>) {
+    let (seed, filter_params) = Seed::from_params(&params);
+    seed.check_written(block.number, seeded.get_last(seed::SEED_KEY).is_some());
+    let filter = FactoryFilter::from_params(&filter_params);
+
+    for entry in collect_pools(&seed, &block) {
+        let Some((key, verified)) = check_pool(&filter, &entry) else {
+            continue;
+        };
+        store.set_if_not_exists(
+            0,
+            key,
+            &PoolV2 {
+                protocol: entry.protocol as i32,
+                tokens: entry.tokens,
+                factory: entry.factory,
+                verified,
+                params: entry.params,
+            },
+        );
     }
 }
 
@@ -77,37 +151,86 @@ pub fn map_pools(deltas: Deltas<DeltaProto<Pool>>) -> Pools {
     Pools { pools }
 }
 
-/// Decode the (at most one) supported pool-creation event from a single log.
-fn collect_log(log: &Log, create_address: Option<&[u8]>) -> Option<PoolEntry> {
-    uniswap::collect_uniswap_v1(log)
-        .or_else(|| uniswap::collect_uniswap_v2(log))
-        .or_else(|| uniswap::collect_uniswap_v3(log))
-        .or_else(|| uniswap::collect_uniswap_v4(log))
-        .or_else(|| aerodrome::collect_aerodrome(log))
-        .or_else(|| kyber::collect_kyber_elastic(log))
-        .or_else(|| traderjoe::collect_traderjoe(log))
-        .or_else(|| balancer::collect_balancer(log))
-        .or_else(|| bancor::collect_bancor(log))
-        .or_else(|| sunpump::collect_sunpump(log))
-        .or_else(|| curvefi::collect_curvefi_factory(log, create_address))
+/// `map_pools` for `store_pools_v2`.
+#[substreams::handlers::map]
+pub fn map_pools_v2(deltas: Deltas<DeltaProto<PoolV2>>) -> PoolsV2 {
+    let pools = deltas
+        .deltas
+        .into_iter()
+        .filter(|delta| delta.operation == Operation::Create)
+        .map(|delta| PoolCreatedV2 {
+            address: delta.key,
+            pool: Some(delta.new_value),
+        })
+        .collect();
+    PoolsV2 { pools }
+}
+
+/// Uniswap V4 pools initialized with a hooks contract in this block, filtered on their PoolManager
+/// with the same params as `store_pools`.
+#[substreams::handlers::map]
+pub fn map_hooks(params: String, block: Block) -> HookPools {
+    let (_, filter_params) = Seed::from_params(&params);
+    let filter = FactoryFilter::from_params(&filter_params);
+    let pools = block
+        .logs()
+        .filter_map(|log| hooks::collect_hook_pool(log.log))
+        .filter(|hook_pool| matches!(filter.check(&hook_pool.factory), FactoryCheck::Keep { .. }))
+        .collect();
+    HookPools { pools }
+}
+
+/// Hooks registry keyed by `<hooks>:<pool>` (hex-encoded): a prefix scan on `<hooks>:` lists the
+/// pools using a hook.
+#[substreams::handlers::store]
+pub fn store_hooks(hook_pools: HookPools, store: StoreSetIfNotExistsProto<HookPool>) {
+    for hook_pool in hook_pools.pools {
+        store.set_if_not_exists(0, hooks::hook_pool_key(&hook_pool), &hook_pool);
+    }
+}
+
+/// Every pool of this block: the seed snapshot (at `seed_block`) followed by the decoded pools.
+fn collect_pools(seed: &Seed, block: &Block) -> Vec<PoolEntry> {
+    let mut entries = seed.entries(block.number);
+    let mut table = dispatch::dispatch();
+
+    for trx in block.transactions() {
+        // CurveFi direct deployments expose pool metadata via the constructor calldata,
+        // not via a log — decode it once per transaction before walking the logs.
+        entries.extend(curvefi::collect_curvefi_init(trx));
+
+        // CurveFi factory events identify the pool by the freshly-CREATE'd contract address,
+        // which lives in the call traces rather than the event itself.
+        let create_address = get_create_address(trx);
+
+        entries.extend(
+            trx_logs(trx)
+                .into_iter()
+                .filter_map(|log| collect_log(&mut table, log, create_address.as_deref())),
+        );
+    }
+    entries
+}
+
+/// Decode the (at most one) supported pool-creation event from a single log, trying only the
+/// collectors whose events share its topic0.
+fn collect_log(table: &mut TopicDispatch<Collector>, log: &Log, create_address: Option<&[u8]>) -> Option<PoolEntry> {
+    table.decoders(log).iter().find_map(|collector| collector.collect(log, create_address))
 }
 
-/// Write a pool entry keyed by its hex-encoded address. Empty addresses are skipped.
+/// Store key (hex-encoded address) and `verified` flag of a pool entry. Empty addresses and pools
+/// rejected by the factory filter are skipped.
 ///
-/// The ordinal is always `0`: we only ever store the first value seen for a pool and never
+/// Writes always use ordinal `0`: we only ever store the first value seen for a pool and never
 /// overwrite it afterwards, so there is no need to track intra-block ordering.
-fn set_pool(store: &StoreSetIfNotExistsProto<Pool>, entry: PoolEntry) {
+fn check_pool(filter: &FactoryFilter, entry: &PoolEntry) -> Option<(String, Option<bool>)> {
     if entry.address.is_empty() {
-        return;
+        return None;
     }
-    store.set_if_not_exists(
-        0,
-        Hex::encode(&entry.address),
-        &Pool {
-            tokens: entry.tokens,
-            factory: entry.factory,
-        },
-    );
+    let FactoryCheck::Keep { verified } = filter.check(&entry.factory) else {
+        return None;
+    };
+    Some((Hex::encode(&entry.address), verified))
 }
 
 // ── Block / transaction helpers ───────────────────────────────────────────────────────────
@@ -128,3 +251,34 @@ fn get_create_address(trx: &TransactionTrace) -> Option<Vec<u8>> {
         .find(|call| call.call_type == CallType::Create as i32)
         .map(|call| call.address.to_vec())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Default `params` of a module in `substreams.yaml`.
+    fn default_params(module: &str) -> String {
+        let prefix = format!("  {}: ", module);
+        let line = include_str!("../substreams.yaml")
+            .lines()
+            .find(|line| line.starts_with(&prefix))
+            .unwrap_or_else(|| panic!("no default params for {}", module));
+        line[prefix.len()..].trim_matches('"').to_string()
+    }
+
+    #[test]
+    fn default_params_keep_every_protocol() {
+        for module in ["store_pools", "store_pools_v2"] {
+            let (_, filter_params) = Seed::from_params(&default_params(module));
+            let filter = FactoryFilter::from_params(&filter_params);
+
+            // Unregistered factories (Balancer vault, Bancor, TraderJoe, SunPump) and factory-less CurveFi pools.
+            for protocol in (1..).map_while(|value| Protocol::try_from(value).ok()) {
+                for factory in [vec![0x11; 20], vec![]] {
+                    let entry = PoolEntry::new(vec![0xaa; 20], vec![vec![0xbb; 20], vec![0xcc; 20]], factory, protocol);
+                    assert_eq!(check_pool(&filter, &entry), Some((Hex::encode([0xaa; 20]), None)), "{} {:?}", module, protocol);
+                }
+            }
+        }
+    }
+}
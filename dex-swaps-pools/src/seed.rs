@@ -0,0 +1,196 @@
+//! Pool snapshot seeding for streams started mid-chain.
+//!
+//! `store_pools` only learns pools from the creation events it processes, so pools created before
+//! its `initialBlock` are unknown to `dex-swaps`. A seed snapshot writes them once, at `seed_block`,
+//! through the same factory filter and `set_if_not_exists` policy as decoded pools.
+//!
+//! Params (alongside the factory filter params):
+//!   - `snapshot=<network>`  load the snapshot bundled in `snapshots/<network>.csv`
+//!   - `seed=<records>`      inline snapshot, records separated by `;`
+//!   - `seed_block=<number>` block at which the seed is written (set `initialBlock` to it)
+//!
+//! A record is `<pool>,<factory>,<protocol>,<token0>|<token1>|…` with hex addresses (`0x` optional),
+//! an empty factory for pools deployed without one, and tokens in pool order. The protocol uses the
+//! `evm-dex` names (`uniswap_v3`, `curvefi`, …) and sets the expected key length: Uniswap V4 pools are
+//! keyed by their 32-byte pool id. Snapshot files allow blank lines and `#` comments. Seeded pools
+//! carry no protocol-specific params in `PoolV2`.
+//!
+//! The seed is only written if `seed_block` is processed. `store_seed` records it under [`SEED_KEY`]
+//! and the pool stores panic on any later block where that key is missing, instead of silently
+//! streaming without the snapshot when `initialBlock` (or a cached start) is past `seed_block`.
+
+use crate::PoolEntry;
+use proto::pb::dex::foundational_store::v1::Protocol;
+
+/// Snapshots bundled into the module, per network.
+const SNAPSHOTS: &[(&str, &str)] = &[("mainnet", include_str!("../snapshots/mainnet.csv"))];
+
+/// Protocols accepted in snapshot records, as named in the `evm-dex` tables.
+const PROTOCOLS: &[(&str, Protocol)] = &[
+    ("sunpump", Protocol::Sunpump),
+    ("uniswap_v1", Protocol::UniswapV1),
+    ("uniswap_v2", Protocol::UniswapV2),
+    ("uniswap_v3", Protocol::UniswapV3),
+    ("uniswap_v4", Protocol::UniswapV4),
+    ("curvefi", Protocol::Curvefi),
+    ("balancer", Protocol::Balancer),
+    ("bancor", Protocol::Bancor),
+    ("aerodrome", Protocol::Aerodrome),
+    ("traderjoe", Protocol::Traderjoe),
+    ("kyber_elastic", Protocol::KyberElastic),
+];
+
+/// `store_seed` key holding the block at which the seed was written.
+pub(crate) const SEED_KEY: &str = "seed_block";
+
+/// Seed records are only parsed at `seed_block`, the params are read on every block.
+#[derive(Default)]
+pub(crate) struct Seed<'a> {
+    block: Option<u64>,
+    records: Vec<&'a str>,
+}
+
+impl<'a> Seed<'a> {
+    /// Split the seed params off `params`, returning the seed and the remaining (factory filter) params.
+    pub fn from_params(params: &'a str) -> (Self, String) {
+        let mut seed = Seed::default();
+        let mut rest = Vec::new();
+
+        for pair in params.split('&').filter(|pair| !pair.is_empty()) {
+            match pair.split_once('=') {
+                Some(("snapshot", network)) => {
+                    let Some((_, snapshot)) = SNAPSHOTS.iter().find(|(name, _)| *name == network) else {
+                        panic!("No bundled pool snapshot for network `{}`", network);
+                    };
+                    seed.records.extend(snapshot.lines());
+                }
+                Some(("seed", records)) => seed.records.extend(records.split(';')),
+                Some(("seed_block", block)) => {
+                    seed.block = Some(block.parse().unwrap_or_else(|_| panic!("Invalid seed_block `{}`", block)));
+                }
+                _ => rest.push(pair),
+            }
+        }
+        if !seed.records.is_empty() && seed.block.is_none() {
+            panic!("Pool seed requires `seed_block=<number>`");
+        }
+
+        (seed, rest.join("&"))
+    }
+
+    /// Pools to write at `block_number`: the whole seed at `seed_block`, none otherwise.
+    pub fn entries(&self, block_number: u64) -> Vec<PoolEntry> {
+        if self.block != Some(block_number) {
+            return Vec::new();
+        }
+        self.records.iter().filter_map(|record| parse_record(record)).collect()
+    }
+
+    /// Block at which the seed is written, if a seed is configured.
+    pub fn block(&self) -> Option<u64> {
+        self.block
+    }
+
+    /// Panics when `block_number` is past `seed_block` but the seed was never written, i.e. the
+    /// module started after `seed_block`.
+    pub fn check_written(&self, block_number: u64, written: bool) {
+        let Some(seed_block) = self.block else {
+            return;
+        };
+        if block_number > seed_block && !written {
+            panic!(
+                "Pool seed was never written: block {} is past seed_block {}, set the module initialBlock to seed_block",
+                block_number, seed_block
+            );
+        }
+    }
+}
+
+fn parse_record(record: &str) -> Option<PoolEntry> {
+    let record = record.trim();
+    if record.is_empty() || record.starts_with('#') {
+        return None;
+    }
+    let [pool, factory, protocol, tokens] = record.split(',').collect::<Vec<_>>()[..] else {
+        panic!("Invalid pool seed record `{}`, expected pool,factory,protocol,tokens", record);
+    };
+    let Some(&(_, protocol)) = PROTOCOLS.iter().find(|(name, _)| *name == protocol) else {
+        panic!("Unknown protocol `{}` in pool seed record `{}`", protocol, record);
+    };
+    let key_length = if protocol == Protocol::UniswapV4 { 32 } else { 20 };
+
+    Some(PoolEntry::new(
+        parse_hex(pool, key_length),
+        tokens.split('|').map(|token| parse_hex(token, 20)).collect(),
+        if factory.is_empty() { Vec::new() } else { parse_hex(factory, 20) },
+        protocol,
+    ))
+}
+
+fn parse_hex(value: &str, length: usize) -> Vec<u8> {
+    match substreams::Hex::decode(value.trim().trim_start_matches("0x")) {
+        Ok(bytes) if bytes.len() == length => bytes,
+        _ => panic!("Invalid {}-byte hex `{}` in pool seed", length, value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDC: &str = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+    const WETH: &str = "c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+    const POOL: &str = "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640";
+
+    #[test]
+    fn splits_seed_from_filter_params() {
+        let params = format!("network=mainnet&seed={},,uniswap_v3,{}|{}&seed_block=100&unlisted=tag", POOL, USDC, WETH);
+        let (seed, rest) = Seed::from_params(&params);
+
+        assert_eq!(rest, "network=mainnet&unlisted=tag");
+        assert!(seed.entries(99).is_empty());
+        let entries = seed.entries(100);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].tokens.len(), 2);
+        assert!(entries[0].factory.is_empty());
+        assert_eq!(entries[0].protocol, Protocol::UniswapV3);
+    }
+
+    #[test]
+    fn bundled_snapshot_parses() {
+        let (seed, rest) = Seed::from_params("snapshot=mainnet&seed_block=1");
+
+        assert!(rest.is_empty());
+        assert!(!seed.entries(1).is_empty());
+    }
+
+    #[test]
+    fn uniswap_v4_keys_are_pool_ids() {
+        let record = format!("{},000000000004444c5dc75cb358380d2e3de08a90,uniswap_v4,{}|{}", "11".repeat(32), USDC, WETH);
+
+        assert_eq!(parse_record(&record).unwrap().address.len(), 32);
+    }
+
+    #[test]
+    fn seed_written_at_seed_block_passes_check() {
+        let (seed, _) = Seed::from_params("snapshot=mainnet&seed_block=100");
+
+        seed.check_written(100, false);
+        seed.check_written(101, true);
+        Seed::default().check_written(101, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "past seed_block 100")]
+    fn start_past_seed_block_panics() {
+        let (seed, _) = Seed::from_params("snapshot=mainnet&seed_block=100");
+
+        seed.check_written(101, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "requires `seed_block")]
+    fn seed_without_block_panics() {
+        Seed::from_params(&format!("seed={},,uniswap_v3,{}|{}", POOL, USDC, WETH));
+    }
+}
@@ -1,14 +1,22 @@
 use crate::PoolEntry;
+use common::dispatch::LogMatcher;
+use proto::pb::dex::foundational_store::v1::Protocol;
 use substreams_abis::dex::sunpump;
 use substreams_ethereum::pb::eth::v2::Log;
 use substreams_ethereum::Event;
 
+/// Pool-creation events decoded by `collect_sunpump`.
+pub const EVENTS: &[LogMatcher] = &[
+    sunpump::v1::launchpadproxy::events::TokenCreate::match_log,
+    sunpump::legacy::launchpad::events::TokenCreate::match_log,
+];
+
 pub fn collect_sunpump(log: &Log) -> Option<PoolEntry> {
     if let Some(event) = sunpump::v1::launchpadproxy::events::TokenCreate::match_and_decode(log) {
-        return Some(PoolEntry { address: event.token_address.to_vec(), tokens: vec![], factory: log.address.clone() });
+        return Some(PoolEntry::new(event.token_address.to_vec(), vec![], log.address.clone(), Protocol::Sunpump));
     }
     if let Some(event) = sunpump::legacy::launchpad::events::TokenCreate::match_and_decode(log) {
-        return Some(PoolEntry { address: event.token_address.to_vec(), tokens: vec![], factory: log.address.clone() });
+        return Some(PoolEntry::new(event.token_address.to_vec(), vec![], log.address.clone(), Protocol::Sunpump));
     }
     None
 }
@@ -1,9 +1,22 @@
 use crate::PoolEntry;
+use common::dispatch::LogMatcher;
+use proto::pb::dex::foundational_store::v1::{pool_v2::Params, Protocol, TraderJoeParams};
 use substreams_abis::dex::traderjoe;
 use substreams_ethereum::pb::eth::v2::Log;
 use substreams_ethereum::Event;
 
+/// Pool-creation events decoded by `collect_traderjoe`.
+pub const EVENTS: &[LogMatcher] = &[traderjoe::lbfactory::events::LbPairCreated::match_log];
+
 pub fn collect_traderjoe(log: &Log) -> Option<PoolEntry> {
     let event = traderjoe::lbfactory::events::LbPairCreated::match_and_decode(log)?;
-    Some(PoolEntry { address: event.lb_pair.to_vec(), tokens: vec![event.token_x.to_vec(), event.token_y.to_vec()], factory: log.address.clone() })
+    Some(PoolEntry {
+        address: event.lb_pair.to_vec(),
+        tokens: vec![event.token_x.to_vec(), event.token_y.to_vec()],
+        factory: log.address.clone(),
+        protocol: Protocol::Traderjoe,
+        params: Some(Params::Traderjoe(TraderJoeParams {
+            bin_step: event.bin_step.to_u64() as u32,
+        })),
+    })
 }
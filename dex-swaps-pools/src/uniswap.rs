@@ -1,24 +1,68 @@
 use crate::PoolEntry;
+use common::dispatch::LogMatcher;
+use common::{bigint_to_i24, bigint_to_u64};
+use proto::pb::dex::foundational_store::v1::{pool_v2::Params, Protocol, UniswapV3Params, UniswapV4Params};
 use substreams_abis::dex::uniswap;
 use substreams_ethereum::pb::eth::v2::Log;
 use substreams_ethereum::Event;
 
+/// Pool-creation events decoded by `collect_uniswap_v1`.
+pub const V1_EVENTS: &[LogMatcher] = &[uniswap::v1::factory::events::NewExchange::match_log];
+
 pub fn collect_uniswap_v1(log: &Log) -> Option<PoolEntry> {
     let event = uniswap::v1::factory::events::NewExchange::match_and_decode(log)?;
-    Some(PoolEntry { address: event.exchange.to_vec(), tokens: vec![event.token.to_vec()], factory: log.address.clone() })
+    Some(PoolEntry::new(
+        event.exchange.to_vec(),
+        vec![event.token.to_vec()],
+        log.address.clone(),
+        Protocol::UniswapV1,
+    ))
 }
 
+/// Pool-creation events decoded by `collect_uniswap_v2`.
+pub const V2_EVENTS: &[LogMatcher] = &[uniswap::v2::factory::events::PairCreated::match_log];
+
 pub fn collect_uniswap_v2(log: &Log) -> Option<PoolEntry> {
     let event = uniswap::v2::factory::events::PairCreated::match_and_decode(log)?;
-    Some(PoolEntry { address: event.pair.to_vec(), tokens: vec![event.token0.to_vec(), event.token1.to_vec()], factory: log.address.clone() })
+    Some(PoolEntry::new(
+        event.pair.to_vec(),
+        vec![event.token0.to_vec(), event.token1.to_vec()],
+        log.address.clone(),
+        Protocol::UniswapV2,
+    ))
 }
 
+/// Pool-creation events decoded by `collect_uniswap_v3`.
+pub const V3_EVENTS: &[LogMatcher] = &[uniswap::v3::factory::events::PoolCreated::match_log];
+
 pub fn collect_uniswap_v3(log: &Log) -> Option<PoolEntry> {
     let event = uniswap::v3::factory::events::PoolCreated::match_and_decode(log)?;
-    Some(PoolEntry { address: event.pool.to_vec(), tokens: vec![event.token0.to_vec(), event.token1.to_vec()], factory: log.address.clone() })
+    Some(PoolEntry {
+        address: event.pool.to_vec(),
+        tokens: vec![event.token0.to_vec(), event.token1.to_vec()],
+        factory: log.address.clone(),
+        protocol: Protocol::UniswapV3,
+        params: Some(Params::UniswapV3(UniswapV3Params {
+            fee: bigint_to_u64(&event.fee).unwrap_or_default() as u32,
+            tick_spacing: bigint_to_i24(&event.tick_spacing).unwrap_or_default(),
+        })),
+    })
 }
 
+/// Pool-creation events decoded by `collect_uniswap_v4`.
+pub const V4_EVENTS: &[LogMatcher] = &[uniswap::v4::poolmanager::events::Initialize::match_log];
+
 pub fn collect_uniswap_v4(log: &Log) -> Option<PoolEntry> {
     let event = uniswap::v4::poolmanager::events::Initialize::match_and_decode(log)?;
-    Some(PoolEntry { address: event.id.to_vec(), tokens: vec![event.currency0.to_vec(), event.currency1.to_vec()], factory: log.address.clone() })
+    Some(PoolEntry {
+        address: event.id.to_vec(),
+        tokens: vec![event.currency0.to_vec(), event.currency1.to_vec()],
+        factory: log.address.clone(),
+        protocol: Protocol::UniswapV4,
+        params: Some(Params::UniswapV4(UniswapV4Params {
+            fee: bigint_to_u64(&event.fee).unwrap_or_default() as u32,
+            tick_spacing: bigint_to_i24(&event.tick_spacing).unwrap_or_default(),
+            hooks: event.hooks.to_vec(),
+        })),
+    })
 }
@@ -0,0 +1,167 @@
+//! Quote tokens used to price swaps, selected per network through the module params.
+//!
+//! Params are `&`-separated `key=value` pairs:
+//!   - `network=<name>`              load the built-in quote tokens for that network
+//!   - `native=<address>`            wrapped native token used for the one-hop fallback
+//!   - `quote=<address>:<decimals>`  USD-pegged quote token (repeatable)
+//!
+//! `native` and `quote` are applied after `network`, so they override or extend the defaults.
+
+use std::collections::HashMap;
+
+use substreams::Hex;
+
+/// Built-in quote tokens per network: (wrapped native, [(stablecoin, decimals)]).
+const NETWORKS: &[(&str, &str, &[(&str, u32)])] = &[
+    (
+        "mainnet",
+        "c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", // WETH
+        &[
+            ("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", 6),  // USDC
+            ("dac17f958d2ee523a2206206994597c13d831ec7", 6),  // USDT
+            ("6b175474e89094c44da98b954eedeac495271d0f", 18), // DAI
+        ],
+    ),
+    (
+        "base",
+        "4200000000000000000000000000000000000006", // WETH
+        &[
+            ("833589fcd6edb6e08f4c7c32d4f71b54bda02913", 6),  // USDC
+            ("d9aaec86b65d86f6a7b5b1b0c42ffa531710b6ca", 6),  // USDbC
+            ("50c5725949a6f0c72e6c4a641f24049a917db0cb", 18), // DAI
+        ],
+    ),
+    (
+        "arbitrum-one",
+        "82af49447d8a07e3bd95bd0d56f35241523fbab1", // WETH
+        &[
+            ("af88d065e77c8cc2239327c5edb3a432268e5831", 6), // USDC
+            ("ff970a61a04b1ca14834a43f5de4533ebddb5cc8", 6), // USDC.e
+            ("fd086bc7cd5c481dcc9c85ebe478a1c0b69fcbb9", 6), // USDT
+        ],
+    ),
+    (
+        "optimism",
+        "4200000000000000000000000000000000000006", // WETH
+        &[
+            ("0b2c639c533813f4aa9d7837caf62653d097ff85", 6), // USDC
+            ("94b008aa00579c1307b0ef2c499ad98a8ce58e58", 6), // USDT
+        ],
+    ),
+    (
+        "matic",
+        "0d500b1d8e8ef31e21c99d1db9a6444d3adf1270", // WPOL
+        &[
+            ("3c499c542cef5e3811e1192ce70d8cc03d5c3359", 6), // USDC
+            ("2791bca1f2de4661ed88a30c99a7a9449aa84174", 6), // USDC.e
+            ("c2132d05d31c914a87c6611c10748aeb04b58e8f", 6), // USDT
+        ],
+    ),
+    (
+        "bsc",
+        "bb4cdb9cbd36b01bd1cbaebf2de08d9173bc095c", // WBNB
+        &[
+            ("55d398326f99059ff775485246999027b3197955", 18), // USDT
+            ("8ac76a51cc950d9822d68b83fe1ad97b32cd580d", 18), // USDC
+            ("e9e7cea3dedca5984780bafc599bd69add087d56", 18), // BUSD
+        ],
+    ),
+    (
+        "avalanche",
+        "b31f66aa3c1e785363f0875a1b74e27b85fd66c7", // WAVAX
+        &[
+            ("b97ef9ef8734c71904d8002f8b6bc66dd9c48a6e", 6), // USDC
+            ("9702230a8ea53601f5cd2dc00fdbc13d4df4a8c7", 6), // USDT
+        ],
+    ),
+    (
+        "unichain",
+        "4200000000000000000000000000000000000006", // WETH
+        &[
+            ("078d782b760474a361dda0af3839290b0ef57ad6", 6), // USDC
+        ],
+    ),
+];
+
+#[derive(Debug, Default, PartialEq)]
+pub struct PricingConfig {
+    pub native: Option<Vec<u8>>,
+    /// USD-pegged quote token address -> decimals
+    pub quotes: HashMap<Vec<u8>, u32>,
+}
+
+impl PricingConfig {
+    pub fn from_params(params: &str) -> Self {
+        let mut config = PricingConfig::default();
+
+        for (key, value) in params.split('&').filter(|pair| !pair.is_empty()).map(|pair| {
+            pair.split_once('=')
+                .unwrap_or_else(|| panic!("Invalid pricing parameter `{}`, expected key=value", pair))
+        }) {
+            match key {
+                "network" => {
+                    let Some((_, native, quotes)) = NETWORKS.iter().find(|(network, _, _)| *network == value) else {
+                        panic!("Unsupported pricing network `{}`", value);
+                    };
+                    config.native = Some(parse_address(native));
+                    config
+                        .quotes
+                        .extend(quotes.iter().map(|(address, decimals)| (parse_address(address), *decimals)));
+                }
+                "native" => config.native = Some(parse_address(value)),
+                "quote" => {
+                    let Some((address, decimals)) = value.split_once(':') else {
+                        panic!("Invalid quote `{}`, expected <address>:<decimals>", value);
+                    };
+                    let decimals = decimals.parse::<u32>().ok().filter(|decimals| *decimals <= 18);
+                    let Some(decimals) = decimals else {
+                        panic!("Invalid quote decimals in `{}`, expected 0..=18", value);
+                    };
+                    config.quotes.insert(parse_address(address), decimals);
+                }
+                _ => panic!("Unknown pricing parameter `{}`, supported: network, native, quote", key),
+            }
+        }
+
+        config
+    }
+
+    pub fn quote_decimals(&self, token: &[u8]) -> Option<u32> {
+        self.quotes.get(token).copied()
+    }
+
+    pub fn is_native(&self, token: &[u8]) -> bool {
+        self.native.as_deref() == Some(token)
+    }
+}
+
+fn parse_address(address: &str) -> Vec<u8> {
+    match Hex::decode(address.trim_start_matches("0x")) {
+        Ok(bytes) if bytes.len() == 20 => bytes,
+        _ => panic!("Invalid address `{}` in pricing parameters", address),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_defaults_with_overrides() {
+        let config = PricingConfig::from_params("network=mainnet&quote=0x0000000000000000000000000000000000000001:18");
+        assert!(config.is_native(&Hex::decode("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2").unwrap()));
+        assert_eq!(
+            config.quote_decimals(&Hex::decode("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").unwrap()),
+            Some(6)
+        );
+        assert_eq!(
+            config.quote_decimals(&Hex::decode("0000000000000000000000000000000000000001").unwrap()),
+            Some(18)
+        );
+    }
+
+    #[test]
+    fn empty_params_disable_pricing() {
+        assert_eq!(PricingConfig::from_params(""), PricingConfig::default());
+    }
+}
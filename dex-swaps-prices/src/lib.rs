@@ -0,0 +1,143 @@
+//! USD pricing of normalized DEX swaps (`dex.swaps.v1.Events`).
+//!
+//! Prices are derived from the swaps themselves, against the quote tokens configured in
+//! [`config`] (USD-pegged stablecoins plus the wrapped native token):
+//!   1. `store_native_price` keeps the USD price of the wrapped native token, from its swaps
+//!      against a stablecoin.
+//!   2. `store_prices` keeps the last trade price of every token swapped against a stablecoin,
+//!      or against the wrapped native token (one hop through `store_native_price`).
+//!   3. `map_events` re-emits the swaps with `input_usd` / `output_usd` set.
+//!
+//! Prices are stored as USD per *raw* token unit, so the decimals of the priced token are
+//! never needed — only those of the stablecoins, which come with the configuration.
+
+mod config;
+
+use std::str::FromStr;
+
+use config::PricingConfig;
+use proto::pb::dex::swaps::v1 as pb;
+use substreams::scalar::BigDecimal;
+use substreams::store::{StoreGet, StoreGetBigDecimal, StoreNew, StoreSet, StoreSetBigDecimal};
+use substreams::Hex;
+
+const NATIVE_KEY: &str = "native";
+
+/// Significant digits kept for stored prices and USD values.
+const PRICE_PRECISION: u64 = 40;
+
+#[substreams::handlers::store]
+pub fn store_native_price(params: String, events: pb::Events, store: StoreSetBigDecimal) {
+    let config = PricingConfig::from_params(&params);
+
+    for (ordinal, swap) in swaps(&events) {
+        for (token, amount, other, other_amount) in sides(swap) {
+            if !config.is_native(token) {
+                continue;
+            }
+            let Some(usd) = stable_value(&config, other, other_amount) else {
+                continue;
+            };
+            if let Some(price) = unit_price(&usd, amount) {
+                store.set(ordinal, NATIVE_KEY, &price);
+            }
+        }
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_prices(params: String, events: pb::Events, native_price: StoreGetBigDecimal, store: StoreSetBigDecimal) {
+    let config = PricingConfig::from_params(&params);
+
+    for (ordinal, swap) in swaps(&events) {
+        for (token, amount, other, other_amount) in sides(swap) {
+            // Stablecoins are priced by definition.
+            if config.quote_decimals(token).is_some() {
+                continue;
+            }
+            let usd = match stable_value(&config, other, other_amount) {
+                Some(usd) => usd,
+                None if config.is_native(other) => {
+                    let (Some(price), Some(amount)) = (native_price.get_at(ordinal, NATIVE_KEY), parse_amount(other_amount)) else {
+                        continue;
+                    };
+                    price * amount
+                }
+                None => continue,
+            };
+            if let Some(price) = unit_price(&usd, amount) {
+                store.set(ordinal, Hex::encode(token), &price);
+            }
+        }
+    }
+}
+
+#[substreams::handlers::map]
+pub fn map_events(params: String, events: pb::Events, prices: StoreGetBigDecimal) -> Result<pb::Events, substreams::errors::Error> {
+    let config = PricingConfig::from_params(&params);
+    let mut events = events;
+
+    for log in events.transactions.iter_mut().flat_map(|tx| tx.logs.iter_mut()) {
+        let ordinal = log.ordinal;
+        let Some(pb::log::Log::Swap(swap)) = log.log.as_mut() else {
+            continue;
+        };
+        swap.input_usd = usd_value(&config, &prices, ordinal, &swap.input_token, &swap.input_amount).unwrap_or_default();
+        swap.output_usd = usd_value(&config, &prices, ordinal, &swap.output_token, &swap.output_amount).unwrap_or_default();
+    }
+
+    Ok(events)
+}
+
+fn swaps(events: &pb::Events) -> impl Iterator<Item = (u64, &pb::Swap)> {
+    events.transactions.iter().flat_map(|tx| tx.logs.iter()).filter_map(|log| match &log.log {
+        // spoofed pools (unlisted factory, `verified = false`) never set a price
+        Some(pb::log::Log::Swap(swap)) if swap.verified != Some(false) => Some((log.ordinal, swap)),
+        _ => None,
+    })
+}
+
+/// Both directions of a swap as (token, amount, counter token, counter amount).
+fn sides(swap: &pb::Swap) -> [(&[u8], &str, &[u8], &str); 2] {
+    [
+        (
+            swap.input_token.as_slice(),
+            swap.input_amount.as_str(),
+            swap.output_token.as_slice(),
+            swap.output_amount.as_str(),
+        ),
+        (
+            swap.output_token.as_slice(),
+            swap.output_amount.as_str(),
+            swap.input_token.as_slice(),
+            swap.input_amount.as_str(),
+        ),
+    ]
+}
+
+fn parse_amount(amount: &str) -> Option<BigDecimal> {
+    BigDecimal::from_str(amount).ok()
+}
+
+/// USD value of a raw stablecoin amount, `None` if `token` is not a configured quote.
+fn stable_value(config: &PricingConfig, token: &[u8], amount: &str) -> Option<BigDecimal> {
+    let decimals = config.quote_decimals(token)?;
+    Some(parse_amount(amount)? / BigDecimal::from(10u64.pow(decimals)))
+}
+
+/// USD per raw unit, given the USD value of a raw `amount`.
+fn unit_price(usd: &BigDecimal, amount: &str) -> Option<BigDecimal> {
+    let amount = parse_amount(amount)?;
+    if amount.is_zero() || usd.is_zero() {
+        return None;
+    }
+    Some((usd.clone() / amount).with_prec(PRICE_PRECISION))
+}
+
+fn usd_value(config: &PricingConfig, prices: &StoreGetBigDecimal, ordinal: u64, token: &[u8], amount: &str) -> Option<String> {
+    let usd = match stable_value(config, token, amount) {
+        Some(usd) => usd,
+        None => prices.get_at(ordinal, Hex::encode(token))? * parse_amount(amount)?,
+    };
+    Some(usd.with_prec(PRICE_PRECISION).to_string())
+}
@@ -1,20 +1,25 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::aerodrome as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
 use crate::logs::PoolMetadataMap;
-use crate::utils::is_non_zero;
+use crate::utils::{bps_to_pips, is_non_zero, net_amount, pool_pair, token_amounts, unresolved_swap};
 
-pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::pool::events::Swap::match_log,
+    abi::pool::events::Burn::match_log,
+    abi::poolfactory::events::PoolCreated::match_log,
+    abi::poolfactory::events::SetCustomFee::match_log,
+];
+
+pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::pool::events::Swap::match_and_decode(log) {
-        let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
-        };
-        let Some(token0) = pool.tokens.first() else {
-            return Vec::new();
-        };
-        let Some(token1) = pool.tokens.get(1) else {
-            return Vec::new();
+        let Some((pool, token0, token1)) = pool_pair(pools, &log.address) else {
+            let amount0 = net_amount(&event.amount0_in, &event.amount0_out);
+            let amount1 = net_amount(&event.amount1_in, &event.amount1_out);
+            return vec![unresolved_swap(pb::Protocol::Aerodrome, pools, &log.address, &event.sender, amount0, amount1)];
         };
 
         let amount0_in = event.amount0_in.to_string();
@@ -44,6 +49,27 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             input_amount,
             output_token,
             output_amount,
+            ..Default::default()
+        })];
+    }
+
+    // Aerodrome `Mint` has the same signature as Uniswap V2 `Mint`: `uniswap_v2` decodes it and labels Aerodrome pools
+    // from their stored protocol.
+
+    if let Some(event) = abi::pool::events::Burn::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, [event.amount0.to_string(), event.amount1.to_string()]);
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pb::Protocol::Aerodrome as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: tx.from.to_vec(),
+            tokens,
+            amounts,
+            liquidity: String::new(),
         })];
     }
 
@@ -52,8 +78,47 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             protocol: pb::Protocol::Aerodrome as i32,
             factory: log.address.clone(),
             pool: event.pool.to_vec(),
+            ..Default::default()
+        })];
+    }
+
+    if let Some(event) = abi::poolfactory::events::SetCustomFee::match_and_decode(log) {
+        let Some(fee) = custom_fee(event.fee.to_u64()) else {
+            return Vec::new();
+        };
+
+        return vec![pb::log::Log::SwapFee(pb::SwapFee {
+            protocol: pb::Protocol::Aerodrome as i32,
+            factory: log.address.clone(),
+            pool: event.pool.to_vec(),
+            fee,
+            ..Default::default()
         })];
     }
 
     Vec::new()
 }
+
+/// Aerodrome custom fees are in basis points (converted to hundredths of a bip); `420` is the factory's
+/// marker for a zero fee. `0` resets the pool to the stable/volatile default, which is not known from the event.
+fn custom_fee(fee: u64) -> Option<u32> {
+    const ZERO_FEE_INDICATOR: u64 = 420;
+    match fee {
+        0 => None,
+        ZERO_FEE_INDICATOR => Some(0),
+        fee => Some(bps_to_pips(fee)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_fee_is_in_hundredths_of_a_bip() {
+        // 30 bps = 0.3%
+        assert_eq!(custom_fee(30), Some(3000));
+        assert_eq!(custom_fee(420), Some(0));
+        assert_eq!(custom_fee(0), None);
+    }
+}
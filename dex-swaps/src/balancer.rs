@@ -1,14 +1,32 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::balancer as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
 use crate::logs::PoolMetadataMap;
-use crate::utils::fixed_1e18_to_bps;
+use crate::utils::{fixed_1e18_to_pips, token_amounts, unresolved_trade};
+
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::v3::vault::events::Swap::match_log,
+    abi::v3::vault::events::LiquidityAdded::match_log,
+    abi::v3::vault::events::LiquidityRemoved::match_log,
+    abi::v3::vault::events::PoolRegistered::match_log,
+    abi::v2::weightedpool::events::SwapFeePercentageChanged::match_log,
+    abi::v3::vault::events::AggregateSwapFeePercentageChanged::match_log,
+];
 
 pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::v3::vault::events::Swap::match_and_decode(log) {
         let Some(pool) = pools.get(event.pool.as_slice()) else {
-            return Vec::new();
+            return vec![unresolved_trade(
+                pb::Protocol::Balancer,
+                pools,
+                &event.pool,
+                &tx.from,
+                (&event.token_in, &event.amount_in),
+                (&event.token_out, &event.amount_out),
+            )];
         };
 
         return vec![pb::log::Log::Swap(pb::Swap {
@@ -20,6 +38,41 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
             input_amount: event.amount_in.to_string(),
             output_token: event.token_out.to_vec(),
             output_amount: event.amount_out.to_string(),
+            ..Default::default()
+        })];
+    }
+
+    if let Some(event) = abi::v3::vault::events::LiquidityAdded::match_and_decode(log) {
+        let Some(pool) = pools.get(event.pool.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, event.amounts_added_raw.iter().map(|amount| amount.to_string()));
+
+        return vec![pb::log::Log::LiquidityAdded(pb::LiquidityAdded {
+            protocol: pb::Protocol::Balancer as i32,
+            factory: pool.factory.clone(),
+            pool: event.pool.to_vec(),
+            provider: event.liquidity_provider.to_vec(),
+            tokens,
+            amounts,
+            liquidity: String::new(),
+        })];
+    }
+
+    if let Some(event) = abi::v3::vault::events::LiquidityRemoved::match_and_decode(log) {
+        let Some(pool) = pools.get(event.pool.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, event.amounts_removed_raw.iter().map(|amount| amount.to_string()));
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pb::Protocol::Balancer as i32,
+            factory: pool.factory.clone(),
+            pool: event.pool.to_vec(),
+            provider: event.liquidity_provider.to_vec(),
+            tokens,
+            amounts,
+            liquidity: String::new(),
         })];
     }
 
@@ -28,6 +81,7 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
             protocol: pb::Protocol::Balancer as i32,
             factory: event.factory.to_vec(),
             pool: event.pool.to_vec(),
+            ..Default::default()
         })];
     }
 
@@ -40,7 +94,8 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
             protocol: pb::Protocol::Balancer as i32,
             factory: pool.factory.clone(),
             pool: log.address.clone(),
-            fee: fixed_1e18_to_bps(&event.swap_fee_percentage),
+            fee: fixed_1e18_to_pips(&event.swap_fee_percentage),
+            ..Default::default()
         })];
     }
 
@@ -53,9 +108,24 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
             protocol: pb::Protocol::Balancer as i32,
             factory: pool.factory.clone(),
             pool: event.pool.to_vec(),
-            fee: fixed_1e18_to_bps(&event.aggregate_swap_fee_percentage),
+            fee: fixed_1e18_to_pips(&event.aggregate_swap_fee_percentage),
+            ..Default::default()
         })];
     }
 
     Vec::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use substreams::scalar::BigInt;
+
+    #[test]
+    fn swap_fee_percentage_is_in_hundredths_of_a_bip() {
+        // 0.3% as a 1e18 fixed-point fraction
+        assert_eq!(fixed_1e18_to_pips(&BigInt::from(3_000_000_000_000_000u64)), 3000);
+        // 0.0001% (Balancer V3 minimum swap fee)
+        assert_eq!(fixed_1e18_to_pips(&BigInt::from(1_000_000_000_000u64)), 1);
+    }
+}
@@ -1,3 +1,4 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::bancor::bancorconverterfactory;
 use substreams_abis::dex::bancor::converterfactory;
@@ -5,11 +6,29 @@ use substreams_abis::dex::bancor::standardpoolconverter as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
 use crate::logs::PoolMetadataMap;
+use crate::utils::unresolved_trade;
+
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::events::Conversion::match_log,
+    abi::events::ConversionFeeUpdate::match_log,
+    abi::events::LiquidityAdded::match_log,
+    abi::events::LiquidityRemoved::match_log,
+    converterfactory::events::NewConverter::match_log,
+    bancorconverterfactory::events::NewConverter::match_log,
+];
 
 pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::events::Conversion::match_and_decode(log) {
         let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
+            return vec![unresolved_trade(
+                pb::Protocol::Bancor,
+                pools,
+                &log.address,
+                &event.trader,
+                (&event.source_token, &event.source_amount),
+                (&event.target_token, &event.target_amount),
+            )];
         };
 
         return vec![pb::log::Log::Swap(pb::Swap {
@@ -21,9 +40,11 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             input_amount: event.source_amount.to_string(),
             output_token: event.target_token.to_vec(),
             output_amount: event.target_amount.to_string(),
+            ..Default::default()
         })];
     }
 
+    // Conversion fees are in ppm (`PPM_RESOLUTION = 1e6`), already hundredths of a bip.
     if let Some(event) = abi::events::ConversionFeeUpdate::match_and_decode(log) {
         let Some(pool) = pools.get(log.address.as_slice()) else {
             return Vec::new();
@@ -34,6 +55,39 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             factory: pool.factory.clone(),
             pool: log.address.clone(),
             fee: event.new_fee.to_u64() as u32,
+            ..Default::default()
+        })];
+    }
+
+    if let Some(event) = abi::events::LiquidityAdded::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+
+        return vec![pb::log::Log::LiquidityAdded(pb::LiquidityAdded {
+            protocol: pb::Protocol::Bancor as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: event.provider.to_vec(),
+            tokens: vec![event.reserve_token.to_vec()],
+            amounts: vec![event.amount.to_string()],
+            liquidity: String::new(),
+        })];
+    }
+
+    if let Some(event) = abi::events::LiquidityRemoved::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pb::Protocol::Bancor as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: event.provider.to_vec(),
+            tokens: vec![event.reserve_token.to_vec()],
+            amounts: vec![event.amount.to_string()],
+            liquidity: String::new(),
         })];
     }
 
@@ -42,6 +96,7 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             protocol: pb::Protocol::Bancor as i32,
             factory: log.address.clone(),
             pool: event.converter.to_vec(),
+            ..Default::default()
         })];
     }
 
@@ -50,6 +105,7 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             protocol: pb::Protocol::Bancor as i32,
             factory: log.address.clone(),
             pool: event.converter.to_vec(),
+            ..Default::default()
         })];
     }
 
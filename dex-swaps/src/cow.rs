@@ -1,7 +1,11 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::cow::gpv2settlement as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[abi::events::Trade::match_log];
+
 pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log) -> Vec<pb::log::Log> {
     let Some(event) = abi::events::Trade::match_and_decode(log) else {
         return Vec::new();
@@ -16,5 +20,6 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log) -> Vec<pb::log::Log>
         input_amount: event.sell_amount.to_string(),
         output_token: event.buy_token.to_vec(),
         output_amount: event.buy_amount.to_string(),
+        ..Default::default()
     })]
 }
@@ -1,13 +1,39 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
+use substreams::scalar::BigInt;
 use substreams_abis::dex::curvefi as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
 use crate::logs::PoolMetadataMap;
+use crate::utils::{token_amounts, unresolved_trade};
+
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::pool::events::TokenExchange::match_log,
+    abi::cryptoswap::events::TokenExchange::match_log,
+    abi::pool::events::AddLiquidity::match_log,
+    abi::pool::events::RemoveLiquidity::match_log,
+    abi::pool::events::RemoveLiquidityImbalance::match_log,
+    abi::cryptoswap::events::AddLiquidity::match_log,
+    abi::cryptoswap::events::RemoveLiquidity::match_log,
+    abi::cryptoswap::events::RemoveLiquidityOne::match_log,
+    abi::pool::events::NewFee::match_log,
+    abi::factory::events::PlainPoolDeployed::match_log,
+    abi::factory::events::MetaPoolDeployed::match_log,
+];
 
 pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::pool::events::TokenExchange::match_and_decode(log) {
         let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
+            // Sold / bought coins, whose addresses are unknown.
+            return vec![unresolved_trade(
+                pb::Protocol::Curvefi,
+                pools,
+                &log.address,
+                &event.buyer,
+                (&[], &event.tokens_sold),
+                (&[], &event.tokens_bought),
+            )];
         };
         let Some(input_token) = parse_curve_token(&pool.tokens, &event.sold_id.to_string()) else {
             return Vec::new();
@@ -25,12 +51,21 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             input_amount: event.tokens_sold.to_string(),
             output_token,
             output_amount: event.tokens_bought.to_string(),
+            ..Default::default()
         })];
     }
 
     if let Some(event) = abi::cryptoswap::events::TokenExchange::match_and_decode(log) {
         let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
+            // Sold / bought coins, whose addresses are unknown.
+            return vec![unresolved_trade(
+                pb::Protocol::Curvefi,
+                pools,
+                &log.address,
+                &event.buyer,
+                (&[], &event.tokens_sold),
+                (&[], &event.tokens_bought),
+            )];
         };
         let Some(input_token) = parse_curve_token(&pool.tokens, &event.sold_id.to_string()) else {
             return Vec::new();
@@ -48,22 +83,118 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             input_amount: event.tokens_sold.to_string(),
             output_token,
             output_amount: event.tokens_bought.to_string(),
+            ..Default::default()
         })];
     }
 
-    if let Some(event) = abi::pool::events::CommitNewFee::match_and_decode(log) {
+    if let Some(event) = abi::pool::events::AddLiquidity::match_and_decode(log) {
         let Some(pool) = pools.get(log.address.as_slice()) else {
             return Vec::new();
         };
+        let (tokens, amounts) = token_amounts(&pool.tokens, event.token_amounts.iter().map(|amount| amount.to_string()));
 
-        return vec![pb::log::Log::SwapFee(pb::SwapFee {
+        return vec![pb::log::Log::LiquidityAdded(pb::LiquidityAdded {
+            protocol: pb::Protocol::Curvefi as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: event.provider,
+            tokens,
+            amounts,
+            liquidity: String::new(),
+        })];
+    }
+
+    if let Some(event) = abi::pool::events::RemoveLiquidity::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, event.token_amounts.iter().map(|amount| amount.to_string()));
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pb::Protocol::Curvefi as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: event.provider,
+            tokens,
+            amounts,
+            liquidity: String::new(),
+        })];
+    }
+
+    if let Some(event) = abi::pool::events::RemoveLiquidityImbalance::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, event.token_amounts.iter().map(|amount| amount.to_string()));
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pb::Protocol::Curvefi as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: event.provider,
+            tokens,
+            amounts,
+            liquidity: String::new(),
+        })];
+    }
+
+    // The stableswap `RemoveLiquidityOne` does not report which coin was withdrawn, so it cannot be normalized.
+
+    if let Some(event) = abi::cryptoswap::events::AddLiquidity::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, event.token_amounts.iter().map(|amount| amount.to_string()));
+
+        return vec![pb::log::Log::LiquidityAdded(pb::LiquidityAdded {
+            protocol: pb::Protocol::Curvefi as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: event.provider,
+            tokens,
+            amounts,
+            liquidity: String::new(),
+        })];
+    }
+
+    if let Some(event) = abi::cryptoswap::events::RemoveLiquidity::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, event.token_amounts.iter().map(|amount| amount.to_string()));
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pb::Protocol::Curvefi as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: event.provider,
+            tokens,
+            amounts,
+            liquidity: String::new(),
+        })];
+    }
+
+    if let Some(event) = abi::cryptoswap::events::RemoveLiquidityOne::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let Some(token) = parse_curve_token(&pool.tokens, &event.coin_index.to_string()) else {
+            return Vec::new();
+        };
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
             protocol: pb::Protocol::Curvefi as i32,
             factory: pool.factory.clone(),
             pool: log.address.clone(),
-            fee: event.fee.to_u64() as u32,
+            provider: event.provider,
+            tokens: vec![token],
+            amounts: vec![event.coin_amount.to_string()],
+            liquidity: String::new(),
         })];
     }
 
+    // `CommitNewFee` only schedules a fee: it takes effect when applied, which emits `NewFee`.
+
     if let Some(event) = abi::pool::events::NewFee::match_and_decode(log) {
         let Some(pool) = pools.get(log.address.as_slice()) else {
             return Vec::new();
@@ -73,7 +204,8 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             protocol: pb::Protocol::Curvefi as i32,
             factory: pool.factory.clone(),
             pool: log.address.clone(),
-            fee: event.fee.to_u64() as u32,
+            fee: curve_fee(&event.fee),
+            ..Default::default()
         })];
     }
 
@@ -84,12 +216,14 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
                 protocol: pb::Protocol::Curvefi as i32,
                 factory: log.address.clone(),
                 pool: pool.clone(),
+                ..Default::default()
             }),
             pb::log::Log::SwapFee(pb::SwapFee {
                 protocol: pb::Protocol::Curvefi as i32,
                 factory: log.address.clone(),
                 pool,
-                fee: event.fee.to_u64() as u32,
+                fee: curve_fee(&event.fee),
+                ..Default::default()
             }),
         ];
     }
@@ -101,12 +235,14 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
                 protocol: pb::Protocol::Curvefi as i32,
                 factory: log.address.clone(),
                 pool: pool.clone(),
+                ..Default::default()
             }),
             pb::log::Log::SwapFee(pb::SwapFee {
                 protocol: pb::Protocol::Curvefi as i32,
                 factory: log.address.clone(),
                 pool,
-                fee: event.fee.to_u64() as u32,
+                fee: curve_fee(&event.fee),
+                ..Default::default()
             }),
         ];
     }
@@ -122,3 +258,21 @@ fn parse_curve_token(tokens: &[Vec<u8>], index: &str) -> Option<Vec<u8>> {
 fn get_create_address(tx: &TransactionTrace) -> Option<Vec<u8>> {
     tx.calls.iter().find(|call| call.state_reverted == false && !call.address.is_empty()).map(|call| call.address.clone())
 }
+
+/// Curve fees are fractions of `FEE_DENOMINATOR = 1e10`, converted to hundredths of a bip (1e-6).
+fn curve_fee(fee: &BigInt) -> u32 {
+    (fee.to_u64() / 10_000) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fee_is_in_hundredths_of_a_bip() {
+        // 3pool 0.015% fee: 1_500_000 / 1e10
+        assert_eq!(curve_fee(&BigInt::from(1_500_000u64)), 150);
+        // 0.04%
+        assert_eq!(curve_fee(&BigInt::from(4_000_000u64)), 400);
+    }
+}
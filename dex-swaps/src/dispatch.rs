@@ -0,0 +1,135 @@
+use common::dispatch::{LogMatcher, TopicDispatch};
+use proto::pb::dex::swaps::v1 as pb;
+use substreams_ethereum::pb::eth::v2::{Log, TransactionTrace};
+
+use crate::logs::PoolMetadataMap;
+use crate::{aerodrome, balancer, bancor, cow, curvefi, dodo, kyber_elastic, sunpump, traderjoe, uniswap_v1, uniswap_v2, uniswap_v3, uniswap_v4, woofi};
+
+/// Protocol decoders, in the order their events are emitted for a single log.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Decoder {
+    UniswapV1,
+    UniswapV2,
+    UniswapV3,
+    UniswapV4,
+    Curvefi,
+    Balancer,
+    Bancor,
+    Cow,
+    Aerodrome,
+    Dodo,
+    Woofi,
+    Traderjoe,
+    KyberElastic,
+    Sunpump,
+}
+
+pub(crate) const DECODERS: &[(Decoder, &[LogMatcher])] = &[
+    (Decoder::UniswapV1, uniswap_v1::EVENTS),
+    (Decoder::UniswapV2, uniswap_v2::EVENTS),
+    (Decoder::UniswapV3, uniswap_v3::EVENTS),
+    (Decoder::UniswapV4, uniswap_v4::EVENTS),
+    (Decoder::Curvefi, curvefi::EVENTS),
+    (Decoder::Balancer, balancer::EVENTS),
+    (Decoder::Bancor, bancor::EVENTS),
+    (Decoder::Cow, cow::EVENTS),
+    (Decoder::Aerodrome, aerodrome::EVENTS),
+    (Decoder::Dodo, dodo::EVENTS),
+    (Decoder::Woofi, woofi::EVENTS),
+    (Decoder::Traderjoe, traderjoe::EVENTS),
+    (Decoder::KyberElastic, kyber_elastic::EVENTS),
+    (Decoder::Sunpump, sunpump::EVENTS),
+];
+
+/// Dispatch table over every protocol decoder; build one per block.
+pub(crate) fn dispatch() -> TopicDispatch<Decoder> {
+    TopicDispatch::new(DECODERS)
+}
+
+impl Decoder {
+    pub(crate) fn protocol(self) -> pb::Protocol {
+        match self {
+            Decoder::UniswapV1 => pb::Protocol::UniswapV1,
+            Decoder::UniswapV2 => pb::Protocol::UniswapV2,
+            Decoder::UniswapV3 => pb::Protocol::UniswapV3,
+            Decoder::UniswapV4 => pb::Protocol::UniswapV4,
+            Decoder::Curvefi => pb::Protocol::Curvefi,
+            Decoder::Balancer => pb::Protocol::Balancer,
+            Decoder::Bancor => pb::Protocol::Bancor,
+            Decoder::Cow => pb::Protocol::Cow,
+            Decoder::Aerodrome => pb::Protocol::Aerodrome,
+            Decoder::Dodo => pb::Protocol::Dodo,
+            Decoder::Woofi => pb::Protocol::Woofi,
+            Decoder::Traderjoe => pb::Protocol::Traderjoe,
+            Decoder::KyberElastic => pb::Protocol::KyberElastic,
+            Decoder::Sunpump => pb::Protocol::Sunpump,
+        }
+    }
+
+    /// Block index key of the protocol, e.g. `protocol:uniswap_v2`.
+    pub(crate) fn index_key(self) -> String {
+        format!("protocol:{}", self.protocol().as_str_name().trim_start_matches("PROTOCOL_").to_lowercase())
+    }
+
+    pub(crate) fn decode(self, tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
+        match self {
+            Decoder::UniswapV1 => uniswap_v1::decode_log(tx, log, pools),
+            Decoder::UniswapV2 => uniswap_v2::decode_log(tx, log, pools),
+            Decoder::UniswapV3 => uniswap_v3::decode_log(tx, log, pools),
+            Decoder::UniswapV4 => uniswap_v4::decode_log(tx, log, pools),
+            Decoder::Curvefi => curvefi::decode_log(tx, log, pools),
+            Decoder::Balancer => balancer::decode_log(tx, log, pools),
+            Decoder::Bancor => bancor::decode_log(tx, log, pools),
+            Decoder::Cow => cow::decode_log(tx, log),
+            Decoder::Aerodrome => aerodrome::decode_log(tx, log, pools),
+            Decoder::Dodo => dodo::decode_log(tx, log),
+            Decoder::Woofi => woofi::decode_log(tx, log),
+            Decoder::Traderjoe => traderjoe::decode_log(tx, log, pools),
+            Decoder::KyberElastic => kyber_elastic::decode_log(tx, log, pools),
+            Decoder::Sunpump => sunpump::decode_log(tx, log, pools),
+        }
+    }
+}
+
+/// Decodes `log` with only the decoders whose events share its shape.
+pub(crate) fn decode_log(table: &mut TopicDispatch<Decoder>, tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
+    table.decoders(log).iter().flat_map(|decoder| decoder.decode(tx, log, pools)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(topic0: [u8; 32], topics: usize, data: usize) -> Log {
+        let mut log_topics = vec![topic0.to_vec()];
+        log_topics.extend((1..topics).map(|_| vec![0u8; 32]));
+        Log {
+            address: vec![0x11; 20],
+            topics: log_topics,
+            data: vec![0u8; data],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn every_protocol_lists_its_events() {
+        for (decoder, events) in DECODERS {
+            assert!(!events.is_empty(), "{:?} has no events", decoder);
+        }
+    }
+
+    #[test]
+    fn index_keys_name_the_protocol() {
+        assert_eq!(Decoder::UniswapV2.index_key(), "protocol:uniswap_v2");
+        assert_eq!(Decoder::KyberElastic.index_key(), "protocol:kyber_elastic");
+    }
+
+    #[test]
+    fn unknown_logs_skip_every_decoder() {
+        let mut table = dispatch();
+        let log = log([0xee; 32], 3, 64);
+
+        assert!(table.decoders(&log).is_empty());
+        assert!(decode_log(&mut table, &TransactionTrace::default(), &log, &PoolMetadataMap::new()).is_empty());
+    }
+}
@@ -1,23 +1,34 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::kyber::elastic as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
 use crate::logs::PoolMetadataMap;
+use crate::utils::{is_other_protocol, pool_pair, unresolved_swap};
+
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[abi::pool::events::Swap::match_log, abi::factory::events::PoolCreated::match_log];
 
 pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::pool::events::Swap::match_and_decode(log) {
-        let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
-        };
-        let Some(token0) = pool.tokens.first() else {
-            return Vec::new();
-        };
-        let Some(token1) = pool.tokens.get(1) else {
+        // Same signature as Uniswap V3 `Swap`: pools missing from the store are reported by `uniswap_v3`
+        // (the first decoder), and pools of other protocols are skipped.
+        if !pools.contains_key(log.address.as_slice()) || is_other_protocol(pools, &log.address, pb::Protocol::KyberElastic) {
             return Vec::new();
-        };
-
+        }
         let amount0 = event.delta_qty0.to_string();
         let amount1 = event.delta_qty1.to_string();
+        let Some((pool, token0, token1)) = pool_pair(pools, &log.address) else {
+            return vec![unresolved_swap(
+                pb::Protocol::KyberElastic,
+                pools,
+                &log.address,
+                &event.sender,
+                amount0,
+                amount1,
+            )];
+        };
+
         let Some((input_token, input_amount, output_token, output_amount)) = signed_swap_direction(
             token0,
             token1,
@@ -36,14 +47,20 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             input_amount,
             output_token,
             output_amount,
+            ..Default::default()
         })];
     }
 
+    // Kyber Elastic `Mint`/`Burn` have the same signatures as Uniswap V3: `uniswap_v3` decodes them and labels Kyber
+    // pools from their stored protocol. `BurnRTokens` burns reinvestment tokens (accrued fees), not position
+    // liquidity, and is not a liquidity removal.
+
     if let Some(event) = abi::factory::events::PoolCreated::match_and_decode(log) {
         return vec![pb::log::Log::Initialize(pb::Initialize {
             protocol: pb::Protocol::KyberElastic as i32,
             factory: log.address.clone(),
             pool: event.pool.to_vec(),
+            ..Default::default()
         })];
     }
 
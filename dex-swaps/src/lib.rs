@@ -1,11 +1,15 @@
 mod logs;
+mod pool_states;
 mod sunpump;
+mod swap_fees;
+mod trades;
 mod utils;
 mod aerodrome;
 mod balancer;
 mod bancor;
 mod cow;
 mod curvefi;
+mod dispatch;
 mod dodo;
 mod kyber_elastic;
 mod traderjoe;
@@ -16,16 +20,46 @@ mod uniswap_v4;
 mod woofi;
 
 use common::create::{CreateLog, CreateTransaction};
+use common::dispatch::TopicDispatch;
+use common::index;
+use common::projects;
+use dispatch::Decoder;
 use logs::PoolMetadataMap;
-use proto::pb::dex::foundational_store::v1::Pool;
+use proto::pb::dex::foundational_store::v1::PoolV2;
 use proto::pb::dex::swaps::v1 as pb;
-use substreams::{errors::Error, store::{StoreGet, StoreGetProto}};
+use substreams::pb::sf::substreams::index::v1::Keys;
+use substreams::{
+    errors::Error,
+    store::{StoreGet, StoreGetInt64, StoreGetProto, StoreNew, StoreSet, StoreSetInt64},
+    Hex,
+};
 use substreams_ethereum::pb::eth::v2::{Block, Call, Log, TransactionTrace};
 
+/// Block index used as the `blockFilter` of `map_events`: `evt_sig` / `evt_addr` keys of every log,
+/// plus a `protocol:<name>` key per protocol with a decodable event in the block.
 #[substreams::handlers::map]
-pub fn map_events(block: Block, store: StoreGetProto<Pool>) -> Result<pb::Events, Error> {
+pub fn index_events(block: Block) -> Result<Keys, Error> {
+    let mut table = dispatch::dispatch();
+    let mut keys = index::block_keys(&block).keys;
+    for log in block.logs() {
+        keys.extend(table.decoders(log.log).iter().map(|decoder| decoder.index_key()));
+    }
+    keys.sort();
+    keys.dedup();
+
+    Ok(Keys { keys })
+}
+
+#[substreams::handlers::map]
+pub fn map_events(params: String, block: Block, store: StoreGetProto<PoolV2>, dynamic_fees: StoreGetInt64) -> Result<pb::Events, Error> {
+    let network = parse_network(&params);
     let pools = logs::get_pools_by_address(&store, &logs::collect_log_addresses(&block));
-    let transactions: Vec<pb::Transaction> = block.transactions().filter_map(|tx| process_transaction(tx, &pools)).collect();
+    let mut table = dispatch::dispatch();
+    let mut transactions: Vec<pb::Transaction> = block
+        .transactions()
+        .filter_map(|tx| process_transaction(tx, &pools, &mut table, network))
+        .collect();
+    swap_fees::normalize_swap_fees(&mut transactions, |pool| dynamic_fees.get_at(0, Hex::encode(pool)).map(|fee| fee as u32));
 
     // No transactions, so we can skip returning an empty list of transactions and just return the default Events message.
     if transactions.is_empty() {
@@ -35,7 +69,46 @@ pub fn map_events(block: Block, store: StoreGetProto<Pool>) -> Result<pb::Events
     Ok(pb::Events { transactions })
 }
 
-fn process_transaction(tx: &TransactionTrace, pools: &PoolMetadataMap) -> Option<pb::Transaction> {
+/// Last swap fee of every Uniswap V4 dynamic-fee pool. `map_events` reads it as of the previous
+/// block (`get_at(0, ..)`), so a `SwapFee` is only emitted when the fee changes.
+#[substreams::handlers::store]
+pub fn store_dynamic_fees(block: Block, store: StoreGetProto<PoolV2>, fees: StoreSetInt64) {
+    let pools = logs::get_pools_by_address(&store, &logs::collect_log_addresses(&block));
+    for log in block.logs() {
+        if let Some((pool, fee)) = uniswap_v4::dynamic_swap_fee(log.log, &pools) {
+            fees.set(log.log.ordinal, Hex::encode(pool), &(fee as i64));
+        }
+    }
+}
+
+#[substreams::handlers::map]
+pub fn map_pool_states(block: Block, store: StoreGetProto<PoolV2>) -> Result<pb::PoolStates, Error> {
+    let pools = logs::get_pools_by_address(&store, &logs::collect_log_addresses(&block));
+    let pool_states = block
+        .logs()
+        .filter_map(|log| pool_states::decode_log(log.receipt.transaction, log.log, &pools))
+        .collect();
+
+    Ok(pb::PoolStates { pool_states })
+}
+
+#[substreams::handlers::map]
+pub fn map_trades(events: pb::Events) -> Result<pb::Trades, Error> {
+    let trades = events.transactions.iter().flat_map(trades::collect_trades).collect();
+
+    Ok(pb::Trades { trades })
+}
+
+/// Network of the `common::projects` registry, from `network=<name>` (empty params label nothing).
+fn parse_network(params: &str) -> &str {
+    match params.split('&').filter(|pair| !pair.is_empty()).find_map(|pair| pair.strip_prefix("network=")) {
+        Some(network) if projects::network_factories(network).is_none() => panic!("Unsupported project registry network `{}`", network),
+        Some(network) => network,
+        None => "",
+    }
+}
+
+fn process_transaction(tx: &TransactionTrace, pools: &PoolMetadataMap, table: &mut TopicDispatch<Decoder>, network: &str) -> Option<pb::Transaction> {
     let mut transaction = pb::Transaction::create_transaction(tx);
     let logs_with_calls: Vec<(&Log, Option<&Call>)> = if tx.calls.is_empty() {
         tx.receipt().logs().map(|log_view| (log_view.log, None)).collect()
@@ -44,7 +117,9 @@ fn process_transaction(tx: &TransactionTrace, pools: &PoolMetadataMap) -> Option
     };
 
     for (log, call) in logs_with_calls {
-        for event in decode_log(tx, log, pools) {
+        for mut event in dispatch::decode_log(table, tx, log, pools) {
+            set_project(&mut event, network);
+            set_verified(&mut event, pools);
             transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
         }
     }
@@ -56,27 +131,19 @@ fn process_transaction(tx: &TransactionTrace, pools: &PoolMetadataMap) -> Option
     Some(transaction)
 }
 
-fn decode_log(
-    tx: &TransactionTrace,
-    log: &Log,
-    pools: &PoolMetadataMap,
-) -> Vec<pb::log::Log> {
-    let mut events = Vec::new();
-
-    events.extend(uniswap_v1::decode_log(tx, log, pools));
-    events.extend(uniswap_v2::decode_log(tx, log, pools));
-    events.extend(uniswap_v3::decode_log(tx, log, pools));
-    events.extend(uniswap_v4::decode_log(tx, log, pools));
-    events.extend(curvefi::decode_log(tx, log, pools));
-    events.extend(balancer::decode_log(tx, log, pools));
-    events.extend(bancor::decode_log(tx, log, pools));
-    events.extend(cow::decode_log(tx, log));
-    events.extend(aerodrome::decode_log(tx, log, pools));
-    events.extend(dodo::decode_log(tx, log));
-    events.extend(woofi::decode_log(tx, log));
-    events.extend(traderjoe::decode_log(tx, log, pools));
-    events.extend(kyber_elastic::decode_log(tx, log, pools));
-    events.extend(sunpump::decode_log(tx, log, pools));
-
-    events
+/// Carries the factory allowlist flag of the pool, so consumers can skip swaps of spoofed pools kept with `unlisted=tag`.
+fn set_verified(event: &mut pb::log::Log, pools: &PoolMetadataMap) {
+    if let pb::log::Log::Swap(swap) = event {
+        swap.verified = pools.get(&swap.pool).and_then(|pool| pool.verified);
+    }
+}
+
+/// Labels fork deployments (SushiSwap, PancakeSwap, ...) that share a `Protocol` by their factory.
+fn set_project(event: &mut pb::log::Log, network: &str) {
+    match event {
+        pb::log::Log::Swap(swap) => swap.project = projects::project_label(network, &swap.factory),
+        pb::log::Log::SwapFee(swap_fee) => swap_fee.project = projects::project_label(network, &swap_fee.factory),
+        pb::log::Log::Initialize(initialize) => initialize.project = projects::project_label(network, &initialize.factory),
+        _ => {}
+    }
 }
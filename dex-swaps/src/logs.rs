@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use proto::pb::dex::foundational_store::v1::Pool;
+use proto::pb::dex::foundational_store::v1::PoolV2;
 use substreams::{
     store::{StoreGet, StoreGetProto},
     Hex,
@@ -10,7 +10,7 @@ use substreams_abis::dex::sunpump;
 use substreams_abis::dex::uniswap::v4 as uniswap_v4;
 use substreams_ethereum::{pb::eth::v2::{Block, Log}, Event};
 
-pub(crate) type PoolMetadata = Pool;
+pub(crate) type PoolMetadata = PoolV2;
 pub(crate) type PoolMetadataMap = HashMap<Vec<u8>, PoolMetadata>;
 
 pub(crate) fn collect_log_addresses(block: &Block) -> HashSet<Vec<u8>> {
@@ -32,10 +32,22 @@ fn collect_log_address(log: &Log, addresses: &mut HashSet<Vec<u8>>) {
         addresses.insert(event.id.to_vec());
     }
 
+    if let Some(event) = uniswap_v4::poolmanager::events::ModifyLiquidity::match_and_decode(log) {
+        addresses.insert(event.id.to_vec());
+    }
+
     if let Some(event) = balancer::v3::vault::events::Swap::match_and_decode(log) {
         addresses.insert(event.pool.to_vec());
     }
 
+    if let Some(event) = balancer::v3::vault::events::LiquidityAdded::match_and_decode(log) {
+        addresses.insert(event.pool.to_vec());
+    }
+
+    if let Some(event) = balancer::v3::vault::events::LiquidityRemoved::match_and_decode(log) {
+        addresses.insert(event.pool.to_vec());
+    }
+
     if let Some(event) = sunpump::v1::launchpadproxy::events::TokenPurchased::match_and_decode(log) {
         addresses.insert(event.token.to_vec());
     }
@@ -45,9 +57,9 @@ fn collect_log_address(log: &Log, addresses: &mut HashSet<Vec<u8>>) {
     }
 }
 
-/// Look up pool metadata from the legacy `store_pools` key-value store. Keys are the
-/// hex-encoded pool address (lowercase, no `0x`), matching how `store_pools` writes them.
-pub(crate) fn get_pools_by_address(store: &StoreGetProto<Pool>, addresses: &HashSet<Vec<u8>>) -> PoolMetadataMap {
+/// Look up pool metadata from the `dex-swaps-pools` `store_pools_v2` key-value store. Keys are the
+/// hex-encoded pool address (lowercase, no `0x`), matching how `store_pools_v2` writes them.
+pub(crate) fn get_pools_by_address(store: &StoreGetProto<PoolV2>, addresses: &HashSet<Vec<u8>>) -> PoolMetadataMap {
     addresses
         .iter()
         .filter_map(|address| store.get_last(Hex::encode(address)).map(|pool| (address.clone(), pool)))
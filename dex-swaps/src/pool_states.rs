@@ -0,0 +1,99 @@
+use common::bigint_to_i24;
+use proto::pb::dex::swaps::v1 as pb;
+use substreams_abis::dex::aerodrome;
+use substreams_abis::dex::traderjoe;
+use substreams_abis::dex::uniswap::{v2 as uniswap_v2, v3 as uniswap_v3, v4 as uniswap_v4};
+use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
+
+use crate::logs::PoolMetadataMap;
+use crate::utils::{pool_protocol, token_amounts};
+
+pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Option<pb::PoolState> {
+    if let Some(event) = uniswap_v2::pair::events::Sync::match_and_decode(log) {
+        let pool = pools.get(log.address.as_slice())?;
+        let (tokens, reserves) = token_amounts(&pool.tokens, [event.reserve0.to_string(), event.reserve1.to_string()]);
+        return Some(pool_state(
+            tx,
+            log,
+            pb::Protocol::UniswapV2,
+            &pool.factory,
+            &log.address,
+            pb::pool_state::State::Reserves(pb::Reserves { tokens, reserves }),
+        ));
+    }
+
+    if let Some(event) = aerodrome::pool::events::Sync::match_and_decode(log) {
+        let pool = pools.get(log.address.as_slice())?;
+        let (tokens, reserves) = token_amounts(&pool.tokens, [event.reserve0.to_string(), event.reserve1.to_string()]);
+        return Some(pool_state(
+            tx,
+            log,
+            pb::Protocol::Aerodrome,
+            &pool.factory,
+            &log.address,
+            pb::pool_state::State::Reserves(pb::Reserves { tokens, reserves }),
+        ));
+    }
+
+    // Kyber Elastic `Swap` has the same signature as Uniswap V3 `Swap`, so Kyber pools are reported by this branch
+    // and labelled from their stored protocol.
+    if let Some(event) = uniswap_v3::pool::events::Swap::match_and_decode(log) {
+        let pool = pools.get(log.address.as_slice())?;
+        return Some(pool_state(
+            tx,
+            log,
+            pool_protocol(pool, pb::Protocol::UniswapV3),
+            &pool.factory,
+            &log.address,
+            pb::pool_state::State::ConcentratedLiquidity(pb::ConcentratedLiquidity {
+                sqrt_price_x96: event.sqrt_price_x96.to_string(),
+                liquidity: event.liquidity.to_string(),
+                tick: bigint_to_i24(&event.tick).unwrap_or_default(),
+            }),
+        ));
+    }
+
+    if let Some(event) = uniswap_v4::poolmanager::events::Swap::match_and_decode(log) {
+        let pool = pools.get(event.id.as_slice())?;
+        return Some(pool_state(
+            tx,
+            log,
+            pb::Protocol::UniswapV4,
+            &pool.factory,
+            &event.id,
+            pb::pool_state::State::ConcentratedLiquidity(pb::ConcentratedLiquidity {
+                sqrt_price_x96: event.sqrt_price_x96.to_string(),
+                liquidity: event.liquidity.to_string(),
+                tick: bigint_to_i24(&event.tick).unwrap_or_default(),
+            }),
+        ));
+    }
+
+    if let Some(event) = traderjoe::lbpair::events::Swap::match_and_decode(log) {
+        let pool = pools.get(log.address.as_slice())?;
+        return Some(pool_state(
+            tx,
+            log,
+            pb::Protocol::Traderjoe,
+            &pool.factory,
+            &log.address,
+            pb::pool_state::State::ActiveBin(pb::ActiveBin {
+                active_id: event.id.to_u64() as u32,
+            }),
+        ));
+    }
+
+    None
+}
+
+fn pool_state(tx: &TransactionTrace, log: &Log, protocol: pb::Protocol, factory: &[u8], pool: &[u8], state: pb::pool_state::State) -> pb::PoolState {
+    pb::PoolState {
+        protocol: protocol as i32,
+        factory: factory.to_vec(),
+        pool: pool.to_vec(),
+        transaction_hash: tx.hash.to_vec(),
+        ordinal: log.ordinal,
+        block_index: log.block_index,
+        state: Some(state),
+    }
+}
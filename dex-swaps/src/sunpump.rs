@@ -1,14 +1,33 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::sunpump as abi;
 use substreams_abis::dex::sunpump::legacy::launchpad::events::TokenCreate as TokenCreateLegacy;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event, NULL_ADDRESS};
 
 use crate::logs::PoolMetadataMap;
+use crate::utils::{bps_to_pips, unresolved_trade};
+
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::v1::launchpadproxy::events::TokenPurchased::match_log,
+    abi::v1::launchpadproxy::events::TokenSold::match_log,
+    abi::v1::launchpadproxy::events::TokenCreate::match_log,
+    TokenCreateLegacy::match_log,
+    abi::v1::launchpadproxy::events::PurchaseFeeSet::match_log,
+    abi::v1::launchpadproxy::events::SaleFeeSet::match_log,
+];
 
 pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::v1::launchpadproxy::events::TokenPurchased::match_and_decode(log) {
         let Some(pool) = pools.get(event.token.as_slice()) else {
-            return Vec::new();
+            return vec![unresolved_trade(
+                pb::Protocol::Sunpump,
+                pools,
+                &event.token,
+                &event.buyer,
+                (&NULL_ADDRESS, &event.trx_amount),
+                (&event.token, &event.token_amount),
+            )];
         };
 
         return vec![pb::log::Log::Swap(pb::Swap {
@@ -20,12 +39,20 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             input_amount: event.trx_amount.to_string(),
             output_token: event.token.to_vec(),
             output_amount: event.token_amount.to_string(),
+            ..Default::default()
         })];
     }
 
     if let Some(event) = abi::v1::launchpadproxy::events::TokenSold::match_and_decode(log) {
         let Some(pool) = pools.get(event.token.as_slice()) else {
-            return Vec::new();
+            return vec![unresolved_trade(
+                pb::Protocol::Sunpump,
+                pools,
+                &event.token,
+                &event.seller,
+                (&event.token, &event.token_amount),
+                (&NULL_ADDRESS, &event.trx_amount),
+            )];
         };
 
         return vec![pb::log::Log::Swap(pb::Swap {
@@ -37,6 +64,7 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             input_amount: event.token_amount.to_string(),
             output_token: NULL_ADDRESS.to_vec(),
             output_amount: event.trx_amount.to_string(),
+            ..Default::default()
         })];
     }
 
@@ -45,6 +73,7 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             protocol: pb::Protocol::Sunpump as i32,
             factory: log.address.clone(),
             pool: event.token_address.to_vec(),
+            ..Default::default()
         })];
     }
 
@@ -53,15 +82,18 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             protocol: pb::Protocol::Sunpump as i32,
             factory: log.address.clone(),
             pool: event.token_address.to_vec(),
+            ..Default::default()
         })];
     }
 
+    // Launchpad purchase / sale fees are in basis points (`/ 10000`).
     if let Some(event) = abi::v1::launchpadproxy::events::PurchaseFeeSet::match_and_decode(log) {
         return vec![pb::log::Log::SwapFee(pb::SwapFee {
             protocol: pb::Protocol::Sunpump as i32,
             factory: log.address.clone(),
             pool: log.address.clone(),
-            fee: event.new_fee.to_u64() as u32,
+            fee: bps_to_pips(event.new_fee.to_u64()),
+            ..Default::default()
         })];
     }
 
@@ -70,9 +102,21 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             protocol: pb::Protocol::Sunpump as i32,
             factory: log.address.clone(),
             pool: log.address.clone(),
-            fee: event.new_fee.to_u64() as u32,
+            fee: bps_to_pips(event.new_fee.to_u64()),
+            ..Default::default()
         })];
     }
 
     Vec::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn launchpad_fee_is_in_hundredths_of_a_bip() {
+        // 100 bps = 1%
+        assert_eq!(bps_to_pips(100), 10_000);
+    }
+}
@@ -0,0 +1,102 @@
+//! Fee-change bookkeeping across a block.
+//!
+//! Every `SwapFee` applies from the ordinal of the log that set it. Uniswap V4 dynamic-fee pools
+//! report the fee charged on each `Swap` (their hooks can change it on every swap), so a `SwapFee`
+//! with the same fee as the last one of the pool is dropped, within the block and across blocks
+//! (`stored_fee` is the last fee of the previous blocks, see `store_dynamic_fees`).
+
+use std::collections::HashMap;
+
+use proto::pb::dex::swaps::v1 as pb;
+
+pub fn normalize_swap_fees(transactions: &mut Vec<pb::Transaction>, stored_fee: impl Fn(&[u8]) -> Option<u32>) {
+    let mut current: HashMap<Vec<u8>, Option<u32>> = HashMap::new();
+
+    for tx in transactions.iter_mut() {
+        tx.logs.retain_mut(|log| {
+            let ordinal = log.ordinal;
+            let Some(pb::log::Log::SwapFee(swap_fee)) = log.log.as_mut() else {
+                return true;
+            };
+            swap_fee.effective_ordinal = ordinal;
+            let last = current.entry(swap_fee.pool.clone()).or_insert_with(|| stored_fee(&swap_fee.pool));
+            let changed = *last != Some(swap_fee.fee);
+            *last = Some(swap_fee.fee);
+            changed
+        });
+    }
+    transactions.retain(|tx| !tx.logs.is_empty());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee_log(ordinal: u64, pool: u8, fee: u32) -> pb::Log {
+        pb::Log {
+            ordinal,
+            log: Some(pb::log::Log::SwapFee(pb::SwapFee {
+                pool: vec![pool],
+                fee,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    fn fees(tx: &pb::Transaction) -> Vec<(u64, u32)> {
+        tx.logs
+            .iter()
+            .filter_map(|log| match &log.log {
+                Some(pb::log::Log::SwapFee(swap_fee)) => Some((swap_fee.effective_ordinal, swap_fee.fee)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keeps_fee_changes_with_effective_ordinal() {
+        let mut transactions = vec![
+            pb::Transaction {
+                logs: vec![fee_log(1, 0xaa, 3000), fee_log(2, 0xaa, 3000), fee_log(3, 0xbb, 3000)],
+                ..Default::default()
+            },
+            pb::Transaction {
+                logs: vec![fee_log(7, 0xaa, 500), fee_log(9, 0xaa, 3000)],
+                ..Default::default()
+            },
+        ];
+        normalize_swap_fees(&mut transactions, |_| None);
+
+        assert_eq!(fees(&transactions[0]), vec![(1, 3000), (3, 3000)]);
+        assert_eq!(fees(&transactions[1]), vec![(7, 500), (9, 3000)]);
+    }
+
+    #[test]
+    fn drops_transactions_left_empty() {
+        let mut transactions = vec![
+            pb::Transaction {
+                logs: vec![fee_log(1, 0xaa, 3000)],
+                ..Default::default()
+            },
+            pb::Transaction {
+                logs: vec![fee_log(2, 0xaa, 3000)],
+                ..Default::default()
+            },
+        ];
+        normalize_swap_fees(&mut transactions, |_| None);
+
+        assert_eq!(transactions.len(), 1);
+    }
+
+    #[test]
+    fn drops_fees_unchanged_since_previous_blocks() {
+        let mut transactions = vec![pb::Transaction {
+            logs: vec![fee_log(1, 0xaa, 3000), fee_log(2, 0xaa, 500), fee_log(3, 0xbb, 3000)],
+            ..Default::default()
+        }];
+        normalize_swap_fees(&mut transactions, |pool| (pool == [0xaa]).then_some(3000));
+
+        assert_eq!(fees(&transactions[0]), vec![(2, 500), (3, 3000)]);
+    }
+}
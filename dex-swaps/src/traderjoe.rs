@@ -1,24 +1,28 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams::scalar::BigInt;
 use substreams_abis::dex::traderjoe as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
 use crate::logs::PoolMetadataMap;
-use crate::utils::is_non_zero;
+use crate::utils::{is_non_zero, net_amount, pool_pair, token_amounts, unresolved_swap};
 
-pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::lbpair::events::Swap::match_log,
+    abi::lbpair::events::DepositedToBins::match_log,
+    abi::lbpair::events::WithdrawnFromBins::match_log,
+    abi::lbfactory::events::LbPairCreated::match_log,
+];
+
+pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::lbpair::events::Swap::match_and_decode(log) {
-        let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
-        };
-        let Some(token0) = pool.tokens.first() else {
-            return Vec::new();
-        };
-        let Some(token1) = pool.tokens.get(1) else {
-            return Vec::new();
-        };
         let (amount_in_x, amount_in_y) = decode_packed_uint128(&event.amounts_in);
         let (amount_out_x, amount_out_y) = decode_packed_uint128(&event.amounts_out);
+        let Some((pool, token0, token1)) = pool_pair(pools, &log.address) else {
+            let (amount0, amount1) = net_packed_uint128(&event.amounts_in, &event.amounts_out);
+            return vec![unresolved_swap(pb::Protocol::Traderjoe, pools, &log.address, &event.sender, amount0, amount1)];
+        };
 
         let Some((input_token, input_amount, output_token, output_amount)) = (match (is_non_zero(&amount_in_x), is_non_zero(&amount_in_y)) {
             (true, false) => Some((token0.clone(), amount_in_x, token1.clone(), amount_out_y)),
@@ -37,6 +41,43 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             input_amount,
             output_token,
             output_amount,
+            ..Default::default()
+        })];
+    }
+
+    if let Some(event) = abi::lbpair::events::DepositedToBins::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (amount_x, amount_y) = sum_packed_uint128(&event.amounts);
+        let (tokens, amounts) = token_amounts(&pool.tokens, [amount_x, amount_y]);
+
+        return vec![pb::log::Log::LiquidityAdded(pb::LiquidityAdded {
+            protocol: pb::Protocol::Traderjoe as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: tx.from.to_vec(),
+            tokens,
+            amounts,
+            liquidity: String::new(),
+        })];
+    }
+
+    if let Some(event) = abi::lbpair::events::WithdrawnFromBins::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (amount_x, amount_y) = sum_packed_uint128(&event.amounts);
+        let (tokens, amounts) = token_amounts(&pool.tokens, [amount_x, amount_y]);
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pb::Protocol::Traderjoe as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: tx.from.to_vec(),
+            tokens,
+            amounts,
+            liquidity: String::new(),
         })];
     }
 
@@ -45,14 +86,101 @@ pub(crate) fn decode_log(_tx: &TransactionTrace, log: &Log, pools: &PoolMetadata
             protocol: pb::Protocol::Traderjoe as i32,
             factory: log.address.clone(),
             pool: event.lb_pair.to_vec(),
+            ..Default::default()
         })];
     }
 
     Vec::new()
 }
 
+/// Token X and token Y of a Liquidity Book `PackedUint128Math` word: X in the low 128 bits, Y in the high 128 bits.
+fn unpack_uint128(bytes: &[u8; 32]) -> (BigInt, BigInt) {
+    (BigInt::from_unsigned_bytes_be(&bytes[16..]), BigInt::from_unsigned_bytes_be(&bytes[..16]))
+}
+
+/// Signed token X and token Y pool deltas of a swap.
+fn net_packed_uint128(amounts_in: &[u8; 32], amounts_out: &[u8; 32]) -> (String, String) {
+    let ((in_x, in_y), (out_x, out_y)) = (unpack_uint128(amounts_in), unpack_uint128(amounts_out));
+    (net_amount(&in_x, &out_x), net_amount(&in_y, &out_y))
+}
+
 fn decode_packed_uint128(bytes: &[u8; 32]) -> (String, String) {
-    let x = BigInt::from_unsigned_bytes_be(&bytes[..16]);
-    let y = BigInt::from_unsigned_bytes_be(&bytes[16..]);
+    let (x, y) = unpack_uint128(bytes);
     (x.to_string(), y.to_string())
 }
+
+/// Total token X and token Y amounts across all bins touched by a deposit or withdrawal.
+fn sum_packed_uint128(amounts: &[[u8; 32]]) -> (String, String) {
+    let (x, y) = amounts
+        .iter()
+        .map(unpack_uint128)
+        .fold((BigInt::zero(), BigInt::zero()), |(x, y), (bin_x, bin_y)| (x + bin_x, y + bin_y));
+    (x.to_string(), y.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logs::PoolMetadata;
+    use substreams::Hex;
+
+    /// `LBPair.mint` into bins 8388607 (below the active bin, Y only), 8388608 (active, X and Y) and 8388609
+    /// (above, X only), each amount a `PackedUint128Math.encode(x, y)` word.
+    fn deposited_to_bins(pair: &[u8]) -> Log {
+        let word = |hex: &str| Hex::decode(format!("{:0>64}", hex)).unwrap();
+        let data = [
+            word("40"),
+            word("c0"),
+            word("3"),
+            word("7fffff"),
+            word("800000"),
+            word("800001"),
+            word("3"),
+            // 2_000_000 Y (USDC, 6 decimals) in the high 128 bits
+            word("1e848000000000000000000000000000000000"),
+            // 1 X (WAVAX, 18 decimals) in the low 128 bits, 25_000_000 Y in the high 128 bits
+            word("17d784000000000000000000de0b6b3a7640000"),
+            // 3 X only
+            word("29a2241af62c0000"),
+        ]
+        .concat();
+        Log {
+            address: pair.to_vec(),
+            topics: vec![
+                abi::lbpair::events::DepositedToBins::TOPIC_ID.to_vec(),
+                word("aa".repeat(20).as_str()),
+                word("bb".repeat(20).as_str()),
+            ],
+            data,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unpacks_token_x_from_the_low_bits() {
+        let mut packed = [0u8; 32];
+        (packed[15], packed[31]) = (2, 1);
+        assert_eq!(decode_packed_uint128(&packed), ("1".to_string(), "2".to_string()));
+    }
+
+    #[test]
+    fn deposited_to_bins_sums_x_and_y() {
+        let (pair, token_x, token_y) = (vec![0x11; 20], vec![0x22; 20], vec![0x33; 20]);
+        let pools = PoolMetadataMap::from([(
+            pair.clone(),
+            PoolMetadata {
+                protocol: pb::Protocol::Traderjoe as i32,
+                tokens: vec![token_x.clone(), token_y.clone()],
+                factory: vec![0x44; 20],
+                ..Default::default()
+            },
+        )]);
+
+        let logs = decode_log(&TransactionTrace::default(), &deposited_to_bins(&pair), &pools);
+        let [pb::log::Log::LiquidityAdded(added)] = logs.as_slice() else {
+            panic!("expected LiquidityAdded, got {:?}", logs);
+        };
+        assert_eq!(added.tokens, vec![token_x, token_y]);
+        assert_eq!(added.amounts, vec!["4000000000000000000".to_string(), "27000000".to_string()]);
+    }
+}
@@ -0,0 +1,162 @@
+//! Multi-hop trade reconstruction.
+//!
+//! Routers emit one `Swap` per pool hop, so a USDC -> WETH -> PEPE trade shows up as two swaps.
+//! Swaps of a transaction are chained when a hop spends the exact output (token and amount) of an
+//! earlier hop. Logs are visited in ordinal order, which is the route order for both sequential
+//! routers (Uniswap V2 / V3 `exactInput`) and nested callbacks (V3 `exactOutput`, where the first
+//! hop is the innermost call and emits first). When several open trades match, the one whose last
+//! hop ran at the closest call depth wins, then the most recent one.
+
+use proto::pb::dex::swaps::v1 as pb;
+
+struct Hop<'a> {
+    log: &'a pb::Log,
+    swap: &'a pb::Swap,
+}
+
+impl Hop<'_> {
+    fn depth(&self) -> Option<u32> {
+        self.log.call.as_ref().map(|call| call.depth)
+    }
+
+    /// True when `next` spends exactly what this hop returned.
+    fn feeds(&self, next: &Hop) -> bool {
+        is_amount(&self.swap.output_amount) && self.swap.output_token == next.swap.input_token && self.swap.output_amount == next.swap.input_amount
+    }
+}
+
+pub fn collect_trades(tx: &pb::Transaction) -> Vec<pb::Trade> {
+    let mut hops: Vec<Hop> = tx
+        .logs
+        .iter()
+        .filter_map(|log| match &log.log {
+            Some(pb::log::Log::Swap(swap)) => Some(Hop { log, swap }),
+            _ => None,
+        })
+        .collect();
+    hops.sort_by_key(|hop| hop.log.ordinal);
+
+    let mut routes: Vec<Vec<Hop>> = Vec::new();
+    for hop in hops {
+        let depth = hop.depth();
+        let matched = routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route)| route.last().is_some_and(|last| last.feeds(&hop)))
+            .min_by_key(|(_, route)| {
+                let last = route.last().expect("routes are never empty");
+                let distance = match (last.depth(), depth) {
+                    (Some(a), Some(b)) => a.abs_diff(b),
+                    _ => 0,
+                };
+                (distance, std::cmp::Reverse(last.log.ordinal))
+            })
+            .map(|(i, _)| i);
+
+        match matched {
+            Some(i) => routes[i].push(hop),
+            None => routes.push(vec![hop]),
+        }
+    }
+
+    routes.iter().map(|route| create_trade(&tx.hash, route)).collect()
+}
+
+fn create_trade(transaction_hash: &[u8], route: &[Hop]) -> pb::Trade {
+    let first = route.first().expect("routes are never empty");
+    let last = route.last().expect("routes are never empty");
+
+    pb::Trade {
+        transaction_hash: transaction_hash.to_vec(),
+        block_index: first.log.block_index,
+        user: first.swap.user.clone(),
+        input_token: first.swap.input_token.clone(),
+        input_amount: first.swap.input_amount.clone(),
+        output_token: last.swap.output_token.clone(),
+        output_amount: last.swap.output_amount.clone(),
+        route: std::iter::once(first.swap.input_token.clone())
+            .chain(route.iter().map(|hop| hop.swap.output_token.clone()))
+            .collect(),
+        pools: route.iter().map(|hop| hop.swap.pool.clone()).collect(),
+        protocols: route.iter().map(|hop| hop.swap.protocol).collect(),
+        ordinals: route.iter().map(|hop| hop.log.ordinal).collect(),
+    }
+}
+
+/// Empty or zero amounts never link hops, they would chain unrelated swaps.
+fn is_amount(amount: &str) -> bool {
+    !amount.trim_start_matches('0').is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap_log(ordinal: u64, depth: u32, pool: u8, input: (u8, &str), output: (u8, &str)) -> pb::Log {
+        pb::Log {
+            ordinal,
+            call: Some(pb::Call { depth, ..Default::default() }),
+            log: Some(pb::log::Log::Swap(pb::Swap {
+                pool: vec![pool],
+                input_token: vec![input.0],
+                input_amount: input.1.to_string(),
+                output_token: vec![output.0],
+                output_amount: output.1.to_string(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    fn transaction(logs: Vec<pb::Log>) -> pb::Transaction {
+        pb::Transaction { logs, ..Default::default() }
+    }
+
+    #[test]
+    fn chains_router_hops() {
+        // USDC(1) -> WETH(2) -> PEPE(3)
+        let tx = transaction(vec![swap_log(1, 2, 0xa, (1, "1000"), (2, "5")), swap_log(2, 2, 0xb, (2, "5"), (3, "700"))]);
+        let trades = collect_trades(&tx);
+
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].input_token, vec![1]);
+        assert_eq!(trades[0].input_amount, "1000");
+        assert_eq!(trades[0].output_token, vec![3]);
+        assert_eq!(trades[0].output_amount, "700");
+        assert_eq!(trades[0].route, vec![vec![1], vec![2], vec![3]]);
+        assert_eq!(trades[0].pools, vec![vec![0xa], vec![0xb]]);
+        assert_eq!(trades[0].ordinals, vec![1, 2]);
+    }
+
+    #[test]
+    fn amount_mismatch_splits_trades() {
+        let tx = transaction(vec![swap_log(1, 2, 0xa, (1, "1000"), (2, "5")), swap_log(2, 2, 0xb, (2, "4"), (3, "700"))]);
+        let trades = collect_trades(&tx);
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].route, vec![vec![1], vec![2]]);
+        assert_eq!(trades[1].route, vec![vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn prefers_closest_call_depth() {
+        // Two identical first hops at different depths, the next hop runs at the depth of the first one.
+        let tx = transaction(vec![
+            swap_log(1, 2, 0xa, (1, "10"), (2, "5")),
+            swap_log(2, 4, 0xb, (1, "10"), (2, "5")),
+            swap_log(3, 2, 0xc, (2, "5"), (3, "7")),
+        ]);
+        let trades = collect_trades(&tx);
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].pools, vec![vec![0xa], vec![0xc]]);
+        assert_eq!(trades[1].pools, vec![vec![0xb]]);
+    }
+
+    #[test]
+    fn zero_amounts_never_chain() {
+        let tx = transaction(vec![swap_log(1, 2, 0xa, (1, "10"), (2, "0")), swap_log(2, 2, 0xb, (2, "0"), (3, "7"))]);
+
+        assert_eq!(collect_trades(&tx).len(), 2);
+    }
+}
@@ -1,16 +1,32 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::uniswap::v1 as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event, NULL_ADDRESS};
 
 use crate::logs::PoolMetadataMap;
+use crate::utils::unresolved_trade;
+
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::exchange::events::TokenPurchase::match_log,
+    abi::exchange::events::EthPurchase::match_log,
+    abi::exchange::events::AddLiquidity::match_log,
+    abi::exchange::events::RemoveLiquidity::match_log,
+    abi::factory::events::NewExchange::match_log,
+];
 
 pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::exchange::events::TokenPurchase::match_and_decode(log) {
-        let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
-        };
-        let Some(token) = pool.tokens.first() else {
-            return Vec::new();
+        let Some((pool, token)) = pools.get(log.address.as_slice()).and_then(|pool| Some((pool, pool.tokens.first()?))) else {
+            // The exchange token is unknown, only the ETH side is.
+            return vec![unresolved_trade(
+                pb::Protocol::UniswapV1,
+                pools,
+                &log.address,
+                &tx.from,
+                (&NULL_ADDRESS, &event.eth_sold),
+                (&[], &event.tokens_bought),
+            )];
         };
 
         return vec![pb::log::Log::Swap(pb::Swap {
@@ -22,15 +38,20 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
             input_amount: event.eth_sold.to_string(),
             output_token: token.clone(),
             output_amount: event.tokens_bought.to_string(),
+            ..Default::default()
         })];
     }
 
     if let Some(event) = abi::exchange::events::EthPurchase::match_and_decode(log) {
-        let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
-        };
-        let Some(token) = pool.tokens.first() else {
-            return Vec::new();
+        let Some((pool, token)) = pools.get(log.address.as_slice()).and_then(|pool| Some((pool, pool.tokens.first()?))) else {
+            return vec![unresolved_trade(
+                pb::Protocol::UniswapV1,
+                pools,
+                &log.address,
+                &tx.from,
+                (&[], &event.tokens_sold),
+                (&NULL_ADDRESS, &event.eth_bought),
+            )];
         };
 
         return vec![pb::log::Log::Swap(pb::Swap {
@@ -42,6 +63,45 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
             input_amount: event.tokens_sold.to_string(),
             output_token: NULL_ADDRESS.to_vec(),
             output_amount: event.eth_bought.to_string(),
+            ..Default::default()
+        })];
+    }
+
+    if let Some(event) = abi::exchange::events::AddLiquidity::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let Some(token) = pool.tokens.first() else {
+            return Vec::new();
+        };
+
+        return vec![pb::log::Log::LiquidityAdded(pb::LiquidityAdded {
+            protocol: pb::Protocol::UniswapV1 as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: event.provider.to_vec(),
+            tokens: vec![NULL_ADDRESS.to_vec(), token.clone()],
+            amounts: vec![event.eth_amount.to_string(), event.token_amount.to_string()],
+            liquidity: String::new(),
+        })];
+    }
+
+    if let Some(event) = abi::exchange::events::RemoveLiquidity::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let Some(token) = pool.tokens.first() else {
+            return Vec::new();
+        };
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pb::Protocol::UniswapV1 as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: event.provider.to_vec(),
+            tokens: vec![NULL_ADDRESS.to_vec(), token.clone()],
+            amounts: vec![event.eth_amount.to_string(), event.token_amount.to_string()],
+            liquidity: String::new(),
         })];
     }
 
@@ -51,12 +111,14 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
                 protocol: pb::Protocol::UniswapV1 as i32,
                 factory: log.address.clone(),
                 pool: event.exchange.to_vec(),
+                ..Default::default()
             }),
             pb::log::Log::SwapFee(pb::SwapFee {
                 protocol: pb::Protocol::UniswapV1 as i32,
                 factory: log.address.clone(),
                 pool: event.exchange.to_vec(),
                 fee: 3000,
+                ..Default::default()
             }),
         ];
     }
@@ -1,20 +1,25 @@
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::uniswap::v2 as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
-use crate::logs::{PoolMetadataMap};
-use crate::utils::is_non_zero;
+use crate::logs::PoolMetadataMap;
+use crate::utils::{is_non_zero, net_amount, pool_pair, pool_protocol, token_amounts, unresolved_swap};
+
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::pair::events::Swap::match_log,
+    abi::pair::events::Mint::match_log,
+    abi::pair::events::Burn::match_log,
+    abi::factory::events::PairCreated::match_log,
+];
 
 pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::pair::events::Swap::match_and_decode(log) {
-        let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
-        };
-        let Some(token0) = pool.tokens.first() else {
-            return Vec::new();
-        };
-        let Some(token1) = pool.tokens.get(1) else {
-            return Vec::new();
+        let Some((pool, token0, token1)) = pool_pair(pools, &log.address) else {
+            let amount0 = net_amount(&event.amount0_in, &event.amount0_out);
+            let amount1 = net_amount(&event.amount1_in, &event.amount1_out);
+            return vec![unresolved_swap(pb::Protocol::UniswapV2, pools, &log.address, &tx.from, amount0, amount1)];
         };
 
         let amount0_in = event.amount0_in.to_string();
@@ -44,6 +49,42 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
             input_amount,
             output_token,
             output_amount,
+            ..Default::default()
+        })];
+    }
+
+    // Aerodrome `Mint` shares this signature, so the protocol is the stored one of the pool.
+    if let Some(event) = abi::pair::events::Mint::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, [event.amount0.to_string(), event.amount1.to_string()]);
+
+        return vec![pb::log::Log::LiquidityAdded(pb::LiquidityAdded {
+            protocol: pool_protocol(pool, pb::Protocol::UniswapV2) as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: tx.from.to_vec(),
+            tokens,
+            amounts,
+            liquidity: String::new(),
+        })];
+    }
+
+    if let Some(event) = abi::pair::events::Burn::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, [event.amount0.to_string(), event.amount1.to_string()]);
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pb::Protocol::UniswapV2 as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: tx.from.to_vec(),
+            tokens,
+            amounts,
+            liquidity: String::new(),
         })];
     }
 
@@ -53,12 +94,14 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
                 protocol: pb::Protocol::UniswapV2 as i32,
                 factory: log.address.clone(),
                 pool: event.pair.to_vec(),
+                ..Default::default()
             }),
             pb::log::Log::SwapFee(pb::SwapFee {
                 protocol: pb::Protocol::UniswapV2 as i32,
                 factory: log.address.clone(),
                 pool: event.pair.to_vec(),
                 fee: 3000,
+                ..Default::default()
             }),
         ];
     }
@@ -1,24 +1,31 @@
 use common::bigint_to_u64;
+use common::dispatch::LogMatcher;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::uniswap::v3 as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
 use crate::logs::PoolMetadataMap;
+use crate::utils::{is_non_zero, is_other_protocol, pool_pair, pool_protocol, token_amounts, unresolved_swap};
+
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::pool::events::Swap::match_log,
+    abi::pool::events::Mint::match_log,
+    abi::pool::events::Burn::match_log,
+    abi::factory::events::PoolCreated::match_log,
+];
 
 pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::pool::events::Swap::match_and_decode(log) {
-        let Some(pool) = pools.get(log.address.as_slice()) else {
-            return Vec::new();
-        };
-        let Some(token0) = pool.tokens.first() else {
+        // Kyber Elastic `Swap` shares this signature: its pools are decoded by `kyber_elastic`, unknown pools here.
+        if is_other_protocol(pools, &log.address, pb::Protocol::UniswapV3) {
             return Vec::new();
-        };
-        let Some(token1) = pool.tokens.get(1) else {
-            return Vec::new();
-        };
-
+        }
         let amount0 = event.amount0.to_string();
         let amount1 = event.amount1.to_string();
+        let Some((pool, token0, token1)) = pool_pair(pools, &log.address) else {
+            return vec![unresolved_swap(pb::Protocol::UniswapV3, pools, &log.address, &tx.from, amount0, amount1)];
+        };
         let Some((input_token, input_amount, output_token, output_amount)) =
             signed_swap_direction(token0, token1, &amount0, &amount1) else {
                 return Vec::new();
@@ -33,6 +40,47 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
             input_amount,
             output_token,
             output_amount,
+            ..Default::default()
+        })];
+    }
+
+    // Kyber Elastic `Mint`/`Burn` share these signatures, so the protocol is the stored one of the pool.
+    if let Some(event) = abi::pool::events::Mint::match_and_decode(log) {
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, [event.amount0.to_string(), event.amount1.to_string()]);
+
+        return vec![pb::log::Log::LiquidityAdded(pb::LiquidityAdded {
+            protocol: pool_protocol(pool, pb::Protocol::UniswapV3) as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: tx.from.to_vec(),
+            tokens,
+            amounts,
+            liquidity: event.amount.to_string(),
+        })];
+    }
+
+    if let Some(event) = abi::pool::events::Burn::match_and_decode(log) {
+        // `Burn` with zero liquidity only pokes the position to accrue fees before a `Collect`.
+        let liquidity = event.amount.to_string();
+        if !is_non_zero(&liquidity) {
+            return Vec::new();
+        }
+        let Some(pool) = pools.get(log.address.as_slice()) else {
+            return Vec::new();
+        };
+        let (tokens, amounts) = token_amounts(&pool.tokens, [event.amount0.to_string(), event.amount1.to_string()]);
+
+        return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+            protocol: pool_protocol(pool, pb::Protocol::UniswapV3) as i32,
+            factory: pool.factory.clone(),
+            pool: log.address.clone(),
+            provider: tx.from.to_vec(),
+            tokens,
+            amounts,
+            liquidity,
         })];
     }
 
@@ -43,12 +91,14 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
                 protocol: pb::Protocol::UniswapV3 as i32,
                 factory: log.address.clone(),
                 pool: event.pool.to_vec(),
+                ..Default::default()
             }),
             pb::log::Log::SwapFee(pb::SwapFee {
                 protocol: pb::Protocol::UniswapV3 as i32,
                 factory: log.address.clone(),
                 pool: event.pool.to_vec(),
                 fee,
+                ..Default::default()
             }),
         ];
     }
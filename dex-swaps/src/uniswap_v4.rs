@@ -1,30 +1,37 @@
 use common::bigint_to_u64;
+use common::dispatch::LogMatcher;
+use common::hooks::has_hooks;
+use proto::pb::dex::foundational_store::v1::pool_v2::Params;
 use proto::pb::dex::swaps::v1 as pb;
 use substreams_abis::dex::uniswap::v4 as abi;
 use substreams_ethereum::{pb::eth::v2::{Log, TransactionTrace}, Event};
 
-use crate::logs::PoolMetadataMap;
+use crate::logs::{PoolMetadata, PoolMetadataMap};
+use crate::utils::{pool_pair, unresolved_swap};
+
+/// `fee` of a pool whose LP fee is set by its hooks (`LPFeeLibrary.DYNAMIC_FEE_FLAG`).
+pub(crate) const DYNAMIC_FEE_FLAG: u32 = 0x800000;
+
+/// Events handled by `decode_log`, for the topic0 dispatch table.
+pub(crate) const EVENTS: &[LogMatcher] = &[
+    abi::poolmanager::events::Swap::match_log,
+    abi::poolmanager::events::ModifyLiquidity::match_log,
+    abi::poolmanager::events::Initialize::match_log,
+];
 
 pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataMap) -> Vec<pb::log::Log> {
     if let Some(event) = abi::poolmanager::events::Swap::match_and_decode(log) {
-        let Some(pool) = pools.get(event.id.as_slice()) else {
-            return Vec::new();
-        };
-        let Some(token0) = pool.tokens.first() else {
-            return Vec::new();
-        };
-        let Some(token1) = pool.tokens.get(1) else {
-            return Vec::new();
-        };
-
         let amount0 = event.amount0.to_string();
         let amount1 = event.amount1.to_string();
+        let Some((pool, token0, token1)) = pool_pair(pools, &event.id) else {
+            return vec![unresolved_swap(pb::Protocol::UniswapV4, pools, &event.id, &tx.from, amount0, amount1)];
+        };
         let Some((input_token, input_amount, output_token, output_amount)) =
             signed_swap_direction(token0, token1, &amount0, &amount1) else {
             return Vec::new();
         };
 
-        return vec![pb::log::Log::Swap(pb::Swap {
+        let mut logs = vec![pb::log::Log::Swap(pb::Swap {
             protocol: pb::Protocol::UniswapV4 as i32,
             factory: pool.factory.clone(),
             pool: event.id.to_vec(),
@@ -33,30 +40,110 @@ pub(crate) fn decode_log(tx: &TransactionTrace, log: &Log, pools: &PoolMetadataM
             input_amount,
             output_token,
             output_amount,
+            hooks: pool_hooks(pool),
+            ..Default::default()
         })];
+        // Static-fee pools keep their `Initialize` fee. `normalize_swap_fees` drops unchanged dynamic fees.
+        if is_dynamic_fee(pool) {
+            logs.push(pb::log::Log::SwapFee(pb::SwapFee {
+                protocol: pb::Protocol::UniswapV4 as i32,
+                factory: pool.factory.clone(),
+                pool: event.id.to_vec(),
+                fee: swap_fee(&event),
+                ..Default::default()
+            }));
+        }
+        return logs;
+    }
+
+    if let Some(event) = abi::poolmanager::events::ModifyLiquidity::match_and_decode(log) {
+        let Some(pool) = pools.get(event.id.as_slice()) else {
+            return Vec::new();
+        };
+        // `ModifyLiquidity` only reports the signed liquidity delta; token amounts settle through the PoolManager deltas.
+        let liquidity_delta = event.liquidity_delta.to_string();
+        let Some(liquidity) = unsigned_amount(&liquidity_delta).map(str::to_string) else {
+            return Vec::new();
+        };
+
+        if is_positive(&liquidity_delta) {
+            return vec![pb::log::Log::LiquidityAdded(pb::LiquidityAdded {
+                protocol: pb::Protocol::UniswapV4 as i32,
+                factory: pool.factory.clone(),
+                pool: event.id.to_vec(),
+                provider: tx.from.to_vec(),
+                tokens: pool.tokens.clone(),
+                amounts: Vec::new(),
+                liquidity,
+            })];
+        }
+
+        if is_negative(&liquidity_delta) {
+            return vec![pb::log::Log::LiquidityRemoved(pb::LiquidityRemoved {
+                protocol: pb::Protocol::UniswapV4 as i32,
+                factory: pool.factory.clone(),
+                pool: event.id.to_vec(),
+                provider: tx.from.to_vec(),
+                tokens: pool.tokens.clone(),
+                amounts: Vec::new(),
+                liquidity,
+            })];
+        }
+
+        return Vec::new();
     }
 
     if let Some(event) = abi::poolmanager::events::Initialize::match_and_decode(log) {
         let factory = pools.get(event.id.as_slice()).map(|pool| pool.factory.clone()).unwrap_or_default();
         let fee = bigint_to_u64(&event.fee).unwrap_or_default() as u32;
-        return vec![
-            pb::log::Log::Initialize(pb::Initialize {
-                protocol: pb::Protocol::UniswapV4 as i32,
-                factory: factory.clone(),
-                pool: event.id.to_vec(),
-            }),
-            pb::log::Log::SwapFee(pb::SwapFee {
+        let mut logs = vec![pb::log::Log::Initialize(pb::Initialize {
+            protocol: pb::Protocol::UniswapV4 as i32,
+            factory: factory.clone(),
+            pool: event.id.to_vec(),
+            ..Default::default()
+        })];
+        // The dynamic-fee flag is not a fee: those pools report their fee from their swaps.
+        if fee != DYNAMIC_FEE_FLAG {
+            logs.push(pb::log::Log::SwapFee(pb::SwapFee {
                 protocol: pb::Protocol::UniswapV4 as i32,
                 factory,
                 pool: event.id.to_vec(),
                 fee,
-            }),
-        ];
+                ..Default::default()
+            }));
+        }
+        return logs;
     }
 
     Vec::new()
 }
 
+/// Pool whose LP fee is set by its hooks, `false` when `store_pools_v2` has no V4 params for it (seeded pools).
+fn is_dynamic_fee(pool: &PoolMetadata) -> bool {
+    matches!(&pool.params, Some(Params::UniswapV4(params)) if params.fee == DYNAMIC_FEE_FLAG)
+}
+
+/// Fee charged on a swap, in hundredths of a bip: the LP fee set by the hooks plus the protocol fee
+/// (`ProtocolFeeLibrary.calculateSwapFee`), so it is not comparable with the LP fee of static-fee pools.
+fn swap_fee(event: &abi::poolmanager::events::Swap) -> u32 {
+    bigint_to_u64(&event.fee).unwrap_or_default() as u32
+}
+
+/// Pool id and swap fee of a `Swap` of a dynamic-fee pool, for `store_dynamic_fees`.
+pub(crate) fn dynamic_swap_fee(log: &Log, pools: &PoolMetadataMap) -> Option<(Vec<u8>, u32)> {
+    let event = abi::poolmanager::events::Swap::match_and_decode(log)?;
+    let pool = pools.get(event.id.as_slice())?;
+    is_dynamic_fee(pool).then(|| (event.id.to_vec(), swap_fee(&event)))
+}
+
+/// Hooks contract of a pool, empty when none or when `store_pools_v2` has no V4 params for it (seeded pools).
+fn pool_hooks(pool: &PoolMetadata) -> Vec<u8> {
+    match &pool.params {
+        Some(Params::UniswapV4(params)) if has_hooks(&params.hooks) => params.hooks.clone(),
+        _ => Vec::new(),
+    }
+}
+
 fn unsigned_amount(value: &str) -> Option<&str> {
     value.strip_prefix('-').or(Some(value)).filter(|amount| !amount.is_empty())
 }
@@ -1,10 +1,82 @@
 use common::bigint_to_u64;
+use proto::pb::dex::swaps::v1 as pb;
 use substreams::scalar::BigInt;
 
+use crate::logs::{PoolMetadata, PoolMetadataMap};
+
 pub(crate) fn is_non_zero(value: &str) -> bool {
     !value.is_empty() && value.bytes().any(|byte| byte != b'0')
 }
 
-pub(crate) fn fixed_1e18_to_bps(value: &BigInt) -> u32 {
-    bigint_to_u64(value).unwrap_or_default().saturating_div(100_000_000_000_000) as u32
+/// 1e18 fixed-point fraction to hundredths of a bip (1e-6, the `SwapFee.fee` unit).
+pub(crate) fn fixed_1e18_to_pips(value: &BigInt) -> u32 {
+    bigint_to_u64(value).unwrap_or_default().saturating_div(1_000_000_000_000) as u32
+}
+
+/// Basis points to hundredths of a bip.
+pub(crate) fn bps_to_pips(bps: u64) -> u32 {
+    bps.saturating_mul(100).min(u32::MAX as u64) as u32
+}
+
+/// Pair a pool's tokens with the per-token amounts reported by a liquidity event, in pool order.
+pub(crate) fn token_amounts(tokens: &[Vec<u8>], amounts: impl IntoIterator<Item = String>) -> (Vec<Vec<u8>>, Vec<String>) {
+    tokens.iter().cloned().zip(amounts).unzip()
+}
+
+/// Protocol of a stored pool, `default` when the store does not record one. `PoolV2.protocol` is numbered as
+/// `dex.swaps.v1.Protocol`, which tells apart forks whose events share a signature (Kyber Elastic / Uniswap V3).
+pub(crate) fn pool_protocol(pool: &PoolMetadata, default: pb::Protocol) -> pb::Protocol {
+    match pb::Protocol::try_from(pool.protocol) {
+        Ok(pb::Protocol::Unspecified) | Err(_) => default,
+        Ok(protocol) => protocol,
+    }
+}
+
+/// Metadata and (token0, token1) of a two-token pool, `None` when `store_pools` has not resolved it.
+pub(crate) fn pool_pair<'a>(pools: &'a PoolMetadataMap, address: &[u8]) -> Option<(&'a PoolMetadata, &'a Vec<u8>, &'a Vec<u8>)> {
+    let pool = pools.get(address)?;
+    Some((pool, pool.tokens.first()?, pool.tokens.get(1)?))
+}
+
+/// Swap of a pool without token metadata, kept with its signed deltas so coverage gaps stay visible.
+pub(crate) fn unresolved_swap(protocol: pb::Protocol, pools: &PoolMetadataMap, address: &[u8], user: &[u8], amount0: String, amount1: String) -> pb::log::Log {
+    pb::log::Log::UnresolvedSwap(pb::UnresolvedSwap {
+        protocol: protocol as i32,
+        factory: pools.get(address).map(|pool| pool.factory.clone()).unwrap_or_default(),
+        pool: address.to_vec(),
+        user: user.to_vec(),
+        amount0,
+        amount1,
+        ..Default::default()
+    })
+}
+
+/// [`unresolved_swap`] of an event that names what was paid in and out: `token0` is the input and
+/// `token1` the output (empty when the event does not name it).
+pub(crate) fn unresolved_trade(
+    protocol: pb::Protocol,
+    pools: &PoolMetadataMap,
+    address: &[u8],
+    user: &[u8],
+    (input_token, input_amount): (&[u8], &BigInt),
+    (output_token, output_amount): (&[u8], &BigInt),
+) -> pb::log::Log {
+    let output_amount = net_amount(&BigInt::zero(), output_amount);
+    let mut log = unresolved_swap(protocol, pools, address, user, input_amount.to_string(), output_amount);
+    if let pb::log::Log::UnresolvedSwap(swap) = &mut log {
+        swap.token0 = input_token.to_vec();
+        swap.token1 = output_token.to_vec();
+    }
+    log
+}
+
+/// Pool stored under another protocol, for events whose signature is shared with a fork (each fork's decoder
+/// skips the other's pools).
+pub(crate) fn is_other_protocol(pools: &PoolMetadataMap, address: &[u8], protocol: pb::Protocol) -> bool {
+    pools.get(address).is_some_and(|pool| pool_protocol(pool, protocol) != protocol)
+}
+
+/// Signed pool delta from the `in`/`out` amounts of a Uniswap V2-style `Swap`.
+pub(crate) fn net_amount(amount_in: &BigInt, amount_out: &BigInt) -> String {
+    (amount_in.clone() - amount_out.clone()).to_string()
 }
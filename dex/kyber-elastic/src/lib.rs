@@ -1,5 +1,5 @@
 use common::create::{CreateLog, CreateTransaction};
-use common::bigint_to_i32;
+use common::bigint_to_i24;
 use proto::pb::kyber_elastic::v1 as pb;
 use substreams_abis::dex::kyber::elastic;
 use substreams_ethereum::pb::eth::v2::Block;
@@ -36,7 +36,7 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                     delta_qty1: event.delta_qty1.to_string(),
                     sqrt_p: event.sqrt_p.to_string(),
                     liquidity: event.liquidity.to_string(),
-                    current_tick: bigint_to_i32(&event.current_tick).unwrap_or_default(),
+                    current_tick: bigint_to_i24(&event.current_tick).unwrap_or_default(),
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
             }
@@ -47,8 +47,8 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 let event = pb::log::Log::Mint(pb::Mint {
                     sender: event.sender.to_vec(),
                     owner: event.owner.to_vec(),
-                    tick_lower: bigint_to_i32(&event.tick_lower).unwrap_or_default(),
-                    tick_upper: bigint_to_i32(&event.tick_upper).unwrap_or_default(),
+                    tick_lower: bigint_to_i24(&event.tick_lower).unwrap_or_default(),
+                    tick_upper: bigint_to_i24(&event.tick_upper).unwrap_or_default(),
                     qty: event.qty.to_string(),
                     qty0: event.qty0.to_string(),
                     qty1: event.qty1.to_string(),
@@ -61,8 +61,8 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 total_burns += 1;
                 let event = pb::log::Log::Burn(pb::Burn {
                     owner: event.owner.to_vec(),
-                    tick_lower: bigint_to_i32(&event.tick_lower).unwrap_or_default(),
-                    tick_upper: bigint_to_i32(&event.tick_upper).unwrap_or_default(),
+                    tick_lower: bigint_to_i24(&event.tick_lower).unwrap_or_default(),
+                    tick_upper: bigint_to_i24(&event.tick_upper).unwrap_or_default(),
                     qty: event.qty.to_string(),
                     qty0: event.qty0.to_string(),
                     qty1: event.qty1.to_string(),
@@ -101,7 +101,7 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 total_initialize += 1;
                 let event = pb::log::Log::Initialize(pb::Initialize {
                     sqrt_p: event.sqrt_p.to_string(),
-                    tick: bigint_to_i32(&event.tick).unwrap_or_default(),
+                    tick: bigint_to_i24(&event.tick).unwrap_or_default(),
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
             }
@@ -113,7 +113,7 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                     token0: event.token0.to_vec(),
                     token1: event.token1.to_vec(),
                     swap_fee_units: event.swap_fee_units.to_u64() as u32,
-                    tick_distance: bigint_to_i32(&event.tick_distance).unwrap_or_default(),
+                    tick_distance: bigint_to_i24(&event.tick_distance).unwrap_or_default(),
                     pool: event.pool.to_vec(),
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
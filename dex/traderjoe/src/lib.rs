@@ -5,11 +5,11 @@ use substreams_abis::dex::traderjoe;
 use substreams_ethereum::pb::eth::v2::Block;
 use substreams_ethereum::Event;
 
-/// Decode a packed bytes32 into two uint128 values (upper = x, lower = y).
-/// Trader Joe LB uses this encoding: `uint128(x) << 128 | uint128(y)`
+/// Decode a packed bytes32 into two uint128 values (lower = x, upper = y).
+/// Trader Joe LB `PackedUint128Math` uses this encoding: `uint128(y) << 128 | uint128(x)`
 fn decode_packed_uint128(bytes: &[u8; 32]) -> (String, String) {
-    let x = BigInt::from_unsigned_bytes_be(&bytes[..16]);
-    let y = BigInt::from_unsigned_bytes_be(&bytes[16..]);
+    let x = BigInt::from_unsigned_bytes_be(&bytes[16..]);
+    let y = BigInt::from_unsigned_bytes_be(&bytes[..16]);
     (x.to_string(), y.to_string())
 }
 
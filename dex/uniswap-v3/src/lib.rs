@@ -1,5 +1,5 @@
 use common::create::{CreateLog, CreateTransaction};
-use common::{bigint_to_i32, bigint_to_u64};
+use common::{bigint_to_i24, bigint_to_u64};
 use proto::pb::uniswap::v3 as pb;
 use substreams_abis::dex::uniswap::v3 as uniswap;
 use substreams_ethereum::pb::eth::v2::Block;
@@ -41,7 +41,7 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                     amount1: event.amount1.to_string(),
                     sqrt_price_x96: event.sqrt_price_x96.to_string(),
                     liquidity: event.liquidity.to_string(),
-                    tick: bigint_to_i32(&event.tick).unwrap_or_default(),
+                    tick: bigint_to_i24(&event.tick).unwrap_or_default(),
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
             }
@@ -51,7 +51,7 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 total_initialize += 1;
                 let event = pb::log::Log::Initialize(pb::Initialize {
                     sqrt_price_x96: event.sqrt_price_x96.to_string(),
-                    tick: bigint_to_i32(&event.tick).unwrap_or_default(),
+                    tick: bigint_to_i24(&event.tick).unwrap_or_default(),
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
             }
@@ -62,8 +62,8 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 let event = pb::log::Log::Mint(pb::Mint {
                     sender: event.sender.to_vec(),
                     owner: event.owner.to_vec(),
-                    tick_lower: bigint_to_i32(&event.tick_lower).unwrap_or_default(),
-                    tick_upper: bigint_to_i32(&event.tick_upper).unwrap_or_default(),
+                    tick_lower: bigint_to_i24(&event.tick_lower).unwrap_or_default(),
+                    tick_upper: bigint_to_i24(&event.tick_upper).unwrap_or_default(),
                     amount: event.amount.to_string(),
                     amount0: event.amount0.to_string(),
                     amount1: event.amount1.to_string(),
@@ -77,8 +77,8 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 let event = pb::log::Log::Collect(pb::Collect {
                     owner: event.owner.to_vec(),
                     recipient: event.recipient.to_vec(),
-                    tick_lower: bigint_to_i32(&event.tick_lower).unwrap_or_default(),
-                    tick_upper: bigint_to_i32(&event.tick_upper).unwrap_or_default(),
+                    tick_lower: bigint_to_i24(&event.tick_lower).unwrap_or_default(),
+                    tick_upper: bigint_to_i24(&event.tick_upper).unwrap_or_default(),
                     amount0: event.amount0.to_string(),
                     amount1: event.amount1.to_string(),
                 });
@@ -90,8 +90,8 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 total_burns += 1;
                 let event = pb::log::Log::Burn(pb::Burn {
                     owner: event.owner.to_vec(),
-                    tick_lower: bigint_to_i32(&event.tick_lower).unwrap_or_default(),
-                    tick_upper: bigint_to_i32(&event.tick_upper).unwrap_or_default(),
+                    tick_lower: bigint_to_i24(&event.tick_lower).unwrap_or_default(),
+                    tick_upper: bigint_to_i24(&event.tick_upper).unwrap_or_default(),
                     amount: event.amount.to_string(),
                     amount0: event.amount0.to_string(),
                     amount1: event.amount1.to_string(),
@@ -155,7 +155,7 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                     token0: event.token0.to_vec(),
                     token1: event.token1.to_vec(),
                     fee: bigint_to_u64(&event.fee).unwrap_or_default(),
-                    tick_spacing: bigint_to_i32(&event.tick_spacing).unwrap_or_default(),
+                    tick_spacing: bigint_to_i24(&event.tick_spacing).unwrap_or_default(),
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
             }
@@ -175,7 +175,7 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 total_fee_amount_enabled += 1;
                 let event = pb::log::Log::FeeAmountEnabled(pb::FeeAmountEnabled {
                     fee: bigint_to_u64(&event.fee).unwrap_or_default(),
-                    tick_spacing: bigint_to_i32(&event.tick_spacing).unwrap_or_default(),
+                    tick_spacing: bigint_to_i24(&event.tick_spacing).unwrap_or_default(),
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
             }
@@ -1,5 +1,5 @@
 use common::create::{CreateLog, CreateTransaction};
-use common::{bigint_to_i32, bigint_to_u64};
+use common::{bigint_to_i24, bigint_to_u64};
 use proto::pb::uniswap::v4 as pb;
 use substreams_abis::dex::uniswap::v4 as uniswap;
 use substreams_ethereum::pb::eth::v2::Block;
@@ -35,7 +35,7 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                     amount1: event.amount1.to_string(),
                     sqrt_price_x96: event.sqrt_price_x96.to_string(),
                     liquidity: event.liquidity.to_string(),
-                    tick: bigint_to_i32(&event.tick).unwrap_or_default(),
+                    tick: bigint_to_i24(&event.tick).unwrap_or_default(),
                     fee: event.fee.to_string(),
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
@@ -49,10 +49,10 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                     currency0: event.currency0.to_vec(),
                     currency1: event.currency1.to_vec(),
                     fee: bigint_to_u64(&event.fee).unwrap_or_default(),
-                    tick_spacing: bigint_to_i32(&event.tick_spacing).unwrap_or_default(),
+                    tick_spacing: bigint_to_i24(&event.tick_spacing).unwrap_or_default(),
                     hooks: event.hooks.to_vec(),
                     sqrt_price_x96: event.sqrt_price_x96.to_string(),
-                    tick: bigint_to_i32(&event.tick).unwrap_or_default(),
+                    tick: bigint_to_i24(&event.tick).unwrap_or_default(),
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
             }
@@ -63,8 +63,8 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 let event = pb::log::Log::ModifyLiquidity(pb::ModifyLiquidity {
                     id: event.id.to_vec(),
                     sender: event.sender.to_vec(),
-                    tick_lower: bigint_to_i32(&event.tick_lower).unwrap_or_default(),
-                    tick_upper: bigint_to_i32(&event.tick_upper).unwrap_or_default(),
+                    tick_lower: bigint_to_i24(&event.tick_lower).unwrap_or_default(),
+                    tick_upper: bigint_to_i24(&event.tick_upper).unwrap_or_default(),
                     liquidity_delta: event.liquidity_delta.to_string(),
                     salt: event.salt.to_vec(),
                 });
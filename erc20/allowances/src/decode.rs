@@ -0,0 +1,411 @@
+//! Allowance changes of a block, decoded from raw logs and call inputs:
+//!   - ERC-20 `Approval` events (3 topics and a 32-byte value, unlike the ERC-721 `Approval`)
+//!   - EIP-2612 / DAI `permit` calls on the token; the `Approval` they emit is reported as the permit
+//!   - ERC-20 `transferFrom` calls by the spender: spends the allowance, unless the token emitted an
+//!     `Approval` for the same pair during the call (that `Approval` already has the new allowance)
+//!   - Uniswap Permit2 `Approval` / `Permit` / `Lockdown` events and `transferFrom` calls
+//!
+//! Only calls of successful transactions whose state was not reverted are followed. Proxied tokens
+//! are matched on their `CALL` frame (the `DELEGATECALL` to the implementation is skipped).
+
+use common::Address;
+use proto::pb::erc20::allowances::v1 as pb;
+use substreams::hex;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2::{Block, Call, CallType, Log, TransactionTrace};
+
+/// Uniswap Permit2, same address on every chain.
+pub const PERMIT2: [u8; 20] = hex!("000000000022d473030f116ddee9f6b43ac78ba3");
+
+const APPROVAL_TOPIC: [u8; 32] = hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+const TRANSFER_TOPIC: [u8; 32] = hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+const PERMIT2_APPROVAL_TOPIC: [u8; 32] = hex!("da9fa7c1b00402c17d0161b249b1ab8bbec047c5a52207b9c112deffd817036b");
+const PERMIT2_PERMIT_TOPIC: [u8; 32] = hex!("c6a377bfc4eb120024a8ac08eef205be16b817020812c73223e81d1bdb9708ec");
+const PERMIT2_LOCKDOWN_TOPIC: [u8; 32] = hex!("89b1add15eff56b3dfe299ad94e01f2b52fbcb80ae1a3baea6ae8c04cb2b98a4");
+
+/// permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s)
+const PERMIT_SELECTOR: [u8; 4] = hex!("d505accf");
+/// permit(address holder, address spender, uint256 nonce, uint256 expiry, bool allowed, uint8 v, bytes32 r, bytes32 s)
+const DAI_PERMIT_SELECTOR: [u8; 4] = hex!("8fcbaf0c");
+/// transferFrom(address from, address to, uint256 amount)
+const TRANSFER_FROM_SELECTOR: [u8; 4] = hex!("23b872dd");
+/// Permit2 transferFrom(address from, address to, uint160 amount, address token)
+const PERMIT2_TRANSFER_FROM_SELECTOR: [u8; 4] = hex!("36c78516");
+/// Permit2 transferFrom((address from, address to, uint160 amount, address token)[] transferDetails)
+const PERMIT2_BATCH_TRANSFER_FROM_SELECTOR: [u8; 4] = hex!("0d58b1db");
+
+/// `(token, owner, spender)` of an ERC-20 allowance.
+type Pair = (Address, Address, Address);
+
+pub fn allowance_changes(block: &Block) -> Vec<pb::AllowanceChange> {
+    let mut changes = Vec::new();
+    for trx in block.transactions() {
+        collect_transaction(trx, &mut changes);
+    }
+    changes.sort_by_key(|change| change.ordinal);
+    changes
+}
+
+fn collect_transaction(trx: &TransactionTrace, changes: &mut Vec<pb::AllowanceChange>) {
+    let calls: Vec<&Call> = trx.calls.iter().filter(|call| !call.state_reverted).collect();
+    let logs: Vec<&Log> = calls.iter().flat_map(|call| call.logs.iter()).collect();
+    let approvals: Vec<(u64, Pair, BigInt)> = logs
+        .iter()
+        .filter_map(|log| decode_approval(log).map(|(pair, value)| (log.ordinal, pair, value)))
+        .collect();
+
+    // `permit` calls replace the `Approval` they emit.
+    let mut permits: Vec<(&Call, Pair)> = Vec::new();
+    for call in calls.iter().filter(|call| call.call_type() == CallType::Call) {
+        if let Some((pair, value)) = decode_permit(call) {
+            changes.push(change(trx, call.begin_ordinal, pb::Source::Permit, None, &pair, value, None));
+            permits.push((call, pair));
+        } else if let Some((pair, amount)) = decode_transfer_from(call) {
+            let during_call = |ordinal: u64| call.begin_ordinal <= ordinal && ordinal <= call.end_ordinal;
+            let transferred = logs.iter().any(|log| during_call(log.ordinal) && is_erc20_transfer(log, &pair.0));
+            let approved = approvals.iter().any(|(ordinal, approval, _)| during_call(*ordinal) && approval == &pair);
+            if transferred && !approved {
+                changes.push(change(trx, call.begin_ordinal, pb::Source::TransferFrom, None, &pair, amount, None));
+            }
+        } else if call.address.as_slice() == PERMIT2 {
+            for (pair, amount) in decode_permit2_transfer_from(call) {
+                changes.push(change(
+                    trx,
+                    call.begin_ordinal,
+                    pb::Source::Permit2TransferFrom,
+                    Some(&PERMIT2),
+                    &pair,
+                    amount,
+                    None,
+                ));
+            }
+        }
+    }
+
+    for (ordinal, pair, value) in approvals {
+        let permitted = permits
+            .iter()
+            .any(|(call, permit)| call.begin_ordinal <= ordinal && ordinal <= call.end_ordinal && permit == &pair);
+        if !permitted {
+            changes.push(change(trx, ordinal, pb::Source::Approval, None, &pair, value, None));
+        }
+    }
+
+    for log in logs.iter().filter(|log| log.address.as_slice() == PERMIT2) {
+        if let Some((source, pair, amount, expiration)) = decode_permit2_log(log) {
+            changes.push(change(trx, log.ordinal, source, Some(&PERMIT2), &pair, amount, expiration));
+        }
+    }
+}
+
+fn change(
+    trx: &TransactionTrace,
+    ordinal: u64,
+    source: pb::Source,
+    permit2: Option<&[u8]>,
+    (token, owner, spender): &Pair,
+    amount: BigInt,
+    expiration: Option<u64>,
+) -> pb::AllowanceChange {
+    pb::AllowanceChange {
+        tx_hash: trx.hash.to_vec(),
+        ordinal,
+        source: source as i32,
+        permit2: permit2.map(|permit2| permit2.to_vec()),
+        token: token.to_vec(),
+        owner: owner.to_vec(),
+        spender: spender.to_vec(),
+        amount: amount.to_string(),
+        expiration,
+    }
+}
+
+fn decode_approval(log: &Log) -> Option<(Pair, BigInt)> {
+    if log.topics.len() != 3 || log.topics[0].as_slice() != APPROVAL_TOPIC || log.data.len() != 32 {
+        return None;
+    }
+    let pair = (log.address.to_vec(), topic_address(&log.topics[1])?, topic_address(&log.topics[2])?);
+    Some((pair, BigInt::from_unsigned_bytes_be(&log.data)))
+}
+
+fn is_erc20_transfer(log: &Log, token: &[u8]) -> bool {
+    log.address.as_slice() == token && log.topics.len() == 3 && log.topics[0].as_slice() == TRANSFER_TOPIC && log.data.len() == 32
+}
+
+fn decode_permit(call: &Call) -> Option<(Pair, BigInt)> {
+    let (selector, args) = split_input(call)?;
+    let value = if selector == PERMIT_SELECTOR && args.len() >= 7 * 32 {
+        uint(args, 2)?
+    } else if selector == DAI_PERMIT_SELECTOR && args.len() >= 8 * 32 {
+        // allowed ? type(uint256).max : 0
+        if uint(args, 4)?.is_zero() {
+            BigInt::zero()
+        } else {
+            BigInt::from_unsigned_bytes_be(&[0xff; 32])
+        }
+    } else {
+        return None;
+    };
+    Some(((call.address.to_vec(), address(args, 0)?, address(args, 1)?), value))
+}
+
+/// The caller spends `from`'s allowance (a holder moving its own tokens uses none).
+fn decode_transfer_from(call: &Call) -> Option<(Pair, BigInt)> {
+    let (selector, args) = split_input(call)?;
+    if selector != TRANSFER_FROM_SELECTOR || args.len() < 3 * 32 {
+        return None;
+    }
+    let from = address(args, 0)?;
+    if from == call.caller {
+        return None;
+    }
+    Some(((call.address.to_vec(), from, call.caller.to_vec()), uint(args, 2)?))
+}
+
+fn decode_permit2_transfer_from(call: &Call) -> Vec<(Pair, BigInt)> {
+    let Some((selector, args)) = split_input(call) else {
+        return vec![];
+    };
+    // (from, to, amount, token) words starting at `word`
+    let transfer =
+        |word: usize| -> Option<(Pair, BigInt)> { Some(((address(args, word + 3)?, address(args, word)?, call.caller.to_vec()), uint(args, word + 2)?)) };
+
+    if selector == PERMIT2_TRANSFER_FROM_SELECTOR {
+        return transfer(0).into_iter().collect();
+    }
+    if selector != PERMIT2_BATCH_TRANSFER_FROM_SELECTOR {
+        return vec![];
+    }
+    // dynamic array of static tuples: offset, then length and 4 words per transfer
+    let Some(start) = usize_word(args, 0).filter(|offset| offset % 32 == 0).map(|offset| offset / 32) else {
+        return vec![];
+    };
+    let Some(length) = usize_word(args, start) else {
+        return vec![];
+    };
+    (0..length).map_while(|index| transfer(start + 1 + index * 4)).collect()
+}
+
+/// `(source, (token, owner, spender), amount, expiration)` of a Permit2 event.
+fn decode_permit2_log(log: &Log) -> Option<(pb::Source, Pair, BigInt, Option<u64>)> {
+    let topic0 = log.topics.first()?.as_slice();
+    if topic0 == PERMIT2_LOCKDOWN_TOPIC && log.topics.len() == 2 {
+        let pair = (address(&log.data, 0)?, topic_address(&log.topics[1])?, address(&log.data, 1)?);
+        return Some((pb::Source::Permit2Lockdown, pair, BigInt::zero(), None));
+    }
+    let source = match topic0 {
+        topic if topic == PERMIT2_APPROVAL_TOPIC => pb::Source::Permit2Approval,
+        topic if topic == PERMIT2_PERMIT_TOPIC => pb::Source::Permit2Permit,
+        _ => return None,
+    };
+    if log.topics.len() != 4 {
+        return None;
+    }
+    let pair = (topic_address(&log.topics[2])?, topic_address(&log.topics[1])?, topic_address(&log.topics[3])?);
+    let expiration = usize_word(&log.data, 1)? as u64;
+    Some((source, pair, uint(&log.data, 0)?, Some(expiration)))
+}
+
+fn split_input(call: &Call) -> Option<([u8; 4], &[u8])> {
+    let selector: [u8; 4] = call.input.get(..4)?.try_into().ok()?;
+    Some((selector, &call.input[4..]))
+}
+
+fn word(data: &[u8], index: usize) -> Option<&[u8]> {
+    data.get(index * 32..(index + 1) * 32)
+}
+
+fn uint(data: &[u8], index: usize) -> Option<BigInt> {
+    word(data, index).map(BigInt::from_unsigned_bytes_be)
+}
+
+fn usize_word(data: &[u8], index: usize) -> Option<usize> {
+    let word = word(data, index)?;
+    if word[..24].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    Some(u64::from_be_bytes(word[24..].try_into().ok()?) as usize)
+}
+
+fn address(data: &[u8], index: usize) -> Option<Address> {
+    topic_address(word(data, index)?)
+}
+
+fn topic_address(topic: &[u8]) -> Option<Address> {
+    (topic.len() == 32).then(|| topic[12..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use substreams_ethereum::pb::eth::v2::TransactionTraceStatus;
+
+    const TOKEN: [u8; 20] = [0x11; 20];
+    const OWNER: [u8; 20] = [0xaa; 20];
+    const SPENDER: [u8; 20] = [0xbb; 20];
+    const RECIPIENT: [u8; 20] = [0xcc; 20];
+
+    fn padded(address: &[u8]) -> Vec<u8> {
+        [vec![0; 12], address.to_vec()].concat()
+    }
+
+    fn amount(value: u64) -> Vec<u8> {
+        [vec![0; 24], value.to_be_bytes().to_vec()].concat()
+    }
+
+    fn input(selector: [u8; 4], words: &[Vec<u8>]) -> Vec<u8> {
+        [selector.to_vec(), words.concat()].concat()
+    }
+
+    fn log(address: &[u8], ordinal: u64, topics: Vec<Vec<u8>>, data: Vec<u8>) -> Log {
+        Log {
+            address: address.to_vec(),
+            ordinal,
+            topics,
+            data,
+            ..Default::default()
+        }
+    }
+
+    fn approval_log(ordinal: u64, value: u64) -> Log {
+        log(&TOKEN, ordinal, vec![APPROVAL_TOPIC.to_vec(), padded(&OWNER), padded(&SPENDER)], amount(value))
+    }
+
+    fn transfer_log(ordinal: u64, value: u64) -> Log {
+        log(
+            &TOKEN,
+            ordinal,
+            vec![TRANSFER_TOPIC.to_vec(), padded(&OWNER), padded(&RECIPIENT)],
+            amount(value),
+        )
+    }
+
+    fn call(address: &[u8], caller: &[u8], input: Vec<u8>, ordinals: (u64, u64), logs: Vec<Log>) -> Call {
+        Call {
+            address: address.to_vec(),
+            caller: caller.to_vec(),
+            input,
+            call_type: CallType::Call as i32,
+            begin_ordinal: ordinals.0,
+            end_ordinal: ordinals.1,
+            logs,
+            ..Default::default()
+        }
+    }
+
+    fn block(calls: Vec<Call>) -> Block {
+        Block {
+            transaction_traces: vec![TransactionTrace {
+                hash: vec![0x01; 32],
+                status: TransactionTraceStatus::Succeeded as i32,
+                calls,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn summary(changes: &[pb::AllowanceChange]) -> Vec<(pb::Source, String)> {
+        changes.iter().map(|change| (change.source(), change.amount.clone())).collect()
+    }
+
+    #[test]
+    fn approval_events_set_the_allowance() {
+        let changes = allowance_changes(&block(vec![call(&TOKEN, &OWNER, vec![], (1, 3), vec![approval_log(2, 500)])]));
+        assert_eq!(summary(&changes), vec![(pb::Source::Approval, "500".to_string())]);
+        assert_eq!(
+            (changes[0].token.as_slice(), changes[0].owner.as_slice(), changes[0].spender.as_slice()),
+            (&TOKEN[..], &OWNER[..], &SPENDER[..])
+        );
+        assert_eq!(changes[0].permit2, None);
+    }
+
+    #[test]
+    fn erc721_approvals_are_skipped() {
+        let nft_approval = log(&TOKEN, 2, vec![APPROVAL_TOPIC.to_vec(), padded(&OWNER), padded(&SPENDER), amount(7)], vec![]);
+        assert!(allowance_changes(&block(vec![call(&TOKEN, &OWNER, vec![], (1, 3), vec![nft_approval])])).is_empty());
+    }
+
+    #[test]
+    fn permit_replaces_its_approval_event() {
+        let permit = input(
+            PERMIT_SELECTOR,
+            &[padded(&OWNER), padded(&SPENDER), amount(42), amount(0), amount(27), amount(0), amount(0)],
+        );
+        let changes = allowance_changes(&block(vec![call(&TOKEN, &RECIPIENT, permit, (1, 3), vec![approval_log(2, 42)])]));
+        assert_eq!(summary(&changes), vec![(pb::Source::Permit, "42".to_string())]);
+    }
+
+    #[test]
+    fn transfer_from_spends_the_allowance_without_approval_event() {
+        let transfer_from = input(TRANSFER_FROM_SELECTOR, &[padded(&OWNER), padded(&RECIPIENT), amount(30)]);
+        let changes = allowance_changes(&block(vec![call(&TOKEN, &SPENDER, transfer_from.clone(), (1, 3), vec![transfer_log(2, 30)])]));
+        assert_eq!(summary(&changes), vec![(pb::Source::TransferFrom, "30".to_string())]);
+        assert_eq!(changes[0].spender, SPENDER.to_vec());
+
+        // OpenZeppelin v4 emits the decreased allowance: the `Approval` is enough.
+        let changes = allowance_changes(&block(vec![call(
+            &TOKEN,
+            &SPENDER,
+            transfer_from.clone(),
+            (1, 4),
+            vec![approval_log(2, 70), transfer_log(3, 30)],
+        )]));
+        assert_eq!(summary(&changes), vec![(pb::Source::Approval, "70".to_string())]);
+
+        // ERC-721 `transferFrom` (same selector) emits no ERC-20 `Transfer`.
+        assert!(allowance_changes(&block(vec![call(&TOKEN, &SPENDER, transfer_from, (1, 3), vec![])])).is_empty());
+    }
+
+    #[test]
+    fn permit2_events_and_transfers() {
+        let approve = log(
+            &PERMIT2,
+            2,
+            vec![PERMIT2_APPROVAL_TOPIC.to_vec(), padded(&OWNER), padded(&TOKEN), padded(&SPENDER)],
+            [amount(100), amount(1_700_000_000)].concat(),
+        );
+        let lockdown = log(
+            &PERMIT2,
+            8,
+            vec![PERMIT2_LOCKDOWN_TOPIC.to_vec(), padded(&OWNER)],
+            [padded(&TOKEN), padded(&SPENDER)].concat(),
+        );
+        let transfer_from = input(
+            PERMIT2_TRANSFER_FROM_SELECTOR,
+            &[padded(&OWNER), padded(&RECIPIENT), amount(40), padded(&TOKEN)],
+        );
+        let batch = input(
+            PERMIT2_BATCH_TRANSFER_FROM_SELECTOR,
+            &[amount(32), amount(1), padded(&OWNER), padded(&RECIPIENT), amount(5), padded(&TOKEN)],
+        );
+
+        let changes = allowance_changes(&block(vec![
+            call(&PERMIT2, &OWNER, vec![], (1, 3), vec![approve]),
+            call(&PERMIT2, &SPENDER, transfer_from, (4, 5), vec![]),
+            call(&PERMIT2, &SPENDER, batch, (6, 7), vec![]),
+            call(&PERMIT2, &OWNER, vec![], (8, 9), vec![lockdown]),
+        ]));
+        assert_eq!(
+            summary(&changes),
+            vec![
+                (pb::Source::Permit2Approval, "100".to_string()),
+                (pb::Source::Permit2TransferFrom, "40".to_string()),
+                (pb::Source::Permit2TransferFrom, "5".to_string()),
+                (pb::Source::Permit2Lockdown, "0".to_string()),
+            ]
+        );
+        assert!(changes.iter().all(|change| change.permit2 == Some(PERMIT2.to_vec())
+            && change.token == TOKEN.to_vec()
+            && change.owner == OWNER.to_vec()
+            && change.spender == SPENDER.to_vec()));
+        assert_eq!(changes[0].expiration, Some(1_700_000_000));
+    }
+
+    #[test]
+    fn reverted_calls_are_skipped() {
+        let mut reverted = call(&TOKEN, &OWNER, vec![], (1, 3), vec![approval_log(2, 500)]);
+        reverted.state_reverted = true;
+        assert!(allowance_changes(&block(vec![reverted])).is_empty());
+    }
+}
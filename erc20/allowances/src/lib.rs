@@ -0,0 +1,66 @@
+mod decode;
+mod state;
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use proto::pb::erc20::allowances::v1 as pb;
+use substreams::errors::Error;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreDelete, StoreGet, StoreGetBigInt, StoreGetProto, StoreNew, StoreSet, StoreSetProto};
+use substreams_ethereum::pb::eth::v2::Block;
+
+#[substreams::handlers::map]
+fn map_events(block: Block) -> Result<pb::Events, Error> {
+    let changes = decode::allowance_changes(&block);
+    substreams::log::info!("Total allowance changes: {}", changes.len());
+    Ok(pb::Events { changes })
+}
+
+/// Last change that set each allowance, keyed by [`state::allowance_key`].
+#[substreams::handlers::store]
+fn store_allowances(events: pb::Events, store: StoreSetProto<pb::AllowanceChange>) {
+    for change in events.changes.iter().filter(|change| !state::is_spend(change)) {
+        store.set(change.ordinal, state::allowance_key(change), change);
+    }
+}
+
+/// Amount spent by `transferFrom` since the last change of `store_allowances` (reset by it).
+#[substreams::handlers::store]
+fn store_allowances_spent(events: pb::Events, store: StoreAddBigInt) {
+    for change in &events.changes {
+        let key = state::allowance_key(change);
+        if !state::is_spend(change) {
+            store.delete_prefix(change.ordinal as i64, &key);
+        } else if let Ok(amount) = BigInt::from_str(&change.amount) {
+            store.add(change.ordinal, key, amount);
+        }
+    }
+}
+
+/// Current state of every allowance changed in the block, with its last change.
+#[substreams::handlers::map]
+fn map_allowances(events: pb::Events, approved: StoreGetProto<pb::AllowanceChange>, spent: StoreGetBigInt) -> Result<pb::Allowances, Error> {
+    // sorted by ordinal: the last change of a key wins
+    let last_changes: BTreeMap<String, &pb::AllowanceChange> = events.changes.iter().map(|change| (state::allowance_key(change), change)).collect();
+
+    let mut allowances = pb::Allowances::default();
+    for (key, change) in last_changes {
+        let approval = approved.get_last(&key);
+        let approved_amount = approval.as_ref().and_then(|approval| BigInt::from_str(&approval.amount).ok());
+        let spent_amount = spent.get_last(&key).unwrap_or_else(BigInt::zero);
+
+        allowances.allowances.push(pb::Allowance {
+            permit2: change.permit2.clone(),
+            token: change.token.clone(),
+            owner: change.owner.clone(),
+            spender: change.spender.clone(),
+            amount: state::current_allowance(approved_amount.as_ref(), &spent_amount).to_string(),
+            expiration: approval.as_ref().and_then(|approval| approval.expiration),
+            unlimited: approved_amount.is_some_and(|amount| state::is_unlimited(&amount, change.permit2.is_some())),
+            source: change.source,
+            tx_hash: change.tx_hash.clone(),
+        });
+    }
+    Ok(allowances)
+}
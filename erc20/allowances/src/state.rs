@@ -0,0 +1,85 @@
+//! Allowance state. `store_allowances` keeps the last change that set an allowance (`Approval`,
+//! `permit`, Permit2 `Approval` / `Permit` / `Lockdown`) and `store_allowances_spent` the amount
+//! spent by `transferFrom` since then, so the current allowance is `approved - spent`.
+
+use proto::pb::erc20::allowances::v1 as pb;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+
+/// Store key: `erc20:<token>:<owner>:<spender>` or `permit2:<token>:<owner>:<spender>`
+/// (hex-encoded, lowercase, no `0x`). Every key has the same length, so deleting a key by prefix
+/// never deletes another one.
+pub fn allowance_key(change: &pb::AllowanceChange) -> String {
+    let kind = if change.permit2.is_some() { "permit2" } else { "erc20" };
+    format!(
+        "{}:{}:{}:{}",
+        kind,
+        Hex::encode(&change.token),
+        Hex::encode(&change.owner),
+        Hex::encode(&change.spender)
+    )
+}
+
+/// Changes that spend the allowance instead of setting it.
+pub fn is_spend(change: &pb::AllowanceChange) -> bool {
+    matches!(change.source(), pb::Source::TransferFrom | pb::Source::Permit2TransferFrom)
+}
+
+/// Approved amounts that most tokens (and Permit2, `type(uint160).max`) never decrement.
+pub fn is_unlimited(approved: &BigInt, permit2: bool) -> bool {
+    let bits = if permit2 { 159 } else { 255 };
+    approved >= &BigInt::from(2).pow(bits)
+}
+
+/// Current allowance (never negative: spends of an approval before the start block are ignored).
+pub fn current_allowance(approved: Option<&BigInt>, spent: &BigInt) -> BigInt {
+    let remaining = approved.cloned().unwrap_or_else(BigInt::zero) - spent.clone();
+    if remaining < BigInt::zero() {
+        BigInt::zero()
+    } else {
+        remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(permit2: bool, source: pb::Source) -> pb::AllowanceChange {
+        pb::AllowanceChange {
+            source: source as i32,
+            permit2: permit2.then(|| vec![0x22; 20]),
+            token: vec![0x11; 20],
+            owner: vec![0xaa; 20],
+            spender: vec![0xbb; 20],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn keys_have_a_fixed_length_per_kind() {
+        let erc20 = allowance_key(&change(false, pb::Source::Approval));
+        let permit2 = allowance_key(&change(true, pb::Source::Permit2Approval));
+        assert_eq!(erc20, format!("erc20:{}:{}:{}", "11".repeat(20), "aa".repeat(20), "bb".repeat(20)));
+        assert_eq!(permit2.len(), erc20.len() + 2);
+        assert!(permit2.starts_with("permit2:"));
+    }
+
+    #[test]
+    fn spends_decrease_the_approved_amount() {
+        assert!(is_spend(&change(false, pb::Source::TransferFrom)));
+        assert!(!is_spend(&change(true, pb::Source::Permit2Lockdown)));
+        assert_eq!(current_allowance(Some(&BigInt::from(100)), &BigInt::from(30)), BigInt::from(70));
+        assert_eq!(current_allowance(None, &BigInt::from(30)), BigInt::zero());
+    }
+
+    #[test]
+    fn unlimited_approvals() {
+        let max_uint256 = BigInt::from_unsigned_bytes_be(&[0xff; 32]);
+        let max_uint160 = BigInt::from_unsigned_bytes_be(&[0xff; 20]);
+        assert!(is_unlimited(&max_uint256, false));
+        assert!(!is_unlimited(&max_uint160, false));
+        assert!(is_unlimited(&max_uint160, true));
+        assert!(!is_unlimited(&BigInt::from(1_000_000), true));
+    }
+}
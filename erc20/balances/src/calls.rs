@@ -1,36 +1,48 @@
 use std::collections::HashMap;
 
+use common::rpc::execute_batch;
 use common::Address;
-use substreams::{log, scalar::BigInt, Hex};
+use proto::pb::evm::balances::v1 as balances_pb;
+use substreams::{log, scalar::BigInt};
 use substreams_abis::standard::erc20;
 use substreams_ethereum::rpc::RpcBatch;
 
-// Returns the token URI.
-pub fn batch_balance_of<'a>(contract_owners: &'a [(&Address, &Address)], chunk_size: usize) -> HashMap<(&'a Address, &'a Address), BigInt> {
-    let mut results: HashMap<(&Address, &Address), BigInt> = HashMap::with_capacity(contract_owners.len());
+pub struct BalanceLookups<'a> {
+    pub balances: HashMap<(&'a Address, &'a Address), BigInt>,
+    /// Pairs without a balance, with the reason of the failure.
+    pub failed: Vec<balances_pb::FailedLookup>,
+}
+
+/// Batch fetch balanceOf(owner) for a list of (contract, owner) pairs. Failed batches are retried
+/// and bisected (see `common::rpc`), so a failing pair never drops the others.
+pub fn batch_balance_of<'a>(contract_owners: &'a [(&Address, &Address)], chunk_size: usize) -> BalanceLookups<'a> {
+    let responses = execute_batch(contract_owners, chunk_size, |batch, (contract, owner)| {
+        batch.add(erc20::functions::BalanceOf { account: owner.to_vec() }, contract.to_vec())
+    });
 
-    for chunk in contract_owners.chunks(chunk_size) {
-        let batch = chunk.iter().fold(RpcBatch::new(), |batch, (contract, owner)| {
-            batch.add(erc20::functions::BalanceOf { account: owner.to_vec() }, contract.to_vec())
+    let mut lookups = BalanceLookups {
+        balances: HashMap::with_capacity(contract_owners.len()),
+        failed: vec![],
+    };
+    for ((contract, owner), response) in contract_owners.iter().zip(responses) {
+        let (reason, error) = match response {
+            Ok(response) if response.failed => (balances_pb::LookupFailure::Reverted, String::new()),
+            Ok(response) => match RpcBatch::decode::<BigInt, erc20::functions::BalanceOf>(&response) {
+                Some(value) => {
+                    lookups.balances.insert((contract, owner), value);
+                    continue;
+                }
+                None => (balances_pb::LookupFailure::Undecodable, String::new()),
+            },
+            Err(error) => (balances_pb::LookupFailure::RpcError, error),
+        };
+        lookups.failed.push(balances_pb::FailedLookup {
+            contract: Some(contract.to_vec()),
+            address: owner.to_vec(),
+            reason: reason as i32,
+            error,
         });
-        let responses = batch.execute().expect("failed to execute erc20::functions::BalanceOf batch").responses;
-        for (i, (contract, owner)) in chunk.iter().enumerate() {
-            if let Some(value) = RpcBatch::decode::<BigInt, erc20::functions::BalanceOf>(&responses[i]) {
-                results.insert((contract, owner), value);
-            } else {
-                substreams::log::info!(
-                    "Failed to decode erc20::BalanceOf for contract={:?} owner={:?}",
-                    Hex::encode(contract),
-                    Hex::encode(owner)
-                );
-            }
-        }
     }
-    log::info!(
-        "\nBalances={}\nRpcBatch={}\nMissing={}",
-        contract_owners.len(),
-        contract_owners.chunks(chunk_size).len(),
-        contract_owners.len() - results.len()
-    );
-    results
+    log::info!("\nBalances={}\nMissing={}", contract_owners.len(), lookups.failed.len());
+    lookups
 }
@@ -1,11 +1,18 @@
 mod calls;
+mod running;
+mod steth;
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use calls::batch_balance_of;
+use common::rpc::parse_chunk_size;
 use proto::pb::erc20::tokens::v1 as tokens_pb;
 use proto::pb::erc20::transfers::v1 as transfers_pb;
 use proto::pb::evm::balances::v1 as balances_pb;
+use running::Reconciliation;
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaBigInt, Deltas, StoreAdd, StoreAddBigInt, StoreGet, StoreGetBigInt, StoreNew, StoreSet, StoreSetBigInt};
 
 #[substreams::handlers::map]
 fn map_balance_changes(transfers: transfers_pb::Events, tokens: tokens_pb::Events) -> Result<balances_pb::BalanceChanges, substreams::errors::Error> {
@@ -168,6 +175,21 @@ fn map_balance_changes(transfers: transfers_pb::Events, tokens: tokens_pb::Event
                         Some(tokens_pb::log::Log::StethExternalSharesBurnt(external_shares_burnt)) => {
                             addresses.push((&log.address, &external_shares_burnt.owner));
                         }
+                        // PYUSD events
+                        Some(tokens_pb::log::Log::PyusdSupplyIncreased(supply_increased)) => {
+                            addresses.push((&log.address, &supply_increased.to));
+                        }
+                        Some(tokens_pb::log::Log::PyusdSupplyDecreased(supply_decreased)) => {
+                            addresses.push((&log.address, &supply_decreased.from));
+                        }
+                        Some(tokens_pb::log::Log::PyusdFrozenAddressWiped(frozen_address_wiped)) => {
+                            addresses.push((&log.address, &frozen_address_wiped.addr));
+                        }
+                        // sUSDe events
+                        Some(tokens_pb::log::Log::UsdeLockedAmountRedistributed(locked_amount_redistributed)) => {
+                            addresses.push((&log.address, &locked_amount_redistributed.from));
+                            addresses.push((&log.address, &locked_amount_redistributed.to));
+                        }
                         _ => {}
                     }
                     addresses
@@ -195,7 +217,7 @@ fn map_balance_changes(transfers: transfers_pb::Events, tokens: tokens_pb::Event
 #[substreams::handlers::map]
 fn map_events(params: String, balance_changes: balances_pb::BalanceChanges) -> Result<balances_pb::Events, substreams::errors::Error> {
     let mut events = balances_pb::Events::default();
-    let chunk_size = params.parse::<usize>().expect("Failed to parse chunk_size");
+    let chunk_size = parse_chunk_size(&params)?;
 
     let contracts_by_address = balance_changes
         .balance_changes
@@ -204,10 +226,10 @@ fn map_events(params: String, balance_changes: balances_pb::BalanceChanges) -> R
         .collect::<Vec<(&common::Address, &common::Address)>>();
 
     // Fetch RPC calls for Balance Of
-    let amounts = batch_balance_of(&contracts_by_address, chunk_size);
+    let lookups = batch_balance_of(&contracts_by_address, chunk_size);
 
     for (contract, address) in &contracts_by_address {
-        if let Some(amount) = amounts.get(&(contract, address)) {
+        if let Some(amount) = lookups.balances.get(&(contract, address)) {
             events.balances.push(balances_pb::Balance {
                 contract: Some(contract.to_vec()),
                 address: address.to_vec(),
@@ -215,5 +237,142 @@ fn map_events(params: String, balance_changes: balances_pb::BalanceChanges) -> R
             });
         };
     }
+    events.failed_lookups = lookups.failed;
+    Ok(events)
+}
+
+/// Running balances keyed by `<contract>:<holder>` (hex-encoded), see [`running`].
+#[substreams::handlers::store]
+fn store_balances(transfers: transfers_pb::Events, tokens: tokens_pb::Events, store: StoreAddBigInt) {
+    let mut deltas = running::transfer_deltas(&transfers);
+    deltas.extend(running::issuer_deltas(&tokens));
+    deltas.sort_by_key(|delta| delta.ordinal);
+
+    for delta in deltas {
+        store.add(delta.ordinal, running::balance_key(&delta.contract, &delta.holder), delta.amount);
+    }
+}
+
+/// Store-based alternative to `map_events`: the balances changed in this block, read from the
+/// `store_balances` deltas without any RPC call. Every `reconcile_every` blocks a sample of them is
+/// compared with `balanceOf` and mismatches are reported in `drifts`.
+#[substreams::handlers::map]
+fn map_events_from_store(params: String, clock: Clock, deltas: Deltas<DeltaBigInt>) -> Result<balances_pb::Events, substreams::errors::Error> {
+    let reconciliation = Reconciliation::from_params(&params);
+    let mut events = balances_pb::Events::default();
+
+    // A key changed several times in the block only keeps its last value.
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    for delta in &deltas.deltas {
+        let Some((contract, address)) = running::parse_balance_key(&delta.key) else {
+            continue;
+        };
+        let balance = balances_pb::Balance {
+            contract: Some(contract),
+            address,
+            amount: delta.new_value.to_string(),
+        };
+        match positions.get(delta.key.as_str()) {
+            Some(&position) => events.balances[position] = balance,
+            None => {
+                positions.insert(&delta.key, events.balances.len());
+                events.balances.push(balance);
+            }
+        }
+    }
+
+    if reconciliation.is_due(clock.number) {
+        reconcile(&mut events, &reconciliation);
+    }
+    Ok(events)
+}
+
+/// Reports the balances of the sample whose `balanceOf` differs from the running balance, and the
+/// sampled pairs whose `balanceOf` could not be fetched.
+fn reconcile(events: &mut balances_pb::Events, reconciliation: &Reconciliation) {
+    let sample: Vec<(&common::Address, &common::Address)> = events
+        .balances
+        .iter()
+        .take(reconciliation.sample)
+        .filter_map(|balance| balance.contract.as_ref().map(|contract| (contract, &balance.address)))
+        .collect();
+    let lookups = batch_balance_of(&sample, reconciliation.chunk_size);
+
+    let drifts = events
+        .balances
+        .iter()
+        .take(reconciliation.sample)
+        .filter_map(|balance| {
+            let rpc_amount = lookups.balances.get(&(balance.contract.as_ref()?, &balance.address))?.to_string();
+            (rpc_amount != balance.amount).then(|| balances_pb::BalanceDrift {
+                contract: balance.contract.clone(),
+                address: balance.address.clone(),
+                amount: balance.amount.clone(),
+                rpc_amount,
+            })
+        })
+        .collect();
+    events.failed_lookups = lookups.failed;
+    events.drifts = drifts;
+}
+
+/// stETH shares keyed by `<contract>:<holder>` (hex-encoded), see [`steth`].
+#[substreams::handlers::store]
+fn store_steth_shares(tokens: tokens_pb::Events, store: StoreAddBigInt) {
+    for delta in steth::shares_deltas(&tokens) {
+        store.add(delta.ordinal, running::balance_key(&delta.contract, &delta.holder), delta.amount);
+    }
+}
+
+/// Last share rate of each stETH contract (`<contract>:pooled_ether` / `<contract>:total_shares`).
+#[substreams::handlers::store]
+fn store_steth_share_rate(tokens: tokens_pb::Events, store: StoreSetBigInt) {
+    for rate in steth::share_rates(&tokens) {
+        store.set(rate.ordinal, steth::pooled_ether_key(&rate.contract), &rate.pooled_ether);
+        store.set(rate.ordinal, steth::total_shares_key(&rate.contract), &rate.total_shares);
+    }
+}
+
+/// stETH shares and balances at the end of the block of the holders whose shares moved, and the
+/// share rates updated in the block. A rebase only emits its `ShareRate`: consumers derive the other
+/// balances as `shares * pooled_ether / total_shares` from each holder's last `Shares` row, instead
+/// of this module reading the shares of every holder ever seen on each rebase.
+/// No balance is emitted for a contract before its first share rate.
+#[substreams::handlers::map]
+fn map_steth_balances(tokens: tokens_pb::Events, shares: StoreGetBigInt, share_rate: StoreGetBigInt) -> Result<balances_pb::Events, substreams::errors::Error> {
+    // (contract, holder), sorted for a deterministic output
+    let moved: BTreeSet<(common::Address, common::Address)> = steth::shares_deltas(&tokens).into_iter().map(|delta| (delta.contract, delta.holder)).collect();
+
+    let mut events = balances_pb::Events {
+        share_rates: steth::share_rate_updates(&tokens),
+        ..Default::default()
+    };
+    let mut rates: HashMap<common::Address, Option<(BigInt, BigInt)>> = HashMap::new();
+    for (contract, address) in moved {
+        let amount = shares.get_last(running::balance_key(&contract, &address)).unwrap_or_else(BigInt::zero);
+        let rate = rates.entry(contract.to_vec()).or_insert_with(|| {
+            let pooled_ether = share_rate.get_last(steth::pooled_ether_key(&contract))?;
+            Some((pooled_ether, share_rate.get_last(steth::total_shares_key(&contract))?))
+        });
+        // No share rate yet: the `Shares` row is enough to derive the balance from the first rate.
+        if let Some(balance) = steth::pooled_ether_by_shares(&amount, rate.as_ref().map(|(pooled_ether, total_shares)| (pooled_ether, total_shares))) {
+            events.balances.push(balances_pb::Balance {
+                contract: Some(contract.to_vec()),
+                address: address.to_vec(),
+                amount: balance.to_string(),
+            });
+        }
+        events.shares.push(balances_pb::Shares {
+            contract,
+            address,
+            amount: amount.to_string(),
+        });
+    }
+    substreams::log::info!(
+        "stETH share_rates={} shares={} balances={}",
+        events.share_rates.len(),
+        events.shares.len(),
+        events.balances.len()
+    );
     Ok(events)
 }
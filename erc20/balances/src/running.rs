@@ -0,0 +1,249 @@
+//! Store-based running balances: every balance-moving event becomes a signed delta on the
+//! `contract:holder` key of `store_balances` (`add` policy), so `map_events_from_store` reads the
+//! resulting balances from the store deltas instead of calling `balanceOf`.
+//!
+//! Only issuer events that move balances *without* emitting a `Transfer` are counted here, since
+//! their `Transfer` is already a delta (USDC / WBTC / USDT0 mints and burns emit one):
+//!   - WETH `Deposit` (+wad to `dst`) / `Withdrawal` (-wad from `src`)
+//!   - USDT `Issue` (+amount to owner) / `Redeem` (-amount from owner)
+//!   - USDT `DestroyedBlackFunds` (-balance from `black_listed_user`)
+//!   - SAI `Mint` (+wad to `guy`) / `Burn` (-wad from `guy`)
+//!
+//! Rebasing tokens (stETH) and fee-on-transfer tokens drift from `balanceOf`; the optional RPC
+//! reconciliation sample reports it.
+
+use std::str::FromStr;
+
+use common::{is_valid_evm_address, Address};
+use proto::pb::erc20::tokens::v1 as tokens_pb;
+use proto::pb::erc20::transfers::v1 as transfers_pb;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+
+/// Periodic RPC reconciliation of `map_events_from_store`, from
+/// `reconcile_every=<blocks>&sample=<pairs>&chunk_size=<calls>` (empty params disable it).
+#[derive(Debug, PartialEq)]
+pub struct Reconciliation {
+    /// Block interval between samples (`0` = never).
+    pub every: u64,
+    /// Balances of the block compared with `balanceOf`.
+    pub sample: usize,
+    /// `balanceOf` calls per RPC batch.
+    pub chunk_size: usize,
+}
+
+impl Reconciliation {
+    pub fn from_params(params: &str) -> Self {
+        let mut reconciliation = Reconciliation {
+            every: 0,
+            sample: 100,
+            chunk_size: 100,
+        };
+        for pair in params.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or_else(|| panic!("Invalid param `{}`, expected key=value", pair));
+            match key {
+                "reconcile_every" => reconciliation.every = value.parse().unwrap_or_else(|_| panic!("Invalid reconcile_every `{}`", value)),
+                "sample" => reconciliation.sample = value.parse().unwrap_or_else(|_| panic!("Invalid sample `{}`", value)),
+                "chunk_size" => reconciliation.chunk_size = value.parse().unwrap_or_else(|_| panic!("Invalid chunk_size `{}`", value)),
+                _ => panic!("Unknown param `{}`", key),
+            }
+        }
+        reconciliation
+    }
+
+    pub fn is_due(&self, block_number: u64) -> bool {
+        self.every > 0 && self.sample > 0 && block_number % self.every == 0
+    }
+}
+
+/// A signed balance change of `holder` on `contract`.
+#[derive(Debug, PartialEq)]
+pub struct BalanceDelta {
+    pub ordinal: u64,
+    pub contract: Address,
+    pub holder: Address,
+    pub amount: BigInt,
+}
+
+/// Store key: `<contract>:<holder>` (hex-encoded, lowercase, no `0x`).
+pub fn balance_key(contract: &[u8], holder: &[u8]) -> String {
+    format!("{}:{}", Hex::encode(contract), Hex::encode(holder))
+}
+
+/// `(contract, holder)` of a `balance_key`.
+pub fn parse_balance_key(key: &str) -> Option<(Address, Address)> {
+    let (contract, holder) = key.split_once(':')?;
+    Some((Hex::decode(contract).ok()?, Hex::decode(holder).ok()?))
+}
+
+pub fn transfer_deltas(transfers: &transfers_pb::Events) -> Vec<BalanceDelta> {
+    let mut deltas = Vec::new();
+    for log in transfers.transactions.iter().flat_map(|tx| tx.logs.iter()) {
+        let Some(transfers_pb::log::Log::Transfer(transfer)) = &log.log else {
+            continue;
+        };
+        let Ok(amount) = BigInt::from_str(&transfer.amount) else {
+            continue;
+        };
+        push_delta(&mut deltas, log.ordinal, &log.address, &transfer.from, amount.neg());
+        push_delta(&mut deltas, log.ordinal, &log.address, &transfer.to, amount);
+    }
+    deltas
+}
+
+pub fn issuer_deltas(tokens: &tokens_pb::Events) -> Vec<BalanceDelta> {
+    let mut deltas = Vec::new();
+    for log in tokens.transactions.iter().flat_map(|tx| tx.logs.iter()) {
+        let (holder, amount, credit) = match &log.log {
+            Some(tokens_pb::log::Log::WethDeposit(deposit)) => (&deposit.dst, &deposit.wad, true),
+            Some(tokens_pb::log::Log::WethWithdrawal(withdrawal)) => (&withdrawal.src, &withdrawal.wad, false),
+            Some(tokens_pb::log::Log::UsdtIssue(issue)) => (&issue.owner, &issue.amount, true),
+            Some(tokens_pb::log::Log::UsdtRedeem(redeem)) => (&redeem.owner, &redeem.amount, false),
+            Some(tokens_pb::log::Log::UsdtDestroyedBlackFunds(destroyed)) => (&destroyed.black_listed_user, &destroyed.balance, false),
+            Some(tokens_pb::log::Log::SaiMint(mint)) => (&mint.guy, &mint.wad, true),
+            Some(tokens_pb::log::Log::SaiBurn(burn)) => (&burn.guy, &burn.wad, false),
+            _ => continue,
+        };
+        let Ok(amount) = BigInt::from_str(amount) else {
+            continue;
+        };
+        push_delta(&mut deltas, log.ordinal, &log.address, holder, if credit { amount } else { amount.neg() });
+    }
+    deltas
+}
+
+/// Skips zero amounts, the zero address (mint/burn counterparty) and malformed addresses.
+pub(crate) fn push_delta(deltas: &mut Vec<BalanceDelta>, ordinal: u64, contract: &[u8], holder: &[u8], amount: BigInt) {
+    if amount.is_zero() || !is_valid_evm_address(contract) || !is_valid_evm_address(holder) {
+        return;
+    }
+    deltas.push(BalanceDelta {
+        ordinal,
+        contract: contract.to_vec(),
+        holder: holder.to_vec(),
+        amount,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::NULL_ADDRESS;
+
+    const TOKEN: [u8; 20] = [0x11; 20];
+    const ALICE: [u8; 20] = [0xaa; 20];
+    const BOB: [u8; 20] = [0xbb; 20];
+
+    fn transfers(from: &[u8], to: &[u8], amount: &str) -> transfers_pb::Events {
+        transfers_pb::Events {
+            transactions: vec![transfers_pb::Transaction {
+                logs: vec![transfers_pb::Log {
+                    address: TOKEN.to_vec(),
+                    ordinal: 7,
+                    log: Some(transfers_pb::log::Log::Transfer(transfers_pb::Transfer {
+                        from: from.to_vec(),
+                        to: to.to_vec(),
+                        amount: amount.to_string(),
+                    })),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }
+    }
+
+    fn tokens(log: tokens_pb::log::Log) -> tokens_pb::Events {
+        tokens_pb::Events {
+            transactions: vec![tokens_pb::Transaction {
+                logs: vec![tokens_pb::Log {
+                    address: TOKEN.to_vec(),
+                    log: Some(log),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }
+    }
+
+    fn delta(holder: [u8; 20], amount: i64, ordinal: u64) -> BalanceDelta {
+        BalanceDelta {
+            ordinal,
+            contract: TOKEN.to_vec(),
+            holder: holder.to_vec(),
+            amount: BigInt::from(amount),
+        }
+    }
+
+    #[test]
+    fn transfer_debits_sender_and_credits_recipient() {
+        assert_eq!(
+            transfer_deltas(&transfers(&ALICE, &BOB, "100")),
+            vec![delta(ALICE, -100, 7), delta(BOB, 100, 7)]
+        );
+    }
+
+    #[test]
+    fn mint_and_burn_skip_the_zero_address() {
+        assert_eq!(transfer_deltas(&transfers(&NULL_ADDRESS, &BOB, "5")), vec![delta(BOB, 5, 7)]);
+        assert_eq!(transfer_deltas(&transfers(&ALICE, &NULL_ADDRESS, "5")), vec![delta(ALICE, -5, 7)]);
+        assert!(transfer_deltas(&transfers(&ALICE, &BOB, "0")).is_empty());
+    }
+
+    #[test]
+    fn issuer_events_without_transfer_move_balances() {
+        let deposit = tokens(tokens_pb::log::Log::WethDeposit(tokens_pb::WethDeposit {
+            dst: ALICE.to_vec(),
+            wad: "3".to_string(),
+        }));
+        let destroyed = tokens(tokens_pb::log::Log::UsdtDestroyedBlackFunds(tokens_pb::UsdtDestroyedBlackFunds {
+            black_listed_user: BOB.to_vec(),
+            balance: "9".to_string(),
+        }));
+
+        assert_eq!(issuer_deltas(&deposit), vec![delta(ALICE, 3, 0)]);
+        assert_eq!(issuer_deltas(&destroyed), vec![delta(BOB, -9, 0)]);
+    }
+
+    #[test]
+    fn issuer_events_with_transfer_are_not_counted_twice() {
+        let mint = tokens(tokens_pb::log::Log::UsdcMint(tokens_pb::UsdcMint {
+            minter: ALICE.to_vec(),
+            to: BOB.to_vec(),
+            amount: "1".to_string(),
+        }));
+
+        assert!(issuer_deltas(&mint).is_empty());
+    }
+
+    #[test]
+    fn reconciliation_params() {
+        let disabled = Reconciliation::from_params("");
+        assert!(!disabled.is_due(0) && !disabled.is_due(1000));
+
+        let reconciliation = Reconciliation::from_params("reconcile_every=1000&sample=10");
+        assert_eq!(
+            reconciliation,
+            Reconciliation {
+                every: 1000,
+                sample: 10,
+                chunk_size: 100
+            }
+        );
+        assert!(reconciliation.is_due(2000));
+        assert!(!reconciliation.is_due(2001));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown param `every`")]
+    fn reconciliation_rejects_unknown_params() {
+        Reconciliation::from_params("every=10");
+    }
+
+    #[test]
+    fn balance_key_round_trips() {
+        let key = balance_key(&TOKEN, &ALICE);
+        assert_eq!(key, format!("{}:{}", "11".repeat(20), "aa".repeat(20)));
+        assert_eq!(parse_balance_key(&key), Some((TOKEN.to_vec(), ALICE.to_vec())));
+        assert_eq!(parse_balance_key("nope"), None);
+    }
+}
@@ -0,0 +1,227 @@
+//! Shares-based stETH balances. stETH balances are `shares * pooled_ether / total_shares`, so the
+//! daily rebase changes every holder's balance without any `Transfer` event. Instead of balances,
+//! `store_steth_shares` keeps the shares of each holder (`add` policy, key `<contract>:<holder>`):
+//!   - `TransferShares` (-shares from `from`, +shares to `to`; mints come from the zero address)
+//!   - `SharesBurnt` (-shares from `account`, no `TransferShares` is emitted)
+//!
+//! `ExternalSharesMinted` / `ExternalSharesBurnt` are not counted, they come with a `TransferShares`
+//! / `SharesBurnt`. The share rate is the last `TokenRebased` (`post_total_ether / post_total_shares`)
+//! or `InternalShareRateUpdated` (`post_internal_ether / post_internal_shares`) of the contract.
+//!
+//! Shares are only complete when `store_steth_shares` starts at the stETH deployment (mainnet block
+//! 11473216). A rebase emits a single `ShareRate` row per contract rather than every holder's balance:
+//! the cost of a block is its `TransferShares` / `SharesBurnt` count, not the number of holders.
+//! Balances are only emitted once a share rate is known (`TokenRebased` is emitted since the Lido V2
+//! upgrade).
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use common::Address;
+use proto::pb::erc20::tokens::v1 as tokens_pb;
+use proto::pb::evm::balances::v1 as balances_pb;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+
+use crate::running::{push_delta, BalanceDelta};
+
+/// `store_steth_share_rate` keys of `contract`.
+pub fn pooled_ether_key(contract: &[u8]) -> String {
+    format!("{}:pooled_ether", Hex::encode(contract))
+}
+
+pub fn total_shares_key(contract: &[u8]) -> String {
+    format!("{}:total_shares", Hex::encode(contract))
+}
+
+pub fn shares_deltas(tokens: &tokens_pb::Events) -> Vec<BalanceDelta> {
+    let mut deltas = Vec::new();
+    for log in tokens.transactions.iter().flat_map(|tx| tx.logs.iter()) {
+        match &log.log {
+            Some(tokens_pb::log::Log::StethTransferShares(transfer)) => {
+                let Ok(shares) = BigInt::from_str(&transfer.shares_value) else {
+                    continue;
+                };
+                push_delta(&mut deltas, log.ordinal, &log.address, &transfer.from, shares.neg());
+                push_delta(&mut deltas, log.ordinal, &log.address, &transfer.to, shares);
+            }
+            Some(tokens_pb::log::Log::StethSharesBurnt(burnt)) => {
+                let Ok(shares) = BigInt::from_str(&burnt.shares_amount) else {
+                    continue;
+                };
+                push_delta(&mut deltas, log.ordinal, &log.address, &burnt.account, shares.neg());
+            }
+            _ => {}
+        }
+    }
+    deltas
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ShareRate {
+    pub ordinal: u64,
+    pub contract: Address,
+    pub pooled_ether: BigInt,
+    pub total_shares: BigInt,
+}
+
+/// `getPooledEthByShares` with the `(pooled_ether, total_shares)` rate, rounded down like the
+/// contract. `None` until a share rate has been seen: shares are not an ether amount.
+pub fn pooled_ether_by_shares(shares: &BigInt, rate: Option<(&BigInt, &BigInt)>) -> Option<BigInt> {
+    let (pooled_ether, total_shares) = rate.filter(|(_, total_shares)| !total_shares.is_zero())?;
+    Some(shares.clone() * pooled_ether.clone() / total_shares.clone())
+}
+
+pub fn share_rates(tokens: &tokens_pb::Events) -> Vec<ShareRate> {
+    let mut rates = Vec::new();
+    for log in tokens.transactions.iter().flat_map(|tx| tx.logs.iter()) {
+        let (pooled_ether, total_shares) = match &log.log {
+            Some(tokens_pb::log::Log::StethTokenRebased(rebased)) => (&rebased.post_total_ether, &rebased.post_total_shares),
+            Some(tokens_pb::log::Log::StethInternalShareRateUpdated(updated)) => (&updated.post_internal_ether, &updated.post_internal_shares),
+            _ => continue,
+        };
+        let (Ok(pooled_ether), Ok(total_shares)) = (BigInt::from_str(pooled_ether), BigInt::from_str(total_shares)) else {
+            continue;
+        };
+        if total_shares.is_zero() || !common::is_valid_evm_address(&log.address) {
+            continue;
+        }
+        rates.push(ShareRate {
+            ordinal: log.ordinal,
+            contract: log.address.to_vec(),
+            pooled_ether,
+            total_shares,
+        });
+    }
+    rates
+}
+
+/// `ShareRate` rows of the block: the last share rate of each contract, like `store_steth_share_rate`.
+pub fn share_rate_updates(tokens: &tokens_pb::Events) -> Vec<balances_pb::ShareRate> {
+    let mut last: BTreeMap<Address, ShareRate> = BTreeMap::new();
+    for rate in share_rates(tokens) {
+        last.insert(rate.contract.to_vec(), rate);
+    }
+    last.into_values()
+        .map(|rate| balances_pb::ShareRate {
+            contract: rate.contract,
+            pooled_ether: rate.pooled_ether.to_string(),
+            total_shares: rate.total_shares.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::NULL_ADDRESS;
+
+    const STETH: [u8; 20] = [0x11; 20];
+    const ALICE: [u8; 20] = [0xaa; 20];
+    const BOB: [u8; 20] = [0xbb; 20];
+
+    fn tokens(logs: Vec<tokens_pb::log::Log>) -> tokens_pb::Events {
+        tokens_pb::Events {
+            transactions: vec![tokens_pb::Transaction {
+                logs: logs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ordinal, log)| tokens_pb::Log {
+                        address: STETH.to_vec(),
+                        ordinal: ordinal as u64,
+                        log: Some(log),
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }],
+        }
+    }
+
+    fn transfer_shares(from: &[u8], to: &[u8], shares: &str) -> tokens_pb::log::Log {
+        tokens_pb::log::Log::StethTransferShares(tokens_pb::StethTransferShares {
+            from: from.to_vec(),
+            to: to.to_vec(),
+            shares_value: shares.to_string(),
+        })
+    }
+
+    fn delta(holder: [u8; 20], amount: i64, ordinal: u64) -> BalanceDelta {
+        BalanceDelta {
+            ordinal,
+            contract: STETH.to_vec(),
+            holder: holder.to_vec(),
+            amount: BigInt::from(amount),
+        }
+    }
+
+    #[test]
+    fn shares_move_with_transfer_shares_and_shares_burnt() {
+        let events = tokens(vec![
+            transfer_shares(&NULL_ADDRESS, &ALICE, "10"),
+            transfer_shares(&ALICE, &BOB, "4"),
+            tokens_pb::log::Log::StethSharesBurnt(tokens_pb::StethSharesBurnt {
+                account: BOB.to_vec(),
+                shares_amount: "1".to_string(),
+                ..Default::default()
+            }),
+        ]);
+
+        assert_eq!(
+            shares_deltas(&events),
+            vec![delta(ALICE, 10, 0), delta(ALICE, -4, 1), delta(BOB, 4, 1), delta(BOB, -1, 2)]
+        );
+    }
+
+    #[test]
+    fn rebase_sets_the_share_rate() {
+        let events = tokens(vec![
+            tokens_pb::log::Log::StethTokenRebased(tokens_pb::StethTokenRebased {
+                post_total_shares: "100".to_string(),
+                post_total_ether: "110".to_string(),
+                ..Default::default()
+            }),
+            tokens_pb::log::Log::StethInternalShareRateUpdated(tokens_pb::StethInternalShareRateUpdated {
+                post_internal_shares: "0".to_string(),
+                post_internal_ether: "1".to_string(),
+                ..Default::default()
+            }),
+        ]);
+
+        let rates = share_rates(&events);
+        assert_eq!(
+            rates,
+            vec![ShareRate {
+                ordinal: 0,
+                contract: STETH.to_vec(),
+                pooled_ether: BigInt::from(110),
+                total_shares: BigInt::from(100),
+            }]
+        );
+        let rate = (&rates[0].pooled_ether, &rates[0].total_shares);
+        assert_eq!(pooled_ether_by_shares(&BigInt::from(15), Some(rate)), Some(BigInt::from(16)));
+        assert_eq!(pooled_ether_by_shares(&BigInt::from(15), None), None);
+    }
+
+    #[test]
+    fn rebase_emits_the_last_share_rate_of_the_block() {
+        let rebased = |ether: &str, shares: &str| {
+            tokens_pb::log::Log::StethTokenRebased(tokens_pb::StethTokenRebased {
+                post_total_shares: shares.to_string(),
+                post_total_ether: ether.to_string(),
+                ..Default::default()
+            })
+        };
+        let events = tokens(vec![rebased("110", "100"), transfer_shares(&ALICE, &BOB, "4"), rebased("121", "100")]);
+
+        assert_eq!(
+            share_rate_updates(&events),
+            vec![balances_pb::ShareRate {
+                contract: STETH.to_vec(),
+                pooled_ether: "121".to_string(),
+                total_shares: "100".to_string(),
+            }]
+        );
+        assert!(share_rate_updates(&tokens(vec![transfer_shares(&ALICE, &BOB, "4")])).is_empty());
+    }
+}
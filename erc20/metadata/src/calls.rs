@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use common::rpc::execute_batch;
+use common::{bigint_to_u8, Address};
+use substreams::{log, scalar::BigInt, Hex};
+use substreams_abis::standard::erc20;
+use substreams_ethereum::pb::eth::rpc::RpcResponse;
+use substreams_ethereum::rpc::RpcBatch;
+
+pub struct TokenMetadata {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<i32>,
+}
+
+#[derive(Clone, Copy)]
+enum MetadataCall {
+    Name,
+    Symbol,
+    Decimals,
+}
+
+/// Batch fetch name(), symbol() and decimals() for a list of contracts (3 calls per contract). Failed batches
+/// are retried and bisected (see `common::rpc`), so a failing contract never drops the others.
+pub fn batch_metadata<'a>(contracts: &'a [&Address], chunk_size: usize) -> HashMap<&'a Address, TokenMetadata> {
+    let calls: Vec<(&Address, MetadataCall)> = contracts
+        .iter()
+        .flat_map(|contract| [MetadataCall::Name, MetadataCall::Symbol, MetadataCall::Decimals].map(|call| (*contract, call)))
+        .collect();
+    let responses = execute_batch(&calls, chunk_size * 3, |batch, (contract, call)| match call {
+        MetadataCall::Name => batch.add(erc20::functions::Name {}, contract.to_vec()),
+        MetadataCall::Symbol => batch.add(erc20::functions::Symbol {}, contract.to_vec()),
+        MetadataCall::Decimals => batch.add(erc20::functions::Decimals {}, contract.to_vec()),
+    });
+
+    let mut results: HashMap<&Address, TokenMetadata> = HashMap::with_capacity(contracts.len());
+    for (contract, responses) in contracts.iter().zip(responses.chunks(3)) {
+        let [name, symbol, decimals] = [0, 1, 2].map(|i| responses[i].as_ref().ok());
+        let name = name.and_then(|response| RpcBatch::decode::<String, erc20::functions::Name>(response).or_else(|| decode_bytes32(response)));
+        let symbol = symbol.and_then(|response| RpcBatch::decode::<String, erc20::functions::Symbol>(response).or_else(|| decode_bytes32(response)));
+        let decimals = decimals
+            .and_then(|response| RpcBatch::decode::<BigInt, erc20::functions::Decimals>(response))
+            .and_then(|decimals| bigint_to_u8(&decimals));
+
+        if name.is_none() && symbol.is_none() && decimals.is_none() {
+            log::info!("Failed to decode erc20 metadata for contract={:?}", Hex::encode(contract));
+            continue;
+        }
+        results.insert(
+            contract,
+            TokenMetadata {
+                name: name.map(|name| sanitize(&name)),
+                symbol: symbol.map(|symbol| sanitize(&symbol)),
+                decimals,
+            },
+        );
+    }
+    log::info!(
+        "\nMetadata={}\nRpcBatch={}\nMissing={}",
+        contracts.len(),
+        contracts.chunks(chunk_size).len(),
+        contracts.len() - results.len()
+    );
+    results
+}
+
+/// Tokens such as MKR and SAI return `bytes32` instead of `string` for name() and symbol().
+pub fn decode_bytes32(response: &RpcResponse) -> Option<String> {
+    if response.failed || response.raw.len() != 32 {
+        return None;
+    }
+    let end = response.raw.iter().rposition(|byte| *byte != 0)? + 1;
+    String::from_utf8(response.raw[..end].to_vec()).ok()
+}
+
+/// Drop NUL and surrounding whitespace, which some tokens pad their strings with.
+pub fn sanitize(value: &str) -> String {
+    value.replace('\0', "").trim().to_string()
+}
@@ -0,0 +1,55 @@
+mod calls;
+
+use calls::batch_metadata;
+use common::rpc::parse_chunk_size;
+use proto::pb::erc20::metadata::v1 as metadata_pb;
+use proto::pb::erc20::transfers::v1 as transfers_pb;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::pb::substreams::Clock;
+use substreams::store::{DeltaInt64, Deltas, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsInt64};
+use substreams::Hex;
+
+#[substreams::handlers::store]
+fn store_contracts(clock: Clock, transfers: transfers_pb::Events, store: StoreSetIfNotExistsInt64) {
+    for log in transfers.transactions.iter().flat_map(|tx| tx.logs.iter()) {
+        if let Some(transfers_pb::log::Log::Transfer(_)) = &log.log {
+            if common::is_valid_evm_address(&log.address) {
+                store.set_if_not_exists(log.ordinal, Hex::encode(&log.address), &(clock.number as i64));
+            }
+        }
+    }
+}
+
+#[substreams::handlers::map]
+fn map_events(params: String, deltas: Deltas<DeltaInt64>) -> Result<metadata_pb::Events, substreams::errors::Error> {
+    let mut events = metadata_pb::Events::default();
+    let chunk_size = parse_chunk_size(&params)?;
+
+    // Only contracts seen for the first time in this block (store `Create` deltas)
+    let contracts: Vec<common::Address> = deltas
+        .deltas
+        .iter()
+        .filter(|delta| delta.operation == Operation::Create)
+        .filter_map(|delta| Hex::decode(&delta.key).ok())
+        .collect();
+
+    if contracts.is_empty() {
+        return Ok(events);
+    }
+
+    let contracts: Vec<&common::Address> = contracts.iter().collect();
+    let metadata = batch_metadata(&contracts, chunk_size);
+
+    for contract in &contracts {
+        if let Some(token) = metadata.get(contract) {
+            events.metadata.push(metadata_pb::Metadata {
+                contract: contract.to_vec(),
+                name: token.name.clone(),
+                symbol: token.symbol.clone(),
+                decimals: token.decimals,
+            });
+        }
+    }
+
+    Ok(events)
+}
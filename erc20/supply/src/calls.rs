@@ -1,32 +1,28 @@
 use std::collections::HashMap;
 
+use common::rpc::execute_batch;
 use common::Address;
 use substreams::{log, scalar::BigInt, Hex};
 use substreams_abis::standard::erc20;
 use substreams_ethereum::rpc::RpcBatch;
 
-/// Batch fetch totalSupply() for a list of contracts.
+/// Batch fetch totalSupply() for a list of contracts. Failed batches are retried and bisected
+/// (see `common::rpc`), so a failing contract never drops the others.
 pub fn batch_total_supply<'a>(contracts: &'a [&Address], chunk_size: usize) -> HashMap<&'a Address, BigInt> {
-    let mut results: HashMap<&Address, BigInt> = HashMap::with_capacity(contracts.len());
+    let responses = execute_batch(contracts, chunk_size, |batch, contract| {
+        batch.add(erc20::functions::TotalSupply {}, contract.to_vec())
+    });
 
-    for chunk in contracts.chunks(chunk_size) {
-        let batch = chunk.iter().fold(RpcBatch::new(), |batch, contract| {
-            batch.add(erc20::functions::TotalSupply {}, contract.to_vec())
-        });
-        let responses = batch.execute().expect("failed to execute erc20::functions::TotalSupply batch").responses;
-        for (i, contract) in chunk.iter().enumerate() {
-            if let Some(value) = RpcBatch::decode::<BigInt, erc20::functions::TotalSupply>(&responses[i]) {
+    let mut results: HashMap<&Address, BigInt> = HashMap::with_capacity(contracts.len());
+    for (contract, response) in contracts.iter().zip(responses) {
+        match response.map(|response| RpcBatch::decode::<BigInt, erc20::functions::TotalSupply>(&response)) {
+            Ok(Some(value)) => {
                 results.insert(contract, value);
-            } else {
-                substreams::log::info!("Failed to decode erc20::TotalSupply for contract={:?}", Hex::encode(contract),);
             }
+            Ok(None) => log::info!("Failed to decode erc20::TotalSupply for contract={:?}", Hex::encode(contract)),
+            Err(error) => log::info!("Failed to call erc20::TotalSupply for contract={:?}: {}", Hex::encode(contract), error),
         }
     }
-    log::info!(
-        "\nTotalSupply={}\nRpcBatch={}\nMissing={}",
-        contracts.len(),
-        contracts.chunks(chunk_size).len(),
-        contracts.len() - results.len()
-    );
+    log::info!("\nTotalSupply={}\nMissing={}", contracts.len(), contracts.len() - results.len());
     results
 }
@@ -3,13 +3,14 @@ mod calls;
 use std::collections::HashSet;
 
 use calls::batch_total_supply;
+use common::rpc::parse_chunk_size;
 use proto::pb::erc20::supply::v1 as supply_pb;
 use proto::pb::evm::balances::v1 as balances_pb;
 
 #[substreams::handlers::map]
 fn map_events(params: String, erc20_balance_events: balances_pb::Events) -> Result<supply_pb::Events, substreams::errors::Error> {
     let mut events = supply_pb::Events::default();
-    let chunk_size = params.parse::<usize>().expect("Failed to parse chunk_size");
+    let chunk_size = parse_chunk_size(&params)?;
 
     // Collect unique contract addresses from balance events
     let contracts: Vec<&common::Address> = erc20_balance_events
@@ -0,0 +1,382 @@
+//! Privileged-function events of DAI/USDS (Sky), PayPal PYUSD (Paxos), Ethena USDe and First Digital
+//! FDUSD, decoded from raw logs by `topic0` (like the other tokens, any contract emitting the signature
+//! matches, except FDUSD whose OpenZeppelin events are only decoded from the FDUSD contract).
+//!
+//! USDS and its join adapter emit named `Rely` / `Deny` / `Join` / `Exit` events. DAI and `DaiJoin`
+//! predate them: their `note` functions emit the anonymous `LibNote` event
+//! `LogNote(bytes4 indexed sig, address indexed usr, bytes32 indexed arg1, bytes32 indexed arg2, bytes data)`,
+//! whose `topics[0]` is the called function selector (left-aligned) and `topics[2..4]` its first two
+//! arguments. `join` / `exit` notes are only decoded for `DaiJoin`, collateral `GemJoin` adapters
+//! share their selector.
+//!
+//! Circle EURC is a FiatToken v2.2 (same events as USDC) and is decoded by the USDC events. FDUSD
+//! mints and burns are `Transfer` events from / to the zero address (`erc20-transfers`) made by its
+//! owner, so its privileged events are the ownership transfers (minter changes) and pauses. Its
+//! blocklist events are not decoded.
+
+use proto::pb::erc20::tokens::v1 as pb;
+use substreams::hex;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2::Log;
+
+// DAI / USDS (Sky)
+const RELY_TOPIC: [u8; 32] = hex!("dd0e34038ac38b2a1ce960229778ac48a8719bc900b6c4f8d0475c6e8b385a60");
+const DENY_TOPIC: [u8; 32] = hex!("184450df2e323acec0ed3b5c7531b81f9b4cdef7914dfd4c0a4317416bb5251b");
+const JOIN_TOPIC: [u8; 32] = hex!("0e64978d073561c3dfd4d4e3e4dce066cde2ab246a44f990fabb0a21a4a3bd95");
+const EXIT_TOPIC: [u8; 32] = hex!("bc2a67d422c268da6fe45f3e7d194e1d98906d221f1cfad62a5c80f2cd209f4c");
+
+// DAI / DaiJoin `LogNote` selectors: rely(address), deny(address), join(address,uint256), exit(address,uint256)
+const RELY_NOTE: [u8; 32] = hex!("65fae35e00000000000000000000000000000000000000000000000000000000");
+const DENY_NOTE: [u8; 32] = hex!("9c52a7f100000000000000000000000000000000000000000000000000000000");
+const JOIN_NOTE: [u8; 32] = hex!("3b4da69f00000000000000000000000000000000000000000000000000000000");
+const EXIT_NOTE: [u8; 32] = hex!("ef693bed00000000000000000000000000000000000000000000000000000000");
+
+/// Token of the Ethereum join adapters (`MCD_JOIN_DAI` -> DAI, `USDS_JOIN` -> USDS).
+const JOIN_TOKENS: &[([u8; 20], [u8; 20])] = &[
+    (
+        hex!("9759a6ac90977b93b58547b4a71c78317f391a28"),
+        hex!("6b175474e89094c44da98b954eedeac495271d0f"),
+    ),
+    (
+        hex!("3c0f895007ca717aa01c8693e59df1e8c3777feb"),
+        hex!("dc035d45d973e3ec169d2276ddab16f1e407384f"),
+    ),
+];
+
+// PYUSD (Paxos)
+const SUPPLY_INCREASED_TOPIC: [u8; 32] = hex!("f5c174d57843e57fea3c649fdde37f015ef08750759cbee88060390566a98797");
+const SUPPLY_DECREASED_TOPIC: [u8; 32] = hex!("1b7e18241beced0d7f41fbab1ea8ed468732edbcb74ec4420151654ca71c8a63");
+const SUPPLY_CONTROLLER_SET_TOPIC: [u8; 32] = hex!("40d53b0b666e4424f29d55244e7e171a1dc332acc11d04ed4abd884629d8cc97");
+const ADDRESS_FROZEN_TOPIC: [u8; 32] = hex!("90811a8edd3b3c17eeaefffc17f639cc69145d41a359c9843994dc2538203690");
+const ADDRESS_UNFROZEN_TOPIC: [u8; 32] = hex!("c3776b472ebf54114339eec9e4dc924e7ce307a97f5c1ee72b6d474e6e5e8b7c");
+const FROZEN_ADDRESS_WIPED_TOPIC: [u8; 32] = hex!("fc5960f1c5a5d2b60f031bf534af053b1bf7d9881989afaeb8b1d164db23aede");
+
+// USDe / sUSDe (Ethena)
+const MINTER_UPDATED_TOPIC: [u8; 32] = hex!("1cf2de25c5bf439ac0287061c3a0fa69b3b02867d0ccfd2ded34e42577050b73");
+const ROLE_GRANTED_TOPIC: [u8; 32] = hex!("2f8788117e7eff1d82e926ec794901d17c78024a50270940304540a733656f0d");
+const ROLE_REVOKED_TOPIC: [u8; 32] = hex!("f6391f5c32d9c69d2a47ea670b442974b53935d1edc7fd64eb21e047a839171b");
+const LOCKED_AMOUNT_REDISTRIBUTED_TOPIC: [u8; 32] = hex!("b8ef21f2b52f8ca740012254a6b10f17d2fd6e589f97ebf401fde0e8b9218937");
+/// keccak256("FULL_RESTRICTED_STAKER_ROLE"): can't transfer, stake or unstake (blacklisted)
+const FULL_RESTRICTED_STAKER_ROLE: [u8; 32] = hex!("0a4af4bcc1942295207d9f047442ebdae6170a6e324850f758b14cf99b65c3bd");
+/// keccak256("SOFT_RESTRICTED_STAKER_ROLE"): can't stake
+const SOFT_RESTRICTED_STAKER_ROLE: [u8; 32] = hex!("8f7080408a06296c6347c87c115ad99669141ae35eae974c12dff8bd01680cb6");
+
+// FDUSD (First Digital), same address on Ethereum and BSC
+const FDUSD: [u8; 20] = hex!("c5f0f7b66764f6ec8c8dff7ba683102295e16409");
+const OWNERSHIP_TRANSFERRED_TOPIC: [u8; 32] = hex!("8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0");
+const PAUSED_TOPIC: [u8; 32] = hex!("62e78cea01bee320cd4e420270b5ea74000d11b0c9f74754ebdbfc544b05a258");
+const UNPAUSED_TOPIC: [u8; 32] = hex!("5db9ee0a495bf2e6ff9c91a7834c1ba4fdd244a5e8aa4e537bd38aeae4b073aa");
+
+/// Address of an indexed `topics[index]` (left-padded to 32 bytes).
+fn topic_address(log: &Log, index: usize) -> Option<Vec<u8>> {
+    let topic = log.topics.get(index)?;
+    (topic.len() == 32).then(|| topic[12..].to_vec())
+}
+
+/// Address of the first data word.
+fn data_address(log: &Log) -> Option<Vec<u8>> {
+    let word = log.data.get(0..32)?;
+    Some(word[12..].to_vec())
+}
+
+/// `uint256` of the first data word.
+fn data_uint(log: &Log) -> Option<String> {
+    let word = log.data.get(0..32)?;
+    Some(BigInt::from_unsigned_bytes_be(word).to_string())
+}
+
+fn is_event(log: &Log, topic: &[u8; 32], topics: usize) -> bool {
+    log.topics.len() == topics && log.topics[0].as_slice() == topic
+}
+
+/// `usr` of a named `Rely` / `Deny` event or of its `LogNote`.
+fn ward(log: &Log, topic: &[u8; 32], note: &[u8; 32]) -> Option<Vec<u8>> {
+    if is_event(log, topic, 2) {
+        return topic_address(log, 1);
+    }
+    if is_event(log, note, 4) {
+        return topic_address(log, 2);
+    }
+    None
+}
+
+/// `(caller, usr, wad, token)` of a named `Join` / `Exit` event or of a `DaiJoin` `LogNote`.
+fn join_exit(log: &Log, topic: &[u8; 32], note: &[u8; 32]) -> Option<(Vec<u8>, Vec<u8>, String, Vec<u8>)> {
+    let token = JOIN_TOKENS.iter().find(|(adapter, _)| log.address == adapter).map(|(_, token)| token.to_vec());
+    if is_event(log, topic, 3) {
+        return Some((topic_address(log, 1)?, topic_address(log, 2)?, data_uint(log)?, token.unwrap_or_default()));
+    }
+    if is_event(log, note, 4) && token.is_some() {
+        let wad = BigInt::from_unsigned_bytes_be(log.topics[3].as_slice()).to_string();
+        return Some((topic_address(log, 1)?, topic_address(log, 2)?, wad, token?));
+    }
+    None
+}
+
+pub fn usds_rely(log: &Log) -> Option<pb::UsdsRely> {
+    Some(pb::UsdsRely {
+        usr: ward(log, &RELY_TOPIC, &RELY_NOTE)?,
+    })
+}
+
+pub fn usds_deny(log: &Log) -> Option<pb::UsdsDeny> {
+    Some(pb::UsdsDeny {
+        usr: ward(log, &DENY_TOPIC, &DENY_NOTE)?,
+    })
+}
+
+pub fn usds_join(log: &Log) -> Option<pb::UsdsJoin> {
+    let (caller, usr, wad, token) = join_exit(log, &JOIN_TOPIC, &JOIN_NOTE)?;
+    Some(pb::UsdsJoin { caller, usr, wad, token })
+}
+
+pub fn usds_exit(log: &Log) -> Option<pb::UsdsExit> {
+    let (caller, usr, wad, token) = join_exit(log, &EXIT_TOPIC, &EXIT_NOTE)?;
+    Some(pb::UsdsExit { caller, usr, wad, token })
+}
+
+pub fn pyusd_supply_increased(log: &Log) -> Option<pb::PyusdSupplyIncreased> {
+    if !is_event(log, &SUPPLY_INCREASED_TOPIC, 2) {
+        return None;
+    }
+    Some(pb::PyusdSupplyIncreased {
+        to: topic_address(log, 1)?,
+        value: data_uint(log)?,
+    })
+}
+
+pub fn pyusd_supply_decreased(log: &Log) -> Option<pb::PyusdSupplyDecreased> {
+    if !is_event(log, &SUPPLY_DECREASED_TOPIC, 2) {
+        return None;
+    }
+    Some(pb::PyusdSupplyDecreased {
+        from: topic_address(log, 1)?,
+        value: data_uint(log)?,
+    })
+}
+
+pub fn pyusd_supply_controller_set(log: &Log) -> Option<pb::PyusdSupplyControllerSet> {
+    if !is_event(log, &SUPPLY_CONTROLLER_SET_TOPIC, 3) {
+        return None;
+    }
+    Some(pb::PyusdSupplyControllerSet {
+        old_supply_controller: topic_address(log, 1)?,
+        new_supply_controller: topic_address(log, 2)?,
+    })
+}
+
+pub fn pyusd_address_frozen(log: &Log) -> Option<pb::PyusdAddressFrozen> {
+    if !is_event(log, &ADDRESS_FROZEN_TOPIC, 2) {
+        return None;
+    }
+    Some(pb::PyusdAddressFrozen { addr: topic_address(log, 1)? })
+}
+
+pub fn pyusd_address_unfrozen(log: &Log) -> Option<pb::PyusdAddressUnfrozen> {
+    if !is_event(log, &ADDRESS_UNFROZEN_TOPIC, 2) {
+        return None;
+    }
+    Some(pb::PyusdAddressUnfrozen { addr: topic_address(log, 1)? })
+}
+
+pub fn pyusd_frozen_address_wiped(log: &Log) -> Option<pb::PyusdFrozenAddressWiped> {
+    if !is_event(log, &FROZEN_ADDRESS_WIPED_TOPIC, 2) {
+        return None;
+    }
+    Some(pb::PyusdFrozenAddressWiped { addr: topic_address(log, 1)? })
+}
+
+pub fn usde_minter_updated(log: &Log) -> Option<pb::UsdeMinterUpdated> {
+    if !is_event(log, &MINTER_UPDATED_TOPIC, 3) {
+        return None;
+    }
+    Some(pb::UsdeMinterUpdated {
+        new_minter: topic_address(log, 1)?,
+        old_minter: topic_address(log, 2)?,
+    })
+}
+
+/// `(account, full_restriction)` of a sUSDe restricted staker role change, other roles are skipped.
+fn restricted_staker(log: &Log, topic: &[u8; 32]) -> Option<(Vec<u8>, bool)> {
+    if !is_event(log, topic, 4) {
+        return None;
+    }
+    let full_restriction = match log.topics[1].as_slice() {
+        role if role == FULL_RESTRICTED_STAKER_ROLE => true,
+        role if role == SOFT_RESTRICTED_STAKER_ROLE => false,
+        _ => return None,
+    };
+    Some((topic_address(log, 2)?, full_restriction))
+}
+
+pub fn usde_blacklist_added(log: &Log) -> Option<pb::UsdeBlacklistAdded> {
+    let (account, full_restriction) = restricted_staker(log, &ROLE_GRANTED_TOPIC)?;
+    Some(pb::UsdeBlacklistAdded { account, full_restriction })
+}
+
+pub fn usde_blacklist_removed(log: &Log) -> Option<pb::UsdeBlacklistRemoved> {
+    let (account, full_restriction) = restricted_staker(log, &ROLE_REVOKED_TOPIC)?;
+    Some(pb::UsdeBlacklistRemoved { account, full_restriction })
+}
+
+pub fn usde_locked_amount_redistributed(log: &Log) -> Option<pb::UsdeLockedAmountRedistributed> {
+    if !is_event(log, &LOCKED_AMOUNT_REDISTRIBUTED_TOPIC, 3) {
+        return None;
+    }
+    Some(pb::UsdeLockedAmountRedistributed {
+        from: topic_address(log, 1)?,
+        to: topic_address(log, 2)?,
+        amount: data_uint(log)?,
+    })
+}
+
+fn is_fdusd_event(log: &Log, topic: &[u8; 32], topics: usize) -> bool {
+    log.address == FDUSD && is_event(log, topic, topics)
+}
+
+pub fn fdusd_ownership_transferred(log: &Log) -> Option<pb::FdusdOwnershipTransferred> {
+    if !is_fdusd_event(log, &OWNERSHIP_TRANSFERRED_TOPIC, 3) {
+        return None;
+    }
+    Some(pb::FdusdOwnershipTransferred {
+        previous_owner: topic_address(log, 1)?,
+        new_owner: topic_address(log, 2)?,
+    })
+}
+
+pub fn fdusd_paused(log: &Log) -> Option<pb::FdusdPaused> {
+    if !is_fdusd_event(log, &PAUSED_TOPIC, 1) {
+        return None;
+    }
+    Some(pb::FdusdPaused { account: data_address(log)? })
+}
+
+pub fn fdusd_unpaused(log: &Log) -> Option<pb::FdusdUnpaused> {
+    if !is_fdusd_event(log, &UNPAUSED_TOPIC, 1) {
+        return None;
+    }
+    Some(pb::FdusdUnpaused { account: data_address(log)? })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn padded(address: &[u8]) -> Vec<u8> {
+        [vec![0u8; 12], address.to_vec()].concat()
+    }
+
+    fn log(topics: Vec<Vec<u8>>, data: Vec<u8>) -> Log {
+        Log {
+            topics,
+            data,
+            ..Default::default()
+        }
+    }
+
+    fn uint(value: u64) -> Vec<u8> {
+        [vec![0u8; 24], value.to_be_bytes().to_vec()].concat()
+    }
+
+    #[test]
+    fn decodes_supply_and_freeze_events() {
+        let increased = log(vec![SUPPLY_INCREASED_TOPIC.to_vec(), padded(&[0xaa; 20])], uint(1_000));
+        assert_eq!(
+            pyusd_supply_increased(&increased),
+            Some(pb::PyusdSupplyIncreased {
+                to: vec![0xaa; 20],
+                value: "1000".to_string(),
+            })
+        );
+        assert_eq!(pyusd_supply_decreased(&increased), None);
+
+        let exit = log(vec![EXIT_TOPIC.to_vec(), padded(&[0xbb; 20]), padded(&[0xcc; 20])], uint(5));
+        assert_eq!(usds_exit(&exit).map(|exit| (exit.usr, exit.wad)), Some((vec![0xcc; 20], "5".to_string())));
+
+        let frozen = log(vec![ADDRESS_FROZEN_TOPIC.to_vec(), padded(&[0xdd; 20])], vec![]);
+        assert_eq!(pyusd_address_frozen(&frozen), Some(pb::PyusdAddressFrozen { addr: vec![0xdd; 20] }));
+    }
+
+    #[test]
+    fn decodes_dai_log_notes() {
+        let note = |selector: [u8; 32], arg1: Vec<u8>, arg2: Vec<u8>| log(vec![selector.to_vec(), padded(&[0xbb; 20]), arg1, arg2], vec![]);
+
+        let rely = note(RELY_NOTE, padded(&[0xcc; 20]), vec![0u8; 32]);
+        assert_eq!(usds_rely(&rely), Some(pb::UsdsRely { usr: vec![0xcc; 20] }));
+        assert_eq!(usds_deny(&rely), None);
+
+        let (dai_join, dai) = JOIN_TOKENS[0];
+        let exit = Log {
+            address: dai_join.to_vec(),
+            ..note(EXIT_NOTE, padded(&[0xcc; 20]), uint(7))
+        };
+        assert_eq!(
+            usds_exit(&exit),
+            Some(pb::UsdsExit {
+                caller: vec![0xbb; 20],
+                usr: vec![0xcc; 20],
+                wad: "7".to_string(),
+                token: dai.to_vec(),
+            })
+        );
+
+        // collateral adapters share the join / exit selectors
+        let gem_exit = Log {
+            address: vec![0x11; 20],
+            ..exit
+        };
+        assert_eq!(usds_exit(&gem_exit), None);
+    }
+
+    #[test]
+    fn decodes_fdusd_events_of_the_fdusd_contract_only() {
+        let paused = Log {
+            address: FDUSD.to_vec(),
+            ..log(vec![PAUSED_TOPIC.to_vec()], padded(&[0xaa; 20]))
+        };
+        assert_eq!(fdusd_paused(&paused), Some(pb::FdusdPaused { account: vec![0xaa; 20] }));
+        assert_eq!(fdusd_unpaused(&paused), None);
+
+        let owner = Log {
+            address: FDUSD.to_vec(),
+            ..log(vec![OWNERSHIP_TRANSFERRED_TOPIC.to_vec(), padded(&[0xbb; 20]), padded(&[0xcc; 20])], vec![])
+        };
+        assert_eq!(
+            fdusd_ownership_transferred(&owner),
+            Some(pb::FdusdOwnershipTransferred {
+                previous_owner: vec![0xbb; 20],
+                new_owner: vec![0xcc; 20],
+            })
+        );
+
+        let other = Log {
+            address: vec![0x11; 20],
+            ..paused
+        };
+        assert_eq!(fdusd_paused(&other), None);
+    }
+
+    #[test]
+    fn only_restricted_staker_roles_are_blacklists() {
+        let granted = |role: [u8; 32]| {
+            log(
+                vec![ROLE_GRANTED_TOPIC.to_vec(), role.to_vec(), padded(&[0xaa; 20]), padded(&[0xbb; 20])],
+                vec![],
+            )
+        };
+
+        assert_eq!(
+            usde_blacklist_added(&granted(FULL_RESTRICTED_STAKER_ROLE)),
+            Some(pb::UsdeBlacklistAdded {
+                account: vec![0xaa; 20],
+                full_restriction: true,
+            })
+        );
+        assert_eq!(
+            usde_blacklist_added(&granted(SOFT_RESTRICTED_STAKER_ROLE)).map(|e| e.full_restriction),
+            Some(false)
+        );
+        assert_eq!(usde_blacklist_added(&granted([0x11; 32])), None);
+        assert_eq!(usde_blacklist_removed(&granted(FULL_RESTRICTED_STAKER_ROLE)), None);
+    }
+}
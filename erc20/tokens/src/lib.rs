@@ -1,3 +1,5 @@
+mod issuers;
+
 use common::create::{CreateLog, CreateTransaction};
 use proto::pb::erc20::tokens::v1 as pb;
 use substreams_abis::tokens::erc20::sai::events as sai_events;
@@ -96,6 +98,31 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
     let mut total_steth_withdrawals_received = 0;
     let mut total_steth_lido_locator_set = 0;
 
+    // DAI / USDS counters
+    let mut total_usds_rely = 0;
+    let mut total_usds_deny = 0;
+    let mut total_usds_join = 0;
+    let mut total_usds_exit = 0;
+
+    // PYUSD counters
+    let mut total_pyusd_supply_increased = 0;
+    let mut total_pyusd_supply_decreased = 0;
+    let mut total_pyusd_supply_controller_set = 0;
+    let mut total_pyusd_address_frozen = 0;
+    let mut total_pyusd_address_unfrozen = 0;
+    let mut total_pyusd_frozen_address_wiped = 0;
+
+    // USDe counters
+    let mut total_usde_minter_updated = 0;
+    let mut total_usde_blacklist_added = 0;
+    let mut total_usde_blacklist_removed = 0;
+    let mut total_usde_locked_amount_redistributed = 0;
+
+    // FDUSD counters
+    let mut total_fdusd_ownership_transferred = 0;
+    let mut total_fdusd_paused = 0;
+    let mut total_fdusd_unpaused = 0;
+
     for trx in block.transactions() {
         let mut transaction = pb::Transaction::create_transaction(trx);
 
@@ -732,6 +759,141 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
                 });
                 transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
             }
+
+            // ============================================
+            // DAI / USDS Events (raw logs, see `issuers.rs`)
+            // ============================================
+
+            // Rely
+            if let Some(event) = issuers::usds_rely(log) {
+                total_usds_rely += 1;
+                let event = pb::log::Log::UsdsRely(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // Deny
+            if let Some(event) = issuers::usds_deny(log) {
+                total_usds_deny += 1;
+                let event = pb::log::Log::UsdsDeny(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // Join
+            if let Some(event) = issuers::usds_join(log) {
+                total_usds_join += 1;
+                let event = pb::log::Log::UsdsJoin(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // Exit
+            if let Some(event) = issuers::usds_exit(log) {
+                total_usds_exit += 1;
+                let event = pb::log::Log::UsdsExit(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // ============================================
+            // PYUSD Events (raw logs, see `issuers.rs`)
+            // ============================================
+
+            // SupplyIncreased
+            if let Some(event) = issuers::pyusd_supply_increased(log) {
+                total_pyusd_supply_increased += 1;
+                let event = pb::log::Log::PyusdSupplyIncreased(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // SupplyDecreased
+            if let Some(event) = issuers::pyusd_supply_decreased(log) {
+                total_pyusd_supply_decreased += 1;
+                let event = pb::log::Log::PyusdSupplyDecreased(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // SupplyControllerSet
+            if let Some(event) = issuers::pyusd_supply_controller_set(log) {
+                total_pyusd_supply_controller_set += 1;
+                let event = pb::log::Log::PyusdSupplyControllerSet(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // AddressFrozen
+            if let Some(event) = issuers::pyusd_address_frozen(log) {
+                total_pyusd_address_frozen += 1;
+                let event = pb::log::Log::PyusdAddressFrozen(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // AddressUnfrozen
+            if let Some(event) = issuers::pyusd_address_unfrozen(log) {
+                total_pyusd_address_unfrozen += 1;
+                let event = pb::log::Log::PyusdAddressUnfrozen(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // FrozenAddressWiped
+            if let Some(event) = issuers::pyusd_frozen_address_wiped(log) {
+                total_pyusd_frozen_address_wiped += 1;
+                let event = pb::log::Log::PyusdFrozenAddressWiped(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // ============================================
+            // USDe Events (raw logs, see `issuers.rs`)
+            // ============================================
+
+            // MinterUpdated
+            if let Some(event) = issuers::usde_minter_updated(log) {
+                total_usde_minter_updated += 1;
+                let event = pb::log::Log::UsdeMinterUpdated(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // BlacklistAdded (RoleGranted)
+            if let Some(event) = issuers::usde_blacklist_added(log) {
+                total_usde_blacklist_added += 1;
+                let event = pb::log::Log::UsdeBlacklistAdded(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // BlacklistRemoved (RoleRevoked)
+            if let Some(event) = issuers::usde_blacklist_removed(log) {
+                total_usde_blacklist_removed += 1;
+                let event = pb::log::Log::UsdeBlacklistRemoved(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // LockedAmountRedistributed
+            if let Some(event) = issuers::usde_locked_amount_redistributed(log) {
+                total_usde_locked_amount_redistributed += 1;
+                let event = pb::log::Log::UsdeLockedAmountRedistributed(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // ============================================
+            // FDUSD Events (raw logs, see `issuers.rs`)
+            // ============================================
+
+            // OwnershipTransferred
+            if let Some(event) = issuers::fdusd_ownership_transferred(log) {
+                total_fdusd_ownership_transferred += 1;
+                let event = pb::log::Log::FdusdOwnershipTransferred(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // Paused
+            if let Some(event) = issuers::fdusd_paused(log) {
+                total_fdusd_paused += 1;
+                let event = pb::log::Log::FdusdPaused(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
+
+            // Unpaused
+            if let Some(event) = issuers::fdusd_unpaused(log) {
+                total_fdusd_unpaused += 1;
+                let event = pb::log::Log::FdusdUnpaused(event);
+                transaction.logs.push(pb::Log::create_log_with_call(log, event, call));
+            }
         }
 
         // Only include transactions with logs
@@ -822,7 +984,32 @@ fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
     substreams::log::info!("  StakingLimitRemoved: {}", total_steth_staking_limit_removed);
     substreams::log::info!("  ELRewardsReceived: {}", total_steth_el_rewards_received);
     substreams::log::info!("  WithdrawalsReceived: {}", total_steth_withdrawals_received);
-    substreams::log::info!("  LidoLocatorSet: {}", total_steth_lido_locator_set);
+    substreams::log::info!("  LidoLocatorSet: {}\n", total_steth_lido_locator_set);
+
+    substreams::log::info!("--- DAI / USDS Events ---");
+    substreams::log::info!("  Rely: {}", total_usds_rely);
+    substreams::log::info!("  Deny: {}", total_usds_deny);
+    substreams::log::info!("  Join: {}", total_usds_join);
+    substreams::log::info!("  Exit: {}\n", total_usds_exit);
+
+    substreams::log::info!("--- PYUSD Events ---");
+    substreams::log::info!("  SupplyIncreased: {}", total_pyusd_supply_increased);
+    substreams::log::info!("  SupplyDecreased: {}", total_pyusd_supply_decreased);
+    substreams::log::info!("  SupplyControllerSet: {}", total_pyusd_supply_controller_set);
+    substreams::log::info!("  AddressFrozen: {}", total_pyusd_address_frozen);
+    substreams::log::info!("  AddressUnfrozen: {}", total_pyusd_address_unfrozen);
+    substreams::log::info!("  FrozenAddressWiped: {}\n", total_pyusd_frozen_address_wiped);
+
+    substreams::log::info!("--- USDe Events ---");
+    substreams::log::info!("  MinterUpdated: {}", total_usde_minter_updated);
+    substreams::log::info!("  BlacklistAdded: {}", total_usde_blacklist_added);
+    substreams::log::info!("  BlacklistRemoved: {}", total_usde_blacklist_removed);
+    substreams::log::info!("  LockedAmountRedistributed: {}\n", total_usde_locked_amount_redistributed);
+
+    substreams::log::info!("--- FDUSD Events ---");
+    substreams::log::info!("  OwnershipTransferred: {}", total_fdusd_ownership_transferred);
+    substreams::log::info!("  Paused: {}", total_fdusd_paused);
+    substreams::log::info!("  Unpaused: {}", total_fdusd_unpaused);
 
     Ok(events)
 }
@@ -1,9 +1,17 @@
 use common::create::{CreateLog, CreateTransaction};
+use common::index::block_keys;
 use proto::pb::erc4626::v1 as pb;
+use substreams::pb::sf::substreams::index::v1::Keys;
 use substreams_abis::standard::erc4626::events;
 use substreams_ethereum::pb::eth::v2::Block;
 use substreams_ethereum::Event;
 
+/// Block index of `evt_sig` / `evt_addr` keys, used as the `blockFilter` of `map_events`.
+#[substreams::handlers::map]
+fn index_events(block: Block) -> Result<Keys, substreams::errors::Error> {
+    Ok(block_keys(&block))
+}
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, substreams::errors::Error> {
     let mut events = pb::Events::default();
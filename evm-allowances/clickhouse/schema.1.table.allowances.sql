@@ -0,0 +1,34 @@
+-- ERC-20 & Permit2 allowances (open approvals) --
+-- There can only be a single allowance per (permit2, token, owner, spender), the latest one --
+CREATE TABLE IF NOT EXISTS allowances (
+    -- block --
+    block_num            UInt32,
+    block_hash           String,
+    timestamp            DateTime(0, 'UTC'),
+    minute               UInt32 MATERIALIZED toRelativeMinuteNum(timestamp),
+
+    -- allowance --
+    permit2              String COMMENT 'Permit2 contract address (empty for ERC-20 allowances)',
+    token                String COMMENT 'token contract address',
+    owner                String COMMENT 'token owner address',
+    spender              String COMMENT 'approved spender address',
+    amount               UInt256 COMMENT 'current allowance (approved - spent)',
+    unlimited            Bool COMMENT 'approved amount >= 2^255 (2^159 for Permit2)',
+    expiration           UInt64 COMMENT 'Permit2 expiration timestamp (0 for ERC-20 allowances)',
+
+    -- last change --
+    source               LowCardinality(String) COMMENT 'SOURCE_APPROVAL, SOURCE_PERMIT, SOURCE_TRANSFER_FROM, SOURCE_PERMIT2_APPROVAL...',
+    tx_hash              String COMMENT 'transaction of the last change',
+
+    -- indexes --
+    INDEX idx_spender (spender) TYPE bloom_filter GRANULARITY 4,
+    INDEX idx_token (token) TYPE bloom_filter GRANULARITY 4,
+    INDEX idx_amount (amount) TYPE minmax GRANULARITY 1,
+
+    -- projections --
+    PROJECTION prj_spender ( SELECT * ORDER BY spender, token, owner )
+)
+ENGINE = ReplacingMergeTree(block_num)
+ORDER BY (owner, token, spender, permit2)
+SETTINGS deduplicate_merge_projection_mode = 'rebuild'
+COMMENT 'Current ERC-20 & Permit2 allowances per (permit2, token, owner, spender)';
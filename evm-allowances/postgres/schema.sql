@@ -0,0 +1,64 @@
+-- Blocks table for PostgreSQL
+CREATE TABLE IF NOT EXISTS blocks (
+    block_num            INTEGER NOT NULL PRIMARY KEY,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_blocks_block_hash ON blocks (block_hash);
+CREATE INDEX IF NOT EXISTS idx_blocks_timestamp ON blocks (timestamp);
+
+-- Upsert rule for blocks
+CREATE OR REPLACE RULE blocks_upsert AS ON INSERT TO blocks
+WHERE EXISTS (SELECT 1 FROM blocks WHERE block_num = NEW.block_num)
+DO INSTEAD UPDATE blocks SET
+    block_hash = NEW.block_hash,
+    timestamp = NEW.timestamp
+WHERE block_num = NEW.block_num;
+
+-- ERC-20 & Permit2 allowances table for PostgreSQL
+-- There can only be a single allowance per (permit2, token, owner, spender), the latest one
+CREATE TABLE IF NOT EXISTS allowances (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+
+    -- allowance --
+    permit2              TEXT NOT NULL,
+    token                TEXT NOT NULL,
+    owner                TEXT NOT NULL,
+    spender              TEXT NOT NULL,
+    amount               NUMERIC NOT NULL,
+    unlimited            BOOLEAN NOT NULL,
+    expiration           BIGINT NOT NULL,
+
+    -- last change --
+    source               TEXT NOT NULL,
+    tx_hash              TEXT NOT NULL,
+
+    PRIMARY KEY (permit2, token, owner, spender)
+);
+
+-- Block indexes
+CREATE INDEX IF NOT EXISTS idx_allowances_block_num ON allowances (block_num);
+CREATE INDEX IF NOT EXISTS idx_allowances_timestamp ON allowances (timestamp);
+
+-- Open approvals (non-zero allowances only)
+CREATE INDEX IF NOT EXISTS idx_allowances_owner_open ON allowances (owner, token, spender) WHERE amount != 0;
+CREATE INDEX IF NOT EXISTS idx_allowances_spender_open ON allowances (spender, token) WHERE amount != 0;
+CREATE INDEX IF NOT EXISTS idx_allowances_unlimited ON allowances (owner) WHERE unlimited;
+
+-- Upsert rule for allowances
+CREATE OR REPLACE RULE allowances_upsert AS ON INSERT TO allowances
+WHERE EXISTS (SELECT 1 FROM allowances WHERE permit2 = NEW.permit2 AND token = NEW.token AND owner = NEW.owner AND spender = NEW.spender)
+DO INSTEAD UPDATE allowances SET
+    block_num = NEW.block_num,
+    block_hash = NEW.block_hash,
+    timestamp = NEW.timestamp,
+    amount = NEW.amount,
+    unlimited = NEW.unlimited,
+    expiration = NEW.expiration,
+    source = NEW.source,
+    tx_hash = NEW.tx_hash
+WHERE permit2 = NEW.permit2 AND token = NEW.token AND owner = NEW.owner AND spender = NEW.spender;
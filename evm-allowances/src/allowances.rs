@@ -0,0 +1,35 @@
+use common::{bytes_to_hex, bytes_to_string, Encoding};
+use proto::pb::erc20::allowances::v1 as pb;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+
+use crate::set_clock;
+
+pub fn process_events(encoding: &Encoding, tables: &mut Tables, clock: &Clock, events: &pb::Allowances) {
+    for allowance in events.allowances.iter() {
+        // ERC-20 allowances have no Permit2 contract (empty string, part of the primary key)
+        let permit2 = allowance.permit2.as_ref().map(|permit2| bytes_to_string(permit2, encoding)).unwrap_or_default();
+        let token = bytes_to_string(&allowance.token, encoding);
+        let owner = bytes_to_string(&allowance.owner, encoding);
+        let spender = bytes_to_string(&allowance.spender, encoding);
+        let key = [
+            ("permit2", permit2.to_string()),
+            ("token", token.to_string()),
+            ("owner", owner.to_string()),
+            ("spender", spender.to_string()),
+        ];
+        let row = tables
+            .upsert_row("allowances", key)
+            .set("permit2", &permit2)
+            .set("token", &token)
+            .set("owner", &owner)
+            .set("spender", &spender)
+            .set("amount", &allowance.amount)
+            .set("unlimited", allowance.unlimited)
+            .set("expiration", allowance.expiration.unwrap_or_default())
+            .set("source", allowance.source().as_str_name())
+            .set("tx_hash", bytes_to_hex(&allowance.tx_hash));
+
+        set_clock(clock, row);
+    }
+}
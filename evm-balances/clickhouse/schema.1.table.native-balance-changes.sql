@@ -0,0 +1,34 @@
+-- Native balance changes --
+-- Every balance change of EXTENDED blocks (gas buy/refund, rewards, transfers, withdrawals...) tied to its transaction & call --
+-- The `amount` of an address sums up to its native balance (changes rolled back by the chain are not included) --
+CREATE TABLE IF NOT EXISTS native_balance_changes (
+    -- block --
+    block_num            UInt32,
+    block_hash           String,
+    timestamp            DateTime(0, 'UTC'),
+    minute               UInt32 MATERIALIZED toRelativeMinuteNum(timestamp),
+
+    -- transaction & call (NULL for block-level changes: rewards, withdrawals, system calls) --
+    tx_index             Nullable(UInt32),
+    tx_hash              Nullable(String),
+    call_index           Nullable(UInt32),
+    ordinal              UInt64 COMMENT 'execution order of the change within the block',
+
+    -- balance change --
+    address              String COMMENT 'account address',
+    old_value            UInt256 COMMENT 'balance before the change',
+    new_value            UInt256 COMMENT 'balance after the change',
+    amount               Int256 MATERIALIZED toInt256(new_value) - toInt256(old_value) COMMENT 'signed balance delta',
+    reason               LowCardinality(String) COMMENT 'REASON_GAS_BUY, REASON_GAS_REFUND, REASON_TRANSFER, REASON_WITHDRAWAL...',
+
+    -- indexes --
+    INDEX idx_tx_hash (tx_hash) TYPE bloom_filter GRANULARITY 4,
+    INDEX idx_reason (reason) TYPE set(32) GRANULARITY 4,
+
+    -- projections --
+    PROJECTION prj_block_ordinal ( SELECT * ORDER BY block_num, ordinal )
+)
+ENGINE = ReplacingMergeTree
+ORDER BY (address, block_num, ordinal)
+SETTINGS deduplicate_merge_projection_mode = 'rebuild'
+COMMENT 'Native balance changes with reason, tied to their transaction and call';
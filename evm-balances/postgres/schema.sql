@@ -96,3 +96,38 @@ DO INSTEAD UPDATE native_balances SET
     timestamp = NEW.timestamp,
     balance = NEW.balance
 WHERE address = NEW.address;
+
+
+-- Native balance changes table for PostgreSQL
+-- Every balance change of EXTENDED blocks tied to its transaction & call (ledger, append-only)
+-- tx_index / tx_hash / call_index are NULL for block-level changes (rewards, withdrawals, system calls)
+CREATE TABLE IF NOT EXISTS native_balance_changes (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+
+    -- transaction & call --
+    tx_index             INTEGER,
+    tx_hash              TEXT,
+    call_index           INTEGER,
+    ordinal              BIGINT NOT NULL,
+
+    -- balance change --
+    address              TEXT NOT NULL,
+    old_value            NUMERIC NOT NULL,
+    new_value            NUMERIC NOT NULL,
+    reason               TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, ordinal)
+);
+
+-- Block indexes
+CREATE INDEX IF NOT EXISTS idx_native_balance_changes_timestamp ON native_balance_changes (timestamp);
+
+-- Single column indexes
+CREATE INDEX IF NOT EXISTS idx_native_balance_changes_tx_hash ON native_balance_changes (tx_hash);
+CREATE INDEX IF NOT EXISTS idx_native_balance_changes_reason ON native_balance_changes (reason);
+
+-- Account ledger
+CREATE INDEX IF NOT EXISTS idx_native_balance_changes_address ON native_balance_changes (address, block_num, ordinal);
@@ -1,4 +1,5 @@
 mod erc20_balances;
+mod native_balance_changes;
 mod native_balances;
 
 use prost_types::Timestamp;
@@ -12,6 +13,7 @@ pub fn db_out(
     mut clock: Clock,
     // Native
     native_balance_events: pb::Events,
+    native_balance_changes: pb::NativeBalanceChanges,
     // ERC-20
     erc20_balance_events: pb::Events,
 ) -> Result<DatabaseChanges, Error> {
@@ -27,6 +29,9 @@ pub fn db_out(
     // -- Native Balances --
     native_balances::process_events(&encoding, &mut tables, &clock, &native_balance_events);
 
+    // -- Native Balance Changes (EXTENDED blocks only) --
+    native_balance_changes::process_events(&encoding, &mut tables, &clock, &native_balance_changes);
+
     // ONLY include blocks if events are present
     if !tables.tables.is_empty() {
         set_clock(&clock, tables.create_row("blocks", [("block_num", clock.number.to_string())]));
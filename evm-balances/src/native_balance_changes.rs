@@ -0,0 +1,33 @@
+use common::{bytes_to_hex, bytes_to_string, Encoding};
+use proto::pb::evm::balances::v1 as pb;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+
+use crate::set_clock;
+
+pub fn process_events(encoding: &Encoding, tables: &mut Tables, clock: &Clock, events: &pb::NativeBalanceChanges) {
+    for change in events.balance_changes.iter() {
+        // ordinals are unique within a block
+        let key = [("block_num", clock.number.to_string()), ("ordinal", change.ordinal.to_string())];
+        let row = tables
+            .create_row("native_balance_changes", key)
+            .set("ordinal", change.ordinal)
+            .set("address", bytes_to_string(&change.address, encoding))
+            .set("old_value", &change.old_value)
+            .set("new_value", &change.new_value)
+            .set("reason", change.reason().as_str_name());
+
+        // block-level changes (rewards, withdrawals) and system calls have no transaction
+        if let Some(tx_hash) = &change.tx_hash {
+            row.set("tx_hash", bytes_to_hex(tx_hash));
+        }
+        if let Some(tx_index) = change.tx_index {
+            row.set("tx_index", tx_index);
+        }
+        if let Some(call_index) = change.call_index {
+            row.set("call_index", call_index);
+        }
+
+        set_clock(clock, row);
+    }
+}
@@ -0,0 +1,34 @@
+-- Cyclic arbitrages written by `evm-dex-mev`, keyed by the first hop
+CREATE TABLE IF NOT EXISTS arbitrages (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  String,
+    timestamp                   DateTime(0, 'UTC'),
+    minute                      UInt32 COMMENT 'toRelativeMinuteNum(timestamp)',
+
+    -- transaction --
+    tx_index                    UInt32,
+    tx_hash                     String,
+    tx_from                     String,
+    log_ordinal                 UInt64 COMMENT 'first hop swap ordinal',
+
+    -- arbitrage --
+    token                       LowCardinality(String) COMMENT 'start and end token of the route',
+    route_raw                   String COMMENT 'comma-separated token path',
+    pools_raw                   String COMMENT 'comma-separated pool of each hop',
+    profit_tokens_raw           String COMMENT 'comma-separated tokens',
+    profit_amounts_raw          String COMMENT 'comma-separated signed profits, aligned with profit_tokens_raw',
+
+    route                       Array(String) MATERIALIZED arrayFilter(x -> x != '', splitByChar(',', route_raw)),
+    pools                       Array(String) MATERIALIZED arrayFilter(x -> x != '', splitByChar(',', pools_raw)),
+    hops                        UInt16 MATERIALIZED length(pools),
+    profit_tokens               Array(String) MATERIALIZED arrayFilter(x -> x != '', splitByChar(',', profit_tokens_raw)),
+    profit_amounts              Array(Int256) MATERIALIZED arrayMap(x -> toInt256(x), arrayFilter(x -> x != '', splitByChar(',', profit_amounts_raw))),
+
+    -- PROJECTIONS --
+    PROJECTION prj_tx_from_count ( SELECT tx_from, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY tx_from ),
+    PROJECTION prj_token_count ( SELECT token, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY token )
+)
+ENGINE = ReplacingMergeTree
+ORDER BY ( timestamp, block_num, tx_index, log_ordinal )
+COMMENT 'DEX cyclic arbitrages (single-transaction routes starting and ending in the same token)';
@@ -0,0 +1,51 @@
+-- Sandwich attacks written by `evm-dex-mev`, keyed by the front-run swap
+CREATE TABLE IF NOT EXISTS sandwiches (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  String,
+    timestamp                   DateTime(0, 'UTC'),
+    minute                      UInt32 COMMENT 'toRelativeMinuteNum(timestamp)',
+
+    -- front-run position --
+    tx_index                    UInt32 COMMENT 'front-run transaction index',
+    log_ordinal                 UInt64 COMMENT 'front-run swap ordinal',
+
+    -- sandwich --
+    factory                     LowCardinality(String),
+    pool                        String,
+    attacker                    String COMMENT 'transaction sender of the front-run and back-run',
+
+    front_run_tx_index          UInt32,
+    front_run_tx_hash           String,
+    front_run_log_ordinal       UInt64,
+    front_run_input_contract    String,
+    front_run_input_amount      UInt256,
+    front_run_output_contract   String,
+    front_run_output_amount     UInt256,
+
+    back_run_tx_index           UInt32,
+    back_run_tx_hash            String,
+    back_run_log_ordinal        UInt64,
+    back_run_input_contract     String,
+    back_run_input_amount       UInt256,
+    back_run_output_contract    String,
+    back_run_output_amount      UInt256,
+
+    victims_tx_hash_raw         String COMMENT 'comma-separated victim transaction hashes',
+    victims_user_raw            String COMMENT 'comma-separated victim swap users, aligned with victims_tx_hash_raw',
+    profit_tokens_raw           String COMMENT 'comma-separated tokens (front-run input, then output)',
+    profit_amounts_raw          String COMMENT 'comma-separated signed profits, aligned with profit_tokens_raw',
+
+    victims_tx_hash             Array(String) MATERIALIZED arrayFilter(x -> x != '', splitByChar(',', victims_tx_hash_raw)),
+    victims_user                Array(String) MATERIALIZED arrayFilter(x -> x != '', splitByChar(',', victims_user_raw)),
+    victims                     UInt16 MATERIALIZED length(victims_tx_hash),
+    profit_tokens               Array(String) MATERIALIZED arrayFilter(x -> x != '', splitByChar(',', profit_tokens_raw)),
+    profit_amounts              Array(Int256) MATERIALIZED arrayMap(x -> toInt256(x), arrayFilter(x -> x != '', splitByChar(',', profit_amounts_raw))),
+
+    -- PROJECTIONS --
+    PROJECTION prj_pool_count ( SELECT pool, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY pool ),
+    PROJECTION prj_attacker_count ( SELECT attacker, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY attacker )
+)
+ENGINE = ReplacingMergeTree
+ORDER BY ( timestamp, block_num, tx_index, log_ordinal )
+COMMENT 'DEX sandwich attacks (front-run, victims, back-run on a single pool)';
@@ -0,0 +1,100 @@
+use substreams::errors::Error;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::pb::database::DatabaseChanges;
+use substreams_database_change::tables::{Row, Tables};
+
+use common::clickhouse::{bytes_to_hex, set_clock};
+use common::{bytes_to_string, Encoding};
+use proto::pb::dex::mev::v1 as pb;
+
+#[substreams::handlers::map]
+pub fn db_out(params: String, clock: Clock, events: pb::Events) -> Result<DatabaseChanges, Error> {
+    let mut tables = Tables::new();
+
+    let encoding = common::handle_encoding_param(&params);
+    process_events(&encoding, &mut tables, &clock, &events);
+
+    if !tables.tables.is_empty() {
+        set_clock(&clock, tables.create_row("blocks", [("block_num", clock.number.to_string())]));
+    }
+
+    substreams::log::info!("Total rows {}", tables.all_row_count());
+    Ok(tables.to_database_changes())
+}
+
+pub fn process_events(encoding: &Encoding, tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    for sandwich in events.sandwiches.iter() {
+        let (Some(front_run), Some(back_run)) = (&sandwich.front_run, &sandwich.back_run) else {
+            continue;
+        };
+        let row = tables.create_row("sandwiches", key(clock, front_run.tx_index, front_run.log_ordinal));
+
+        set_clock(clock, row);
+        row.set("factory", bytes_to_string(&sandwich.factory, encoding))
+            .set("pool", bytes_to_string(&sandwich.pool, encoding))
+            .set("attacker", bytes_to_string(&sandwich.attacker, encoding))
+            .set(
+                "victims_tx_hash_raw",
+                sandwich
+                    .victims
+                    .iter()
+                    .map(|victim| bytes_to_hex(&victim.transaction_hash))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
+            .set("victims_user_raw", join(sandwich.victims.iter().map(|victim| &victim.user), encoding));
+        set_leg(encoding, "front_run", front_run, row);
+        set_leg(encoding, "back_run", back_run, row);
+        set_profits(encoding, &sandwich.profits, row);
+    }
+
+    for arbitrage in events.arbitrages.iter() {
+        let row = tables.create_row("arbitrages", key(clock, arbitrage.tx_index, arbitrage.log_ordinal));
+
+        set_clock(clock, row);
+        row.set("tx_index", arbitrage.tx_index)
+            .set("tx_hash", bytes_to_hex(&arbitrage.transaction_hash))
+            .set("tx_from", bytes_to_string(&arbitrage.tx_from, encoding))
+            .set("log_ordinal", arbitrage.log_ordinal)
+            .set("token", bytes_to_string(&arbitrage.token, encoding))
+            .set("route_raw", join(&arbitrage.route, encoding))
+            .set("pools_raw", join(&arbitrage.pools, encoding));
+        set_profits(encoding, &arbitrage.profits, row);
+    }
+}
+
+fn key(clock: &Clock, tx_index: u32, log_ordinal: u64) -> [(&'static str, String); 4] {
+    let seconds = clock.timestamp.as_ref().expect("clock.timestamp is required").seconds;
+    [
+        ("timestamp", seconds.to_string()),
+        ("block_num", clock.number.to_string()),
+        ("tx_index", tx_index.to_string()),
+        ("log_ordinal", log_ordinal.to_string()),
+    ]
+}
+
+fn set_leg(encoding: &Encoding, prefix: &str, leg: &pb::Leg, row: &mut Row) {
+    row.set(&format!("{}_tx_index", prefix), leg.tx_index)
+        .set(&format!("{}_tx_hash", prefix), bytes_to_hex(&leg.transaction_hash))
+        .set(&format!("{}_log_ordinal", prefix), leg.log_ordinal)
+        .set(&format!("{}_input_contract", prefix), bytes_to_string(&leg.input_token, encoding))
+        .set(&format!("{}_input_amount", prefix), &leg.input_amount)
+        .set(&format!("{}_output_contract", prefix), bytes_to_string(&leg.output_token, encoding))
+        .set(&format!("{}_output_amount", prefix), &leg.output_amount);
+}
+
+fn set_profits(encoding: &Encoding, profits: &[pb::Profit], row: &mut Row) {
+    row.set("profit_tokens_raw", join(profits.iter().map(|profit| &profit.token), encoding)).set(
+        "profit_amounts_raw",
+        profits.iter().map(|profit| profit.amount.as_str()).collect::<Vec<_>>().join(","),
+    );
+}
+
+// Comma-separated addresses, split back into arrays by the ClickHouse schema
+fn join<'a>(addresses: impl IntoIterator<Item = &'a Vec<u8>>, encoding: &Encoding) -> String {
+    addresses
+        .into_iter()
+        .map(|address| bytes_to_string(address, encoding))
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,25 @@
+-- pools missing from store_pools, by number of unresolved swaps (backfill candidates) --
+SELECT
+    protocol,
+    pool,
+    count() AS swaps,
+    min(block_num) AS first_block,
+    max(block_num) AS last_block
+FROM unresolved_swaps
+GROUP BY protocol, pool
+ORDER BY swaps DESC
+LIMIT 20;
+
+-- coverage per protocol: resolved vs unresolved swaps --
+SELECT
+    protocol,
+    countIf(resolved) AS resolved_swaps,
+    countIf(NOT resolved) AS unresolved_swaps,
+    round(unresolved_swaps / count(), 4) AS unresolved_ratio
+FROM (
+    SELECT protocol, true AS resolved FROM swaps
+    UNION ALL
+    SELECT protocol, false AS resolved FROM unresolved_swaps
+)
+GROUP BY protocol
+ORDER BY unresolved_swaps DESC;
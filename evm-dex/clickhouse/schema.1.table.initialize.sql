@@ -19,5 +19,6 @@ ALTER TABLE initialize
         'traderjoe' = 13,
         'kyber_elastic' = 14
     ) COMMENT 'protocol identifier',
+    ADD COLUMN IF NOT EXISTS project                     LowCardinality(String) COMMENT 'project and version from the factory registry (e.g. sushiswap_v2), empty when unknown',
     ADD COLUMN IF NOT EXISTS factory                     LowCardinality(String),
     ADD COLUMN IF NOT EXISTS pool                        String;
@@ -0,0 +1,66 @@
+-- Normalized liquidity deposits and withdrawals written directly by `evm-dex`
+CREATE TABLE IF NOT EXISTS liquidity_added AS TEMPLATE_LOG
+COMMENT 'DEX liquidity deposits normalized across supported protocols';
+
+ALTER TABLE liquidity_added
+    ADD COLUMN IF NOT EXISTS protocol                    Enum8(
+        'sunpump' = 1,
+        'uniswap_v1' = 2,
+        'uniswap_v2' = 3,
+        'uniswap_v3' = 4,
+        'uniswap_v4' = 5,
+        'curvefi' = 6,
+        'balancer' = 7,
+        'bancor' = 8,
+        'cow' = 9,
+        'aerodrome' = 10,
+        'dodo' = 11,
+        'woofi' = 12,
+        'traderjoe' = 13,
+        'kyber_elastic' = 14
+    ) COMMENT 'protocol identifier',
+    ADD COLUMN IF NOT EXISTS factory                     LowCardinality(String),
+    ADD COLUMN IF NOT EXISTS pool                        String,
+    ADD COLUMN IF NOT EXISTS provider                    String,
+    ADD COLUMN IF NOT EXISTS tokens_raw                  String COMMENT 'comma-separated token addresses, in pool order',
+    ADD COLUMN IF NOT EXISTS amounts_raw                 String COMMENT 'comma-separated token amounts, aligned with tokens_raw',
+    ADD COLUMN IF NOT EXISTS liquidity                   UInt256 COMMENT 'liquidity units, only for concentrated liquidity pools',
+    ADD COLUMN IF NOT EXISTS tokens                      Array(String) MATERIALIZED arrayFilter(x -> x != '', splitByChar(',', tokens_raw)),
+    ADD COLUMN IF NOT EXISTS amounts                     Array(UInt256) MATERIALIZED arrayMap(x -> toUInt256(x), arrayFilter(x -> x != '', splitByChar(',', amounts_raw)));
+
+ALTER TABLE liquidity_added
+    ADD PROJECTION IF NOT EXISTS prj_pool_count ( SELECT pool, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY pool ),
+    ADD PROJECTION IF NOT EXISTS prj_provider_count ( SELECT provider, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY provider );
+
+CREATE TABLE IF NOT EXISTS liquidity_removed AS TEMPLATE_LOG
+COMMENT 'DEX liquidity withdrawals normalized across supported protocols';
+
+ALTER TABLE liquidity_removed
+    ADD COLUMN IF NOT EXISTS protocol                    Enum8(
+        'sunpump' = 1,
+        'uniswap_v1' = 2,
+        'uniswap_v2' = 3,
+        'uniswap_v3' = 4,
+        'uniswap_v4' = 5,
+        'curvefi' = 6,
+        'balancer' = 7,
+        'bancor' = 8,
+        'cow' = 9,
+        'aerodrome' = 10,
+        'dodo' = 11,
+        'woofi' = 12,
+        'traderjoe' = 13,
+        'kyber_elastic' = 14
+    ) COMMENT 'protocol identifier',
+    ADD COLUMN IF NOT EXISTS factory                     LowCardinality(String),
+    ADD COLUMN IF NOT EXISTS pool                        String,
+    ADD COLUMN IF NOT EXISTS provider                    String,
+    ADD COLUMN IF NOT EXISTS tokens_raw                  String COMMENT 'comma-separated token addresses, in pool order',
+    ADD COLUMN IF NOT EXISTS amounts_raw                 String COMMENT 'comma-separated token amounts, aligned with tokens_raw',
+    ADD COLUMN IF NOT EXISTS liquidity                   UInt256 COMMENT 'liquidity units, only for concentrated liquidity pools',
+    ADD COLUMN IF NOT EXISTS tokens                      Array(String) MATERIALIZED arrayFilter(x -> x != '', splitByChar(',', tokens_raw)),
+    ADD COLUMN IF NOT EXISTS amounts                     Array(UInt256) MATERIALIZED arrayMap(x -> toUInt256(x), arrayFilter(x -> x != '', splitByChar(',', amounts_raw)));
+
+ALTER TABLE liquidity_removed
+    ADD PROJECTION IF NOT EXISTS prj_pool_count ( SELECT pool, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY pool ),
+    ADD PROJECTION IF NOT EXISTS prj_provider_count ( SELECT provider, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY provider );
@@ -19,6 +19,8 @@ ALTER TABLE swap_fee
         'traderjoe' = 13,
         'kyber_elastic' = 14
     ) COMMENT 'protocol identifier',
+    ADD COLUMN IF NOT EXISTS project                     LowCardinality(String) COMMENT 'project and version from the factory registry (e.g. sushiswap_v2), empty when unknown',
     ADD COLUMN IF NOT EXISTS factory                     LowCardinality(String),
     ADD COLUMN IF NOT EXISTS pool                        String,
-    ADD COLUMN IF NOT EXISTS fee                         UInt32;
+    ADD COLUMN IF NOT EXISTS fee                         UInt32 COMMENT 'hundredths of a bip for every protocol (3000 = 0.30%)',
+    ADD COLUMN IF NOT EXISTS effective_ordinal           UInt64 COMMENT 'block ordinal from which the fee applies';
@@ -19,6 +19,7 @@ ALTER TABLE swaps
         'traderjoe' = 13,
         'kyber_elastic' = 14
     ) COMMENT 'protocol identifier',
+    ADD COLUMN IF NOT EXISTS project                     LowCardinality(String) COMMENT 'project and version from the factory registry (e.g. sushiswap_v2), empty when unknown',
     ADD COLUMN IF NOT EXISTS factory                     LowCardinality(String),
     ADD COLUMN IF NOT EXISTS pool                        String,
     ADD COLUMN IF NOT EXISTS user                        String,
@@ -26,6 +27,8 @@ ALTER TABLE swaps
     ADD COLUMN IF NOT EXISTS input_amount                UInt256,
     ADD COLUMN IF NOT EXISTS output_contract             String,
     ADD COLUMN IF NOT EXISTS output_amount               UInt256,
+    ADD COLUMN IF NOT EXISTS hooks                       LowCardinality(String) COMMENT 'Uniswap V4 hooks contract of the pool, empty for other protocols and pools without hooks',
+    ADD COLUMN IF NOT EXISTS verified                    Bool DEFAULT true COMMENT 'false for pools of factories outside the allowlist (kept with unlisted=tag), true otherwise',
     ADD COLUMN IF NOT EXISTS token0                      LowCardinality(String) MATERIALIZED if(input_contract <= output_contract, input_contract, output_contract),
     ADD COLUMN IF NOT EXISTS token1                      LowCardinality(String) MATERIALIZED if(input_contract <= output_contract, output_contract, input_contract),
     ADD COLUMN IF NOT EXISTS amount0                     UInt256 MATERIALIZED if(input_contract <= output_contract, input_amount, output_amount),
@@ -40,7 +43,9 @@ ALTER TABLE swaps
 ALTER TABLE swaps
     -- count() --
     ADD PROJECTION IF NOT EXISTS prj_protocol_count ( SELECT protocol, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY protocol ),
+    ADD PROJECTION IF NOT EXISTS prj_project_count ( SELECT project, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY project ),
     ADD PROJECTION IF NOT EXISTS prj_factory_count ( SELECT factory, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY factory ),
+    ADD PROJECTION IF NOT EXISTS prj_hooks_count ( SELECT hooks, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY hooks ),
     ADD PROJECTION IF NOT EXISTS prj_pool_count ( SELECT pool, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY pool ),
     ADD PROJECTION IF NOT EXISTS prj_user_count ( SELECT user, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY user ),
     ADD PROJECTION IF NOT EXISTS prj_input_contract_count ( SELECT input_contract, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY input_contract ),
@@ -48,7 +53,9 @@ ALTER TABLE swaps
 
     -- minute --
     ADD PROJECTION IF NOT EXISTS prj_protocol_by_minute ( SELECT protocol, minute, count() GROUP BY protocol, minute ),
+    ADD PROJECTION IF NOT EXISTS prj_project_by_minute ( SELECT project, minute, count() GROUP BY project, minute ),
     ADD PROJECTION IF NOT EXISTS prj_factory_by_minute ( SELECT factory, minute, count() GROUP BY factory, minute ),
+    ADD PROJECTION IF NOT EXISTS prj_hooks_by_minute ( SELECT hooks, minute, count() GROUP BY hooks, minute ),
     ADD PROJECTION IF NOT EXISTS prj_pool_by_minute ( SELECT pool, minute, count() GROUP BY pool, minute ),
     ADD PROJECTION IF NOT EXISTS prj_user_by_minute ( SELECT user, minute, count() GROUP BY user, minute ),
     ADD PROJECTION IF NOT EXISTS prj_input_contract_by_minute ( SELECT input_contract, minute, count() GROUP BY input_contract, minute ),
@@ -0,0 +1,33 @@
+-- Swaps of pools missing from `store_pools` (created before the start block or by unindexed factories)
+CREATE TABLE IF NOT EXISTS unresolved_swaps AS TEMPLATE_LOG
+COMMENT 'DEX swap events whose pool tokens are unknown, kept to measure and backfill coverage gaps';
+
+ALTER TABLE unresolved_swaps
+    ADD COLUMN IF NOT EXISTS protocol                    Enum8(
+        'sunpump' = 1,
+        'uniswap_v1' = 2,
+        'uniswap_v2' = 3,
+        'uniswap_v3' = 4,
+        'uniswap_v4' = 5,
+        'curvefi' = 6,
+        'balancer' = 7,
+        'bancor' = 8,
+        'cow' = 9,
+        'aerodrome' = 10,
+        'dodo' = 11,
+        'woofi' = 12,
+        'traderjoe' = 13,
+        'kyber_elastic' = 14
+    ) COMMENT 'protocol of the decoder that matched the event',
+    ADD COLUMN IF NOT EXISTS factory                     LowCardinality(String) COMMENT 'empty when the pool is not in store_pools',
+    ADD COLUMN IF NOT EXISTS pool                        String,
+    ADD COLUMN IF NOT EXISTS user                        String,
+    ADD COLUMN IF NOT EXISTS amount0                     Int256 COMMENT 'signed token0 delta of the pool, positive when paid in',
+    ADD COLUMN IF NOT EXISTS amount1                     Int256 COMMENT 'signed token1 delta of the pool, positive when paid in',
+    ADD COLUMN IF NOT EXISTS token0                      LowCardinality(String) COMMENT 'input token when the event names it (Balancer, Bancor, SunPump, Uniswap V1 ETH), empty otherwise',
+    ADD COLUMN IF NOT EXISTS token1                      LowCardinality(String) COMMENT 'output token when the event names it, empty otherwise';
+
+ALTER TABLE unresolved_swaps
+    -- count() --
+    ADD PROJECTION IF NOT EXISTS prj_protocol_count ( SELECT protocol, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY protocol ),
+    ADD PROJECTION IF NOT EXISTS prj_pool_count ( SELECT pool, count(), min(block_num), max(block_num), min(timestamp), max(timestamp), min(minute), max(minute) GROUP BY pool );
@@ -30,7 +30,9 @@ CREATE TABLE IF NOT EXISTS state_pools_fees (
     ),
 
     -- state --
-    fee                         UInt32,
+    fee                         UInt32 COMMENT 'hundredths of a bip for every protocol (3000 = 0.30%)',
+    effective_ordinal           UInt64 COMMENT 'block ordinal from which the fee applies',
+    version                     UInt64 COMMENT 'block_num and effective_ordinal, so the latest fee change within a block wins',
 
     -- indexes --
     INDEX idx_block_num         (block_num)         TYPE minmax           GRANULARITY 1,
@@ -39,7 +41,7 @@ CREATE TABLE IF NOT EXISTS state_pools_fees (
     INDEX idx_factory           (factory)           TYPE set(1024)        GRANULARITY 1,
     INDEX idx_protocol          (protocol)          TYPE set(8)           GRANULARITY 1
 )
-ENGINE = ReplacingMergeTree(block_num)
+ENGINE = ReplacingMergeTree(version)
 ORDER BY (pool, factory, protocol);
 
 CREATE MATERIALIZED VIEW IF NOT EXISTS mv_state_pools_fees
@@ -53,5 +55,7 @@ SELECT
     factory,
     pool,
     protocol,
-    fee
+    fee,
+    effective_ordinal,
+    bitShiftLeft(toUInt64(block_num), 32) + effective_ordinal AS version
 FROM swap_fee;
@@ -129,6 +129,7 @@ SELECT
     uniqState(user)         AS uniq_user,
     uniqState(call_caller)  AS uniq_caller
 FROM swaps s
+WHERE verified
 GROUP BY
     -- bar interval
     interval_min,
@@ -88,4 +88,5 @@ SELECT
     uniqState(user) AS uniq_user,
     uniqState(call_caller) AS uniq_caller
 FROM swaps
+WHERE verified
 GROUP BY protocol, factory, pool;
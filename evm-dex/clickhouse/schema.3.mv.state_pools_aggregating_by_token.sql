@@ -82,6 +82,7 @@ SELECT
     -- universal --
     count() as transactions
 FROM swaps
+WHERE verified
 GROUP BY token, protocol, factory, pool;
 
 CREATE MATERIALIZED VIEW IF NOT EXISTS mv_state_pools_aggregating_by_token_output_contract
@@ -101,4 +102,5 @@ SELECT
     -- universal --
     count() as transactions
 FROM swaps
+WHERE verified
 GROUP BY token, protocol, factory, pool;
@@ -72,18 +72,18 @@ SELECT
 FROM (
     SELECT protocol, factory, pool, user AS address, timestamp, block_num
     FROM swaps
-    WHERE user != ''
+    WHERE user != '' AND verified
 
     UNION ALL
 
     SELECT protocol, factory, pool, tx_from AS address, timestamp, block_num
     FROM swaps
-    WHERE tx_from != ''
+    WHERE tx_from != '' AND verified
 
     UNION ALL
 
     SELECT protocol, factory, pool, call_caller AS address, timestamp, block_num
     FROM swaps
-    WHERE call_caller != ''
+    WHERE call_caller != '' AND verified
 )
 GROUP BY protocol, factory, pool, address;
@@ -37,6 +37,15 @@ pub fn process_events(encoding: &Encoding, tables: &mut Tables, clock: &Clock, e
                 Some(pb::log::Log::Initialize(initialize)) => {
                     process_initialize(encoding, tables, clock, tx, log, tx_index, log_index, initialize)
                 }
+                Some(pb::log::Log::LiquidityAdded(liquidity_added)) => {
+                    process_liquidity_added(encoding, tables, clock, tx, log, tx_index, log_index, liquidity_added)
+                }
+                Some(pb::log::Log::LiquidityRemoved(liquidity_removed)) => {
+                    process_liquidity_removed(encoding, tables, clock, tx, log, tx_index, log_index, liquidity_removed)
+                }
+                Some(pb::log::Log::UnresolvedSwap(unresolved_swap)) => {
+                    process_unresolved_swap(encoding, tables, clock, tx, log, tx_index, log_index, unresolved_swap)
+                }
                 None => {}
             }
         }
@@ -62,13 +71,45 @@ fn process_swap(
     set_template_call(encoding, log, row);
 
     row.set("protocol", protocol_name(swap.protocol))
+        .set("project", &swap.project)
         .set("factory", bytes_to_string(&swap.factory, encoding))
         .set("pool", bytes_to_string(&swap.pool, encoding))
         .set("user", bytes_to_string(&swap.user, encoding))
         .set("input_contract", bytes_to_string(&swap.input_token, encoding))
         .set("input_amount", &swap.input_amount)
         .set("output_contract", bytes_to_string(&swap.output_token, encoding))
-        .set("output_amount", &swap.output_amount);
+        .set("output_amount", &swap.output_amount)
+        .set("hooks", bytes_to_string(&swap.hooks, encoding))
+        // unset (no factory allowlist) counts as verified
+        .set("verified", swap.verified.unwrap_or(true));
+}
+
+fn process_unresolved_swap(
+    encoding: &Encoding,
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    log: &pb::Log,
+    tx_index: usize,
+    log_index: usize,
+    unresolved_swap: &pb::UnresolvedSwap,
+) {
+    let key = log_key(clock, tx_index, log_index);
+    let row = tables.create_row("unresolved_swaps", key);
+
+    set_clock(clock, row);
+    set_template_tx(encoding, tx, tx_index, row);
+    set_template_log(encoding, log, log_index, row);
+    set_template_call(encoding, log, row);
+
+    row.set("protocol", protocol_name(unresolved_swap.protocol))
+        .set("factory", bytes_to_string(&unresolved_swap.factory, encoding))
+        .set("pool", bytes_to_string(&unresolved_swap.pool, encoding))
+        .set("user", bytes_to_string(&unresolved_swap.user, encoding))
+        .set("amount0", &unresolved_swap.amount0)
+        .set("amount1", &unresolved_swap.amount1)
+        .set("token0", bytes_to_string(&unresolved_swap.token0, encoding))
+        .set("token1", bytes_to_string(&unresolved_swap.token1, encoding));
 }
 
 fn process_swap_fee(
@@ -90,9 +131,11 @@ fn process_swap_fee(
     set_template_call(encoding, log, row);
 
     row.set("protocol", protocol_name(swap_fee.protocol))
+        .set("project", &swap_fee.project)
         .set("factory", bytes_to_string(&swap_fee.factory, encoding))
         .set("pool", bytes_to_string(&swap_fee.pool, encoding))
-        .set("fee", swap_fee.fee);
+        .set("fee", swap_fee.fee)
+        .set("effective_ordinal", swap_fee.effective_ordinal);
 }
 
 fn process_initialize(
@@ -114,10 +157,78 @@ fn process_initialize(
     set_template_call(encoding, log, row);
 
     row.set("protocol", protocol_name(initialize.protocol))
+        .set("project", &initialize.project)
         .set("factory", bytes_to_string(&initialize.factory, encoding))
         .set("pool", bytes_to_string(&initialize.pool, encoding));
 }
 
+fn process_liquidity_added(
+    encoding: &Encoding,
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    log: &pb::Log,
+    tx_index: usize,
+    log_index: usize,
+    liquidity_added: &pb::LiquidityAdded,
+) {
+    let key = log_key(clock, tx_index, log_index);
+    let row = tables.create_row("liquidity_added", key);
+
+    set_clock(clock, row);
+    set_template_tx(encoding, tx, tx_index, row);
+    set_template_log(encoding, log, log_index, row);
+    set_template_call(encoding, log, row);
+
+    row.set("protocol", protocol_name(liquidity_added.protocol))
+        .set("factory", bytes_to_string(&liquidity_added.factory, encoding))
+        .set("pool", bytes_to_string(&liquidity_added.pool, encoding))
+        .set("provider", bytes_to_string(&liquidity_added.provider, encoding))
+        .set("tokens_raw", tokens_raw(&liquidity_added.tokens, encoding))
+        .set("amounts_raw", liquidity_added.amounts.join(","))
+        .set("liquidity", liquidity_or_zero(&liquidity_added.liquidity));
+}
+
+fn process_liquidity_removed(
+    encoding: &Encoding,
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    log: &pb::Log,
+    tx_index: usize,
+    log_index: usize,
+    liquidity_removed: &pb::LiquidityRemoved,
+) {
+    let key = log_key(clock, tx_index, log_index);
+    let row = tables.create_row("liquidity_removed", key);
+
+    set_clock(clock, row);
+    set_template_tx(encoding, tx, tx_index, row);
+    set_template_log(encoding, log, log_index, row);
+    set_template_call(encoding, log, row);
+
+    row.set("protocol", protocol_name(liquidity_removed.protocol))
+        .set("factory", bytes_to_string(&liquidity_removed.factory, encoding))
+        .set("pool", bytes_to_string(&liquidity_removed.pool, encoding))
+        .set("provider", bytes_to_string(&liquidity_removed.provider, encoding))
+        .set("tokens_raw", tokens_raw(&liquidity_removed.tokens, encoding))
+        .set("amounts_raw", liquidity_removed.amounts.join(","))
+        .set("liquidity", liquidity_or_zero(&liquidity_removed.liquidity));
+}
+
+fn tokens_raw(tokens: &[Vec<u8>], encoding: &Encoding) -> String {
+    tokens.iter().map(|token| bytes_to_string(token, encoding)).collect::<Vec<_>>().join(",")
+}
+
+// `liquidity` is only reported by concentrated liquidity pools
+fn liquidity_or_zero(liquidity: &str) -> &str {
+    if liquidity.is_empty() {
+        "0"
+    } else {
+        liquidity
+    }
+}
+
 fn protocol_name(protocol: i32) -> &'static str {
     match pb::Protocol::try_from(protocol).unwrap_or(pb::Protocol::Unspecified) {
         pb::Protocol::Aerodrome => "aerodrome",
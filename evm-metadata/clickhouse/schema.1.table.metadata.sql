@@ -0,0 +1,21 @@
+-- ERC-20 token metadata --
+-- Fetched once per contract, on its first transfer --
+CREATE TABLE IF NOT EXISTS erc20_metadata (
+    -- block --
+    block_num            UInt32,
+    block_hash           String,
+    timestamp            DateTime(0, 'UTC'),
+
+    -- metadata --
+    contract            String COMMENT 'token contract address',
+    name                String COMMENT 'name(), empty if not implemented',
+    symbol              String COMMENT 'symbol(), empty if not implemented',
+    decimals            UInt8 COMMENT 'decimals(), 0 if not implemented',
+
+    -- indexes --
+    INDEX idx_symbol (symbol) TYPE bloom_filter GRANULARITY 4,
+    INDEX idx_name (name) TYPE bloom_filter GRANULARITY 4
+)
+ENGINE = ReplacingMergeTree(block_num)
+ORDER BY (contract)
+COMMENT 'ERC-20 token metadata (name, symbol, decimals) per contract';
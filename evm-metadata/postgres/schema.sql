@@ -0,0 +1,40 @@
+-- Blocks table for PostgreSQL
+CREATE TABLE IF NOT EXISTS blocks (
+    block_num            INTEGER NOT NULL PRIMARY KEY,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_blocks_block_hash ON blocks (block_hash);
+CREATE INDEX IF NOT EXISTS idx_blocks_timestamp ON blocks (timestamp);
+
+-- Upsert rule for blocks
+CREATE OR REPLACE RULE blocks_upsert AS ON INSERT TO blocks
+WHERE EXISTS (SELECT 1 FROM blocks WHERE block_num = NEW.block_num)
+DO INSTEAD UPDATE blocks SET
+    block_hash = NEW.block_hash,
+    timestamp = NEW.timestamp
+WHERE block_num = NEW.block_num;
+
+-- ERC-20 token metadata table for PostgreSQL
+-- Fetched once per contract, on its first transfer
+CREATE TABLE IF NOT EXISTS erc20_metadata (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+
+    -- metadata --
+    contract             TEXT NOT NULL PRIMARY KEY,
+    name                 TEXT NOT NULL,
+    symbol               TEXT NOT NULL,
+    decimals             SMALLINT NOT NULL
+);
+
+-- Block indexes
+CREATE INDEX IF NOT EXISTS idx_erc20_metadata_block_num ON erc20_metadata (block_num);
+CREATE INDEX IF NOT EXISTS idx_erc20_metadata_timestamp ON erc20_metadata (timestamp);
+
+-- Single column indexes
+CREATE INDEX IF NOT EXISTS idx_erc20_metadata_symbol ON erc20_metadata (symbol);
+CREATE INDEX IF NOT EXISTS idx_erc20_metadata_name ON erc20_metadata (name);
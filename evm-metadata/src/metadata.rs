@@ -0,0 +1,20 @@
+use common::{bytes_to_string, Encoding};
+use proto::pb::erc20::metadata::v1 as pb;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+
+use crate::set_clock;
+
+pub fn process_events(encoding: &Encoding, tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    for metadata in events.metadata.iter() {
+        let contract = bytes_to_string(&metadata.contract, encoding);
+        let row = tables
+            .upsert_row("erc20_metadata", &contract)
+            .set("contract", &contract)
+            .set("name", metadata.name.as_deref().unwrap_or_default())
+            .set("symbol", metadata.symbol.as_deref().unwrap_or_default())
+            .set("decimals", metadata.decimals.unwrap_or_default());
+
+        set_clock(clock, row);
+    }
+}
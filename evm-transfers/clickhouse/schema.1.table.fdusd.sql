@@ -0,0 +1,21 @@
+-- FDUSD OwnershipTransferred events (the owner mints and burns) --
+CREATE TABLE IF NOT EXISTS fdusd_ownership_transferred AS TEMPLATE_LOG
+COMMENT 'FDUSD OwnershipTransferred events';
+ALTER TABLE fdusd_ownership_transferred
+    -- event --
+    ADD COLUMN IF NOT EXISTS previous_owner  String,
+    ADD COLUMN IF NOT EXISTS new_owner       String;
+
+-- FDUSD Paused events --
+CREATE TABLE IF NOT EXISTS fdusd_paused AS TEMPLATE_LOG
+COMMENT 'FDUSD Paused events';
+ALTER TABLE fdusd_paused
+    -- event --
+    ADD COLUMN IF NOT EXISTS account  String;
+
+-- FDUSD Unpaused events --
+CREATE TABLE IF NOT EXISTS fdusd_unpaused AS TEMPLATE_LOG
+COMMENT 'FDUSD Unpaused events';
+ALTER TABLE fdusd_unpaused
+    -- event --
+    ADD COLUMN IF NOT EXISTS account  String;
@@ -0,0 +1,44 @@
+-- PYUSD SupplyIncreased events --
+CREATE TABLE IF NOT EXISTS pyusd_supply_increased AS TEMPLATE_LOG
+COMMENT 'PYUSD SupplyIncreased events';
+ALTER TABLE pyusd_supply_increased
+    -- event --
+    ADD COLUMN IF NOT EXISTS `to`   String,
+    ADD COLUMN IF NOT EXISTS value  UInt256;
+
+-- PYUSD SupplyDecreased events --
+CREATE TABLE IF NOT EXISTS pyusd_supply_decreased AS TEMPLATE_LOG
+COMMENT 'PYUSD SupplyDecreased events';
+ALTER TABLE pyusd_supply_decreased
+    -- event --
+    ADD COLUMN IF NOT EXISTS `from`  String,
+    ADD COLUMN IF NOT EXISTS value   UInt256;
+
+-- PYUSD SupplyControllerSet events --
+CREATE TABLE IF NOT EXISTS pyusd_supply_controller_set AS TEMPLATE_LOG
+COMMENT 'PYUSD SupplyControllerSet events';
+ALTER TABLE pyusd_supply_controller_set
+    -- event --
+    ADD COLUMN IF NOT EXISTS old_supply_controller  String,
+    ADD COLUMN IF NOT EXISTS new_supply_controller  String;
+
+-- PYUSD AddressFrozen events --
+CREATE TABLE IF NOT EXISTS pyusd_address_frozen AS TEMPLATE_LOG
+COMMENT 'PYUSD AddressFrozen events';
+ALTER TABLE pyusd_address_frozen
+    -- event --
+    ADD COLUMN IF NOT EXISTS addr  String;
+
+-- PYUSD AddressUnfrozen events --
+CREATE TABLE IF NOT EXISTS pyusd_address_unfrozen AS TEMPLATE_LOG
+COMMENT 'PYUSD AddressUnfrozen events';
+ALTER TABLE pyusd_address_unfrozen
+    -- event --
+    ADD COLUMN IF NOT EXISTS addr  String;
+
+-- PYUSD FrozenAddressWiped events --
+CREATE TABLE IF NOT EXISTS pyusd_frozen_address_wiped AS TEMPLATE_LOG
+COMMENT 'PYUSD FrozenAddressWiped events';
+ALTER TABLE pyusd_frozen_address_wiped
+    -- event --
+    ADD COLUMN IF NOT EXISTS addr  String;
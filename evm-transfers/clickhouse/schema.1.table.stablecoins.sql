@@ -0,0 +1,44 @@
+-- Stablecoin issuer events, normalized across issuers (USDC, EURC, USDT, WBTC, SAI, DAI / USDS join adapters, PYUSD, sUSDe) --
+-- `token` is the emitting contract, `amount` is 0 for freeze & unfreeze events --
+CREATE TABLE IF NOT EXISTS stablecoin_events AS TEMPLATE_LOG
+COMMENT 'Normalized stablecoin Issuance, Redemption, Freeze, Unfreeze & FundsSeized events';
+ALTER TABLE stablecoin_events
+    -- event --
+    ADD COLUMN IF NOT EXISTS event_type   LowCardinality(String) COMMENT 'issuance, redemption, freeze, unfreeze, funds_seized',
+    ADD COLUMN IF NOT EXISTS token        String COMMENT 'token contract address',
+    ADD COLUMN IF NOT EXISTS account      String COMMENT 'minted to, burned from, frozen, unfrozen or seized account',
+    ADD COLUMN IF NOT EXISTS amount       UInt256 DEFAULT 0,
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_event_type (event_type) TYPE set(8) GRANULARITY 4,
+    ADD INDEX IF NOT EXISTS idx_account (account) TYPE bloom_filter GRANULARITY 4;
+-- compliance history is kept (no TTL, unlike the other base log tables) --
+ALTER TABLE stablecoin_events MODIFY TTL timestamp + INTERVAL 100 YEAR;
+ALTER TABLE stablecoin_events REMOVE TTL;
+
+-- PROJECTIONS --
+ALTER TABLE stablecoin_events ADD PROJECTION IF NOT EXISTS prj_token_event_type_count ( SELECT token, event_type, count(), sum(amount), min(block_num), max(block_num), min(timestamp), max(timestamp) GROUP BY token, event_type );
+ALTER TABLE stablecoin_events ADD PROJECTION IF NOT EXISTS prj_account ( SELECT * ORDER BY account, token, block_num );
+
+-- Current freeze state per (token, account) --
+-- The latest Freeze / Unfreeze event wins (`frozen = false` rows are unfrozen accounts) --
+CREATE TABLE IF NOT EXISTS frozen_accounts (
+    -- block --
+    block_num            UInt32,
+    block_hash           String,
+    timestamp            DateTime(0, 'UTC'),
+    minute               UInt32 COMMENT 'toRelativeMinuteNum(timestamp)',
+
+    -- freeze state --
+    token                String COMMENT 'token contract address',
+    account              String COMMENT 'frozen account',
+    frozen               Bool,
+    tx_hash              String COMMENT 'transaction of the last Freeze / Unfreeze event',
+
+    -- projections --
+    PROJECTION prj_account ( SELECT * ORDER BY account, token )
+)
+ENGINE = ReplacingMergeTree(block_num)
+ORDER BY (token, account)
+SETTINGS deduplicate_merge_projection_mode = 'rebuild'
+COMMENT 'Current freeze state of stablecoin accounts per token';
@@ -0,0 +1,32 @@
+-- USDe MinterUpdated events --
+CREATE TABLE IF NOT EXISTS usde_minter_updated AS TEMPLATE_LOG
+COMMENT 'USDe MinterUpdated events';
+ALTER TABLE usde_minter_updated
+    -- event --
+    ADD COLUMN IF NOT EXISTS new_minter  String,
+    ADD COLUMN IF NOT EXISTS old_minter  String;
+
+-- USDe BlacklistAdded (sUSDe RoleGranted) events --
+CREATE TABLE IF NOT EXISTS usde_blacklist_added AS TEMPLATE_LOG
+COMMENT 'USDe BlacklistAdded events';
+ALTER TABLE usde_blacklist_added
+    -- event --
+    ADD COLUMN IF NOT EXISTS account           String,
+    ADD COLUMN IF NOT EXISTS full_restriction  Bool;
+
+-- USDe BlacklistRemoved (sUSDe RoleRevoked) events --
+CREATE TABLE IF NOT EXISTS usde_blacklist_removed AS TEMPLATE_LOG
+COMMENT 'USDe BlacklistRemoved events';
+ALTER TABLE usde_blacklist_removed
+    -- event --
+    ADD COLUMN IF NOT EXISTS account           String,
+    ADD COLUMN IF NOT EXISTS full_restriction  Bool;
+
+-- USDe LockedAmountRedistributed (sUSDe) events --
+CREATE TABLE IF NOT EXISTS usde_locked_amount_redistributed AS TEMPLATE_LOG
+COMMENT 'USDe LockedAmountRedistributed events';
+ALTER TABLE usde_locked_amount_redistributed
+    -- event --
+    ADD COLUMN IF NOT EXISTS `from`  String,
+    ADD COLUMN IF NOT EXISTS `to`    String,
+    ADD COLUMN IF NOT EXISTS amount  UInt256;
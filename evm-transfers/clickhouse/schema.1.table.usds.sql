@@ -0,0 +1,33 @@
+-- DAI / USDS Rely events (DAI `rely` LogNote included) --
+CREATE TABLE IF NOT EXISTS usds_rely AS TEMPLATE_LOG
+COMMENT 'DAI / USDS Rely events';
+ALTER TABLE usds_rely
+    -- event --
+    ADD COLUMN IF NOT EXISTS usr  String;
+
+-- DAI / USDS Deny events (DAI `deny` LogNote included) --
+CREATE TABLE IF NOT EXISTS usds_deny AS TEMPLATE_LOG
+COMMENT 'DAI / USDS Deny events';
+ALTER TABLE usds_deny
+    -- event --
+    ADD COLUMN IF NOT EXISTS usr  String;
+
+-- DAI / USDS Join events (DaiJoin `join` LogNote included) --
+CREATE TABLE IF NOT EXISTS usds_join AS TEMPLATE_LOG
+COMMENT 'DAI / USDS Join events';
+ALTER TABLE usds_join
+    -- event --
+    ADD COLUMN IF NOT EXISTS caller  String,
+    ADD COLUMN IF NOT EXISTS usr     String,
+    ADD COLUMN IF NOT EXISTS wad     UInt256,
+    ADD COLUMN IF NOT EXISTS token   String COMMENT 'DAI / USDS of the known join adapters, empty otherwise';
+
+-- DAI / USDS Exit events (DaiJoin `exit` LogNote included) --
+CREATE TABLE IF NOT EXISTS usds_exit AS TEMPLATE_LOG
+COMMENT 'DAI / USDS Exit events';
+ALTER TABLE usds_exit
+    -- event --
+    ADD COLUMN IF NOT EXISTS caller  String,
+    ADD COLUMN IF NOT EXISTS usr     String,
+    ADD COLUMN IF NOT EXISTS wad     UInt256,
+    ADD COLUMN IF NOT EXISTS token   String COMMENT 'DAI / USDS of the known join adapters, empty otherwise';
@@ -1589,3 +1589,811 @@ CREATE INDEX IF NOT EXISTS idx_erc4626_withdraw_timestamp ON erc4626_withdraw (t
 CREATE INDEX IF NOT EXISTS idx_erc4626_withdraw_log_address ON erc4626_withdraw (log_address);
 CREATE INDEX IF NOT EXISTS idx_erc4626_withdraw_sender ON erc4626_withdraw (sender);
 CREATE INDEX IF NOT EXISTS idx_erc4626_withdraw_owner ON erc4626_withdraw (owner);
+
+-- DAI / USDS Rely table for PostgreSQL
+CREATE TABLE IF NOT EXISTS usds_rely (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- rely --
+    usr                  TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_usds_rely_timestamp ON usds_rely (timestamp);
+CREATE INDEX IF NOT EXISTS idx_usds_rely_usr ON usds_rely (usr);
+
+-- DAI / USDS Deny table for PostgreSQL
+CREATE TABLE IF NOT EXISTS usds_deny (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- deny --
+    usr                  TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_usds_deny_timestamp ON usds_deny (timestamp);
+CREATE INDEX IF NOT EXISTS idx_usds_deny_usr ON usds_deny (usr);
+
+-- DAI / USDS Join table for PostgreSQL
+CREATE TABLE IF NOT EXISTS usds_join (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- join --
+    caller               TEXT NOT NULL,
+    usr                  TEXT NOT NULL,
+    wad                  NUMERIC NOT NULL,
+    token                TEXT NOT NULL DEFAULT '',
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_usds_join_timestamp ON usds_join (timestamp);
+CREATE INDEX IF NOT EXISTS idx_usds_join_caller ON usds_join (caller);
+
+-- DAI / USDS Exit table for PostgreSQL
+CREATE TABLE IF NOT EXISTS usds_exit (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- exit --
+    caller               TEXT NOT NULL,
+    usr                  TEXT NOT NULL,
+    wad                  NUMERIC NOT NULL,
+    token                TEXT NOT NULL DEFAULT '',
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_usds_exit_timestamp ON usds_exit (timestamp);
+CREATE INDEX IF NOT EXISTS idx_usds_exit_usr ON usds_exit (usr);
+
+-- PYUSD SupplyIncreased table for PostgreSQL
+CREATE TABLE IF NOT EXISTS pyusd_supply_increased (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- supply increased --
+    "to"                 TEXT NOT NULL,
+    value                NUMERIC NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pyusd_supply_increased_timestamp ON pyusd_supply_increased (timestamp);
+CREATE INDEX IF NOT EXISTS idx_pyusd_supply_increased_to ON pyusd_supply_increased ("to");
+
+-- PYUSD SupplyDecreased table for PostgreSQL
+CREATE TABLE IF NOT EXISTS pyusd_supply_decreased (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- supply decreased --
+    "from"               TEXT NOT NULL,
+    value                NUMERIC NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pyusd_supply_decreased_timestamp ON pyusd_supply_decreased (timestamp);
+CREATE INDEX IF NOT EXISTS idx_pyusd_supply_decreased_from ON pyusd_supply_decreased ("from");
+
+-- PYUSD SupplyControllerSet table for PostgreSQL
+CREATE TABLE IF NOT EXISTS pyusd_supply_controller_set (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- supply controller set --
+    old_supply_controller TEXT NOT NULL,
+    new_supply_controller TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pyusd_supply_controller_set_timestamp ON pyusd_supply_controller_set (timestamp);
+CREATE INDEX IF NOT EXISTS idx_pyusd_supply_controller_set_new_supply_controller ON pyusd_supply_controller_set (new_supply_controller);
+
+-- PYUSD AddressFrozen table for PostgreSQL
+CREATE TABLE IF NOT EXISTS pyusd_address_frozen (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- address frozen --
+    addr                 TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pyusd_address_frozen_timestamp ON pyusd_address_frozen (timestamp);
+CREATE INDEX IF NOT EXISTS idx_pyusd_address_frozen_addr ON pyusd_address_frozen (addr);
+
+-- PYUSD AddressUnfrozen table for PostgreSQL
+CREATE TABLE IF NOT EXISTS pyusd_address_unfrozen (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- address unfrozen --
+    addr                 TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pyusd_address_unfrozen_timestamp ON pyusd_address_unfrozen (timestamp);
+CREATE INDEX IF NOT EXISTS idx_pyusd_address_unfrozen_addr ON pyusd_address_unfrozen (addr);
+
+-- PYUSD FrozenAddressWiped table for PostgreSQL
+CREATE TABLE IF NOT EXISTS pyusd_frozen_address_wiped (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- frozen address wiped --
+    addr                 TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pyusd_frozen_address_wiped_timestamp ON pyusd_frozen_address_wiped (timestamp);
+CREATE INDEX IF NOT EXISTS idx_pyusd_frozen_address_wiped_addr ON pyusd_frozen_address_wiped (addr);
+
+-- USDe MinterUpdated table for PostgreSQL
+CREATE TABLE IF NOT EXISTS usde_minter_updated (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- minter updated --
+    new_minter           TEXT NOT NULL,
+    old_minter           TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_usde_minter_updated_timestamp ON usde_minter_updated (timestamp);
+CREATE INDEX IF NOT EXISTS idx_usde_minter_updated_new_minter ON usde_minter_updated (new_minter);
+
+-- USDe BlacklistAdded table for PostgreSQL
+CREATE TABLE IF NOT EXISTS usde_blacklist_added (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- blacklist added --
+    account              TEXT NOT NULL,
+    full_restriction     BOOLEAN NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_usde_blacklist_added_timestamp ON usde_blacklist_added (timestamp);
+CREATE INDEX IF NOT EXISTS idx_usde_blacklist_added_account ON usde_blacklist_added (account);
+
+-- USDe BlacklistRemoved table for PostgreSQL
+CREATE TABLE IF NOT EXISTS usde_blacklist_removed (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- blacklist removed --
+    account              TEXT NOT NULL,
+    full_restriction     BOOLEAN NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_usde_blacklist_removed_timestamp ON usde_blacklist_removed (timestamp);
+CREATE INDEX IF NOT EXISTS idx_usde_blacklist_removed_account ON usde_blacklist_removed (account);
+
+-- USDe LockedAmountRedistributed table for PostgreSQL
+CREATE TABLE IF NOT EXISTS usde_locked_amount_redistributed (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- locked amount redistributed --
+    "from"               TEXT NOT NULL,
+    "to"                 TEXT NOT NULL,
+    amount               NUMERIC NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_usde_locked_amount_redistributed_timestamp ON usde_locked_amount_redistributed (timestamp);
+CREATE INDEX IF NOT EXISTS idx_usde_locked_amount_redistributed_from ON usde_locked_amount_redistributed ("from");
+
+-- FDUSD OwnershipTransferred table for PostgreSQL
+CREATE TABLE IF NOT EXISTS fdusd_ownership_transferred (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- ownership transferred --
+    previous_owner       TEXT NOT NULL,
+    new_owner            TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_fdusd_ownership_transferred_timestamp ON fdusd_ownership_transferred (timestamp);
+CREATE INDEX IF NOT EXISTS idx_fdusd_ownership_transferred_new_owner ON fdusd_ownership_transferred (new_owner);
+
+-- FDUSD Paused table for PostgreSQL
+CREATE TABLE IF NOT EXISTS fdusd_paused (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- paused --
+    account              TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_fdusd_paused_timestamp ON fdusd_paused (timestamp);
+CREATE INDEX IF NOT EXISTS idx_fdusd_paused_account ON fdusd_paused (account);
+
+-- FDUSD Unpaused table for PostgreSQL
+CREATE TABLE IF NOT EXISTS fdusd_unpaused (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- unpaused --
+    account              TEXT NOT NULL,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_fdusd_unpaused_timestamp ON fdusd_unpaused (timestamp);
+CREATE INDEX IF NOT EXISTS idx_fdusd_unpaused_account ON fdusd_unpaused (account);
+
+-- Stablecoin events table for PostgreSQL (normalized across issuers: USDC, EURC, USDT, WBTC, SAI, DAI / USDS join adapters, PYUSD, sUSDe)
+CREATE TABLE IF NOT EXISTS stablecoin_events (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- transaction --
+    tx_index             INTEGER NOT NULL,
+    tx_hash              TEXT NOT NULL,
+    tx_from              TEXT NOT NULL,
+    tx_to                TEXT,
+    tx_nonce             BIGINT NOT NULL,
+    tx_gas_price         NUMERIC NOT NULL,
+    tx_gas_limit         BIGINT NOT NULL,
+    tx_gas_used          BIGINT NOT NULL,
+    tx_value             NUMERIC NOT NULL,
+
+    -- log --
+    log_index            INTEGER NOT NULL,
+    log_address          TEXT NOT NULL,
+    log_ordinal          INTEGER NOT NULL,
+    log_block_index      INTEGER NOT NULL DEFAULT 0,
+    log_topics           TEXT NOT NULL,
+    log_data             TEXT NOT NULL,
+
+    -- call --
+    call_caller          TEXT NOT NULL DEFAULT '',
+    call_index           INTEGER NOT NULL DEFAULT 0,
+    call_depth           INTEGER NOT NULL DEFAULT 0,
+    call_type            TEXT NOT NULL DEFAULT '',
+
+    -- event (amount is 0 for freeze & unfreeze) --
+    event_type           TEXT NOT NULL,
+    token                TEXT NOT NULL,
+    account              TEXT NOT NULL,
+    amount               NUMERIC NOT NULL DEFAULT 0,
+
+    PRIMARY KEY (block_num, tx_index, log_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_stablecoin_events_timestamp ON stablecoin_events (timestamp);
+CREATE INDEX IF NOT EXISTS idx_stablecoin_events_token_event_type ON stablecoin_events (token, event_type);
+CREATE INDEX IF NOT EXISTS idx_stablecoin_events_account ON stablecoin_events (account);
+
+-- Frozen accounts table for PostgreSQL
+-- There can only be a single freeze state per token / account pair (latest Freeze / Unfreeze event)
+CREATE TABLE IF NOT EXISTS frozen_accounts (
+    -- block --
+    block_num            INTEGER NOT NULL,
+    block_hash           TEXT NOT NULL,
+    timestamp            TIMESTAMP NOT NULL,
+    minute               INTEGER NOT NULL,
+
+    -- freeze state --
+    token                TEXT NOT NULL,
+    account              TEXT NOT NULL,
+    frozen               BOOLEAN NOT NULL,
+    tx_hash              TEXT NOT NULL,
+
+    PRIMARY KEY (token, account)
+);
+
+CREATE INDEX IF NOT EXISTS idx_frozen_accounts_account ON frozen_accounts (account);
+CREATE INDEX IF NOT EXISTS idx_frozen_accounts_frozen ON frozen_accounts (token) WHERE frozen;
+
+-- Upsert rule for frozen_accounts
+CREATE OR REPLACE RULE frozen_accounts_upsert AS ON INSERT TO frozen_accounts
+WHERE EXISTS (SELECT 1 FROM frozen_accounts WHERE token = NEW.token AND account = NEW.account)
+DO INSTEAD UPDATE frozen_accounts SET
+    block_num = NEW.block_num,
+    block_hash = NEW.block_hash,
+    timestamp = NEW.timestamp,
+    minute = NEW.minute,
+    frozen = NEW.frozen,
+    tx_hash = NEW.tx_hash
+WHERE token = NEW.token AND account = NEW.account;
@@ -412,6 +412,246 @@ pub fn process_events(encoding: &Encoding, tables: &mut Tables, clock: &Clock, e
                 row.set("owner", bytes_to_string(&event.owner, encoding));
                 row.set("amount_of_shares", &event.amount_of_shares);
             }
+
+            // DAI / USDS Rely
+            if let Some(pb::log::Log::UsdsRely(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("usds_rely", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("usr", bytes_to_string(&event.usr, encoding));
+            }
+
+            // DAI / USDS Deny
+            if let Some(pb::log::Log::UsdsDeny(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("usds_deny", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("usr", bytes_to_string(&event.usr, encoding));
+            }
+
+            // DAI / USDS Join
+            if let Some(pb::log::Log::UsdsJoin(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("usds_join", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("caller", bytes_to_string(&event.caller, encoding));
+                row.set("usr", bytes_to_string(&event.usr, encoding));
+                row.set("wad", &event.wad);
+                if !event.token.is_empty() {
+                    row.set("token", bytes_to_string(&event.token, encoding));
+                }
+            }
+
+            // DAI / USDS Exit
+            if let Some(pb::log::Log::UsdsExit(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("usds_exit", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("caller", bytes_to_string(&event.caller, encoding));
+                row.set("usr", bytes_to_string(&event.usr, encoding));
+                row.set("wad", &event.wad);
+                if !event.token.is_empty() {
+                    row.set("token", bytes_to_string(&event.token, encoding));
+                }
+            }
+
+            // PYUSD SupplyIncreased
+            if let Some(pb::log::Log::PyusdSupplyIncreased(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("pyusd_supply_increased", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("to", bytes_to_string(&event.to, encoding));
+                row.set("value", &event.value);
+            }
+
+            // PYUSD SupplyDecreased
+            if let Some(pb::log::Log::PyusdSupplyDecreased(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("pyusd_supply_decreased", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("from", bytes_to_string(&event.from, encoding));
+                row.set("value", &event.value);
+            }
+
+            // PYUSD SupplyControllerSet
+            if let Some(pb::log::Log::PyusdSupplyControllerSet(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("pyusd_supply_controller_set", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("old_supply_controller", bytes_to_string(&event.old_supply_controller, encoding));
+                row.set("new_supply_controller", bytes_to_string(&event.new_supply_controller, encoding));
+            }
+
+            // PYUSD AddressFrozen
+            if let Some(pb::log::Log::PyusdAddressFrozen(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("pyusd_address_frozen", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("addr", bytes_to_string(&event.addr, encoding));
+            }
+
+            // PYUSD AddressUnfrozen
+            if let Some(pb::log::Log::PyusdAddressUnfrozen(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("pyusd_address_unfrozen", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("addr", bytes_to_string(&event.addr, encoding));
+            }
+
+            // PYUSD FrozenAddressWiped
+            if let Some(pb::log::Log::PyusdFrozenAddressWiped(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("pyusd_frozen_address_wiped", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("addr", bytes_to_string(&event.addr, encoding));
+            }
+
+            // USDe MinterUpdated
+            if let Some(pb::log::Log::UsdeMinterUpdated(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("usde_minter_updated", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("new_minter", bytes_to_string(&event.new_minter, encoding));
+                row.set("old_minter", bytes_to_string(&event.old_minter, encoding));
+            }
+
+            // USDe BlacklistAdded (sUSDe RoleGranted)
+            if let Some(pb::log::Log::UsdeBlacklistAdded(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("usde_blacklist_added", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("account", bytes_to_string(&event.account, encoding));
+                row.set("full_restriction", event.full_restriction);
+            }
+
+            // USDe BlacklistRemoved (sUSDe RoleRevoked)
+            if let Some(pb::log::Log::UsdeBlacklistRemoved(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("usde_blacklist_removed", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("account", bytes_to_string(&event.account, encoding));
+                row.set("full_restriction", event.full_restriction);
+            }
+
+            // USDe LockedAmountRedistributed (sUSDe)
+            if let Some(pb::log::Log::UsdeLockedAmountRedistributed(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("usde_locked_amount_redistributed", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("from", bytes_to_string(&event.from, encoding));
+                row.set("to", bytes_to_string(&event.to, encoding));
+                row.set("amount", &event.amount);
+            }
+
+            // FDUSD OwnershipTransferred
+            if let Some(pb::log::Log::FdusdOwnershipTransferred(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("fdusd_ownership_transferred", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("previous_owner", bytes_to_string(&event.previous_owner, encoding));
+                row.set("new_owner", bytes_to_string(&event.new_owner, encoding));
+            }
+
+            // FDUSD Paused
+            if let Some(pb::log::Log::FdusdPaused(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("fdusd_paused", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("account", bytes_to_string(&event.account, encoding));
+            }
+
+            // FDUSD Unpaused
+            if let Some(pb::log::Log::FdusdUnpaused(event)) = &log.log {
+                let key = log_key(clock, tx_index, log_index);
+                let row = tables.create_row("fdusd_unpaused", key);
+
+                set_clock(clock, row);
+                set_template_log(encoding, log, log_index, row);
+                set_template_call(encoding, log, row);
+                set_template_tokens_tx(encoding, tx, tx_index, row);
+
+                row.set("account", bytes_to_string(&event.account, encoding));
+            }
         }
     }
 }
@@ -3,6 +3,7 @@ mod erc20_transfers;
 mod erc4626;
 mod logs;
 mod native_transfers;
+mod stablecoins;
 mod transactions;
 use substreams::pb::substreams::Clock;
 
@@ -33,6 +34,9 @@ pub fn db_out(
     // Process logs (ERC20 token-specific events)
     erc20_tokens::process_events(&encoding, &mut tables, &clock, &events_erc20_tokens);
 
+    // Process logs (normalized stablecoin issuance, redemption & freeze events)
+    stablecoins::process_events(&encoding, &mut tables, &clock, &events_erc20_tokens);
+
     // Process logs (ERC-4626 tokenized-vault events)
     erc4626::process_events(&encoding, &mut tables, &clock, &events_erc4626);
 
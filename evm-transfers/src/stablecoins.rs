@@ -0,0 +1,250 @@
+//! Issuer events of every supported stablecoin, normalized to a uniform `(token, account, amount)`
+//! shape (`token` is the emitting contract, or the token of a DAI / USDS join adapter):
+//!   - `issuance`: USDC & EURC `Mint` (to), USDT `Issue` (owner) / `Mint` (destination), WBTC & SAI `Mint`,
+//!     PYUSD `SupplyIncreased` (to), DAI / USDS join adapter `Exit` (usr)
+//!   - `redemption`: USDC & EURC `Burn` (burner), USDT `Redeem` (owner), WBTC & SAI `Burn`, PYUSD `SupplyDecreased` (from),
+//!     DAI / USDS join adapter `Join` (caller)
+//!   - `freeze` / `unfreeze`: USDC & EURC `Blacklisted` / `UnBlacklisted`, USDT `AddedBlackList` /
+//!     `RemovedBlackList` and `BlockPlaced` / `BlockReleased`, PYUSD `AddressFrozen` / `AddressUnfrozen`,
+//!     sUSDe full restricted staker role granted / revoked (no amount)
+//!   - `funds_seized`: USDT `DestroyedBlackFunds` / `DestroyedBlockedFunds` (balance of the frozen account),
+//!     PYUSD `FrozenAddressWiped` (no amount), sUSDe `LockedAmountRedistributed` (from)
+//!
+//! `frozen_accounts` keeps the current freeze state of every `(token, account)` pair.
+
+use common::{bytes_to_hex, bytes_to_string, Encoding};
+use proto::pb::erc20::tokens::v1 as pb;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+
+use crate::{
+    log_key,
+    logs::{set_template_call, set_template_log},
+    set_clock,
+    transactions::set_template_tokens_tx,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StablecoinEventType {
+    Issuance,
+    Redemption,
+    Freeze,
+    Unfreeze,
+    FundsSeized,
+}
+
+impl StablecoinEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StablecoinEventType::Issuance => "issuance",
+            StablecoinEventType::Redemption => "redemption",
+            StablecoinEventType::Freeze => "freeze",
+            StablecoinEventType::Unfreeze => "unfreeze",
+            StablecoinEventType::FundsSeized => "funds_seized",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StablecoinEvent<'a> {
+    pub event_type: StablecoinEventType,
+    pub account: &'a [u8],
+    pub amount: Option<&'a str>,
+    /// Token when it is not the emitting contract (join adapters).
+    pub token: Option<&'a [u8]>,
+}
+
+fn event<'a>(event_type: StablecoinEventType, account: &'a [u8], amount: Option<&'a str>) -> Option<StablecoinEvent<'a>> {
+    Some(StablecoinEvent {
+        event_type,
+        account,
+        amount,
+        token: None,
+    })
+}
+
+/// Join adapter event of `token`, skipped for unknown adapters (their token is not in the event).
+fn join_event<'a>(event_type: StablecoinEventType, account: &'a [u8], amount: &'a str, token: &'a [u8]) -> Option<StablecoinEvent<'a>> {
+    if token.is_empty() {
+        return None;
+    }
+    Some(StablecoinEvent {
+        event_type,
+        account,
+        amount: Some(amount),
+        token: Some(token),
+    })
+}
+
+pub fn stablecoin_event(log: &pb::log::Log) -> Option<StablecoinEvent<'_>> {
+    use pb::log::Log;
+    use StablecoinEventType::*;
+
+    match log {
+        // USDC
+        Log::UsdcMint(e) => event(Issuance, &e.to, Some(e.amount.as_str())),
+        Log::UsdcBurn(e) => event(Redemption, &e.burner, Some(e.amount.as_str())),
+        Log::UsdcBlacklisted(e) => event(Freeze, &e.account, None),
+        Log::UsdcUnBlacklisted(e) => event(Unfreeze, &e.account, None),
+
+        // USDT (tethertoken_v0_4_18)
+        Log::UsdtIssue(e) => event(Issuance, &e.owner, Some(e.amount.as_str())),
+        Log::UsdtRedeem(e) => event(Redemption, &e.owner, Some(e.amount.as_str())),
+        Log::UsdtAddedBlackList(e) => event(Freeze, &e.user, None),
+        Log::UsdtRemovedBlackList(e) => event(Unfreeze, &e.user, None),
+        Log::UsdtDestroyedBlackFunds(e) => event(FundsSeized, &e.black_listed_user, Some(e.balance.as_str())),
+
+        // USDT (tethertoken_v0_8_4)
+        Log::UsdtMint(e) => event(Issuance, &e.destination, Some(e.amount.as_str())),
+        Log::UsdtBlockPlaced(e) => event(Freeze, &e.user, None),
+        Log::UsdtBlockReleased(e) => event(Unfreeze, &e.user, None),
+        Log::UsdtDestroyedBlockedFunds(e) => event(FundsSeized, &e.blocked_user, Some(e.balance.as_str())),
+
+        // WBTC
+        Log::WbtcMint(e) => event(Issuance, &e.to, Some(e.amount.as_str())),
+        Log::WbtcBurn(e) => event(Redemption, &e.burner, Some(e.value.as_str())),
+
+        // SAI
+        Log::SaiMint(e) => event(Issuance, &e.guy, Some(e.wad.as_str())),
+        Log::SaiBurn(e) => event(Redemption, &e.guy, Some(e.wad.as_str())),
+
+        // DAI / USDS join adapters: `join` burns from the caller, `exit` mints to usr
+        Log::UsdsJoin(e) => join_event(Redemption, &e.caller, &e.wad, &e.token),
+        Log::UsdsExit(e) => join_event(Issuance, &e.usr, &e.wad, &e.token),
+
+        // PYUSD
+        Log::PyusdSupplyIncreased(e) => event(Issuance, &e.to, Some(e.value.as_str())),
+        Log::PyusdSupplyDecreased(e) => event(Redemption, &e.from, Some(e.value.as_str())),
+        Log::PyusdAddressFrozen(e) => event(Freeze, &e.addr, None),
+        Log::PyusdAddressUnfrozen(e) => event(Unfreeze, &e.addr, None),
+        Log::PyusdFrozenAddressWiped(e) => event(FundsSeized, &e.addr, None),
+
+        // sUSDe (soft restricted stakers can still transfer)
+        Log::UsdeBlacklistAdded(e) if e.full_restriction => event(Freeze, &e.account, None),
+        Log::UsdeBlacklistRemoved(e) if e.full_restriction => event(Unfreeze, &e.account, None),
+        Log::UsdeLockedAmountRedistributed(e) => event(FundsSeized, &e.from, Some(e.amount.as_str())),
+
+        _ => None,
+    }
+}
+
+pub fn process_events(encoding: &Encoding, tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    for (tx_index, tx) in events.transactions.iter().enumerate() {
+        for (log_index, log) in tx.logs.iter().enumerate() {
+            let Some(event) = log.log.as_ref().and_then(stablecoin_event) else {
+                continue;
+            };
+            let token = bytes_to_string(event.token.unwrap_or(&log.address), encoding);
+            let account = bytes_to_string(event.account, encoding);
+
+            let key = log_key(clock, tx_index, log_index);
+            let row = tables.create_row("stablecoin_events", key);
+
+            set_clock(clock, row);
+            set_template_log(encoding, log, log_index, row);
+            set_template_call(encoding, log, row);
+            set_template_tokens_tx(encoding, tx, tx_index, row);
+
+            row.set("event_type", event.event_type.as_str());
+            row.set("token", &token);
+            row.set("account", &account);
+            // freeze & unfreeze have no amount
+            if let Some(amount) = event.amount {
+                row.set("amount", amount);
+            }
+
+            // Current freeze state (the last event of the block wins)
+            let frozen = match event.event_type {
+                StablecoinEventType::Freeze => true,
+                StablecoinEventType::Unfreeze => false,
+                _ => continue,
+            };
+            let row = tables
+                .upsert_row("frozen_accounts", [("token", token.to_string()), ("account", account.to_string())])
+                .set("token", &token)
+                .set("account", &account)
+                .set("frozen", frozen)
+                .set("tx_hash", bytes_to_hex(&tx.hash));
+
+            set_clock(clock, row);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issuer_events_share_one_shape() {
+        let mint = pb::log::Log::UsdtMint(pb::UsdtMint {
+            destination: vec![0xaa; 20],
+            amount: "100".to_string(),
+        });
+        assert_eq!(
+            stablecoin_event(&mint),
+            Some(StablecoinEvent {
+                event_type: StablecoinEventType::Issuance,
+                account: &[0xaa; 20],
+                amount: Some("100"),
+                token: None,
+            })
+        );
+
+        let freeze = pb::log::Log::UsdcBlacklisted(pb::UsdcBlacklisted { account: vec![0xbb; 20] });
+        assert_eq!(
+            stablecoin_event(&freeze).map(|e| (e.event_type, e.amount)),
+            Some((StablecoinEventType::Freeze, None))
+        );
+
+        let seized = pb::log::Log::UsdtDestroyedBlackFunds(pb::UsdtDestroyedBlackFunds {
+            black_listed_user: vec![0xbb; 20],
+            balance: "7".to_string(),
+        });
+        assert_eq!(stablecoin_event(&seized).map(|e| e.event_type.as_str()), Some("funds_seized"));
+
+        let soft = pb::log::Log::UsdeBlacklistAdded(pb::UsdeBlacklistAdded {
+            account: vec![0xcc; 20],
+            full_restriction: false,
+        });
+        assert_eq!(stablecoin_event(&soft), None);
+
+        assert_eq!(stablecoin_event(&pb::log::Log::Pause(pb::Pause {})), None);
+    }
+
+    #[test]
+    fn join_adapter_events_use_the_adapter_token() {
+        let exit = pb::log::Log::UsdsExit(pb::UsdsExit {
+            caller: vec![0xaa; 20],
+            usr: vec![0xbb; 20],
+            wad: "5".to_string(),
+            token: vec![0xdd; 20],
+        });
+        assert_eq!(
+            stablecoin_event(&exit),
+            Some(StablecoinEvent {
+                event_type: StablecoinEventType::Issuance,
+                account: &[0xbb; 20],
+                amount: Some("5"),
+                token: Some(&[0xdd; 20]),
+            })
+        );
+
+        let join = pb::log::Log::UsdsJoin(pb::UsdsJoin {
+            caller: vec![0xaa; 20],
+            usr: vec![0xbb; 20],
+            wad: "5".to_string(),
+            token: vec![0xdd; 20],
+        });
+        assert_eq!(
+            stablecoin_event(&join).map(|e| (e.event_type, e.account)),
+            Some((StablecoinEventType::Redemption, &[0xaa; 20][..]))
+        );
+
+        let unknown = pb::log::Log::UsdsJoin(pb::UsdsJoin {
+            token: vec![],
+            ..Default::default()
+        });
+        assert_eq!(stablecoin_event(&unknown), None);
+    }
+}
@@ -0,0 +1,192 @@
+use proto::pb::evm::balances::v1::{NativeBalanceChange, NativeBalanceChanges};
+use substreams_ethereum::pb::eth::v2::{BalanceChange, BigInt as PbBigInt, Block, Call, TransactionTrace};
+
+use crate::utils::{balance_value, is_balance_change, is_failed_transaction, is_gas_balance_change};
+
+/// A balance change of an EXTENDED block that the chain kept, with the transaction and call it
+/// belongs to (`None` for block-level changes).
+pub struct AppliedChange<'a> {
+    pub trx: Option<&'a TransactionTrace>,
+    pub call: Option<&'a Call>,
+    pub change: &'a BalanceChange,
+}
+
+/// Balance changes of the block, system calls and transactions, sorted by ordinal.
+///
+/// Non-gas changes of failed transactions and of state-reverted calls are rolled back by the chain,
+/// so they are skipped (gas is still charged). A missing old or new value is a zero balance.
+pub fn applied_changes(block: &Block) -> Vec<AppliedChange<'_>> {
+    let mut changes: Vec<AppliedChange> = block
+        .balance_changes
+        .iter()
+        .map(|change| AppliedChange { trx: None, call: None, change })
+        .collect();
+
+    for call in &block.system_calls {
+        changes.extend(call.balance_changes.iter().map(|change| AppliedChange {
+            trx: None,
+            call: Some(call),
+            change,
+        }));
+    }
+
+    for trx in &block.transaction_traces {
+        let failed = is_failed_transaction(trx);
+        for call in &trx.calls {
+            let reverted = failed || call.state_reverted;
+            changes.extend(
+                call.balance_changes
+                    .iter()
+                    .filter(|change| !reverted || is_gas_balance_change(change))
+                    .map(|change| AppliedChange {
+                        trx: Some(trx),
+                        call: Some(call),
+                        change,
+                    }),
+            );
+        }
+    }
+
+    changes.retain(|applied| is_balance_change(applied.change) && common::is_valid_evm_address(&applied.change.address));
+    changes.sort_by_key(|applied| applied.change.ordinal);
+    changes
+}
+
+/// Every applied change as a ledger row, tied to its transaction and call.
+pub fn native_balance_changes(block: &Block) -> NativeBalanceChanges {
+    let balance_changes = applied_changes(block)
+        .into_iter()
+        .map(|applied| NativeBalanceChange {
+            address: applied.change.address.to_vec(),
+            old_value: to_uint256(applied.change.old_value.as_ref()),
+            new_value: to_uint256(applied.change.new_value.as_ref()),
+            reason: applied.change.reason,
+            ordinal: applied.change.ordinal,
+            tx_hash: applied.trx.map(|trx| trx.hash.to_vec()),
+            tx_index: applied.trx.map(|trx| trx.index),
+            call_index: applied.call.map(|call| call.index),
+        })
+        .collect();
+    NativeBalanceChanges { balance_changes }
+}
+
+/// Missing values are a zero balance (account created or emptied by the change).
+fn to_uint256(value: Option<&PbBigInt>) -> String {
+    balance_value(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proto::pb::evm::balances::v1::Reason as PbReason;
+    use substreams_ethereum::pb::eth::v2::{balance_change::Reason, TransactionTraceStatus};
+
+    fn change(address: u8, ordinal: u64, old: u64, new: u64, reason: Reason) -> BalanceChange {
+        BalanceChange {
+            address: vec![address; 20],
+            old_value: Some(PbBigInt {
+                bytes: old.to_be_bytes().to_vec(),
+            }),
+            new_value: Some(PbBigInt {
+                bytes: new.to_be_bytes().to_vec(),
+            }),
+            reason: reason as i32,
+            ordinal,
+        }
+    }
+
+    #[test]
+    fn rows_are_tied_to_their_transaction_and_call() {
+        let block = Block {
+            balance_changes: vec![change(0xcc, 9, 0, 2, Reason::RewardMineBlock)],
+            transaction_traces: vec![TransactionTrace {
+                hash: vec![0x01; 32],
+                index: 4,
+                status: TransactionTraceStatus::Succeeded as i32,
+                calls: vec![
+                    Call {
+                        index: 1,
+                        balance_changes: vec![change(0xaa, 1, 100, 90, Reason::GasBuy), change(0xaa, 7, 85, 88, Reason::GasRefund)],
+                        ..Default::default()
+                    },
+                    Call {
+                        index: 2,
+                        balance_changes: vec![change(0xaa, 3, 90, 85, Reason::Transfer), change(0xbb, 4, 0, 5, Reason::Transfer)],
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let rows = native_balance_changes(&block).balance_changes;
+        let ordinals: Vec<u64> = rows.iter().map(|row| row.ordinal).collect();
+        assert_eq!(ordinals, vec![1, 3, 4, 7, 9]);
+
+        assert_eq!(
+            rows[1],
+            NativeBalanceChange {
+                address: vec![0xaa; 20],
+                old_value: "90".to_string(),
+                new_value: "85".to_string(),
+                reason: PbReason::Transfer as i32,
+                ordinal: 3,
+                tx_hash: Some(vec![0x01; 32]),
+                tx_index: Some(4),
+                call_index: Some(2),
+            }
+        );
+        assert_eq!((rows[4].tx_hash.as_ref(), rows[4].call_index), (None, None));
+        assert_eq!(rows[4].reason(), PbReason::RewardMineBlock);
+    }
+
+    #[test]
+    fn zero_balances_are_kept_as_zero() {
+        let emptied = BalanceChange {
+            new_value: None,
+            ..change(0xaa, 2, 90, 0, Reason::Transfer)
+        };
+        let created = BalanceChange {
+            old_value: None,
+            ..change(0xbb, 3, 0, 90, Reason::Transfer)
+        };
+        let block = Block {
+            transaction_traces: vec![TransactionTrace {
+                status: TransactionTraceStatus::Succeeded as i32,
+                calls: vec![Call {
+                    balance_changes: vec![emptied, created],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let values: Vec<(String, String)> = native_balance_changes(&block)
+            .balance_changes
+            .into_iter()
+            .map(|row| (row.old_value, row.new_value))
+            .collect();
+        assert_eq!(values, vec![("90".to_string(), "0".to_string()), ("0".to_string(), "90".to_string())]);
+    }
+
+    #[test]
+    fn reverted_calls_only_keep_gas_changes() {
+        let block = Block {
+            transaction_traces: vec![TransactionTrace {
+                status: TransactionTraceStatus::Succeeded as i32,
+                calls: vec![Call {
+                    state_reverted: true,
+                    balance_changes: vec![change(0xaa, 1, 100, 90, Reason::GasBuy), change(0xaa, 2, 90, 80, Reason::Transfer)],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let reasons: Vec<PbReason> = native_balance_changes(&block).balance_changes.iter().map(|row| row.reason()).collect();
+        assert_eq!(reasons, vec![PbReason::GasBuy]);
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+
+use common::Address;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2::{BalanceChange, Block};
+
+use crate::applied_changes::applied_changes;
+use crate::utils::balance_value;
+
+/// Final native balance of every account changed in an EXTENDED block: the `new_value` of its
+/// balance change with the highest ordinal (zero when missing), sorted by address.
+///
+/// Only changes the chain kept are considered (see [`applied_changes`]).
+pub fn final_balances(block: &Block) -> Vec<(Address, BigInt)> {
+    let mut finals: BTreeMap<&Address, &BalanceChange> = BTreeMap::new();
+    // sorted by ordinal: the last change of an account wins
+    for applied in applied_changes(block) {
+        finals.insert(&applied.change.address, applied.change);
+    }
+
+    finals
+        .into_iter()
+        .map(|(address, change)| (address.to_vec(), balance_value(change.new_value.as_ref())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use substreams_ethereum::pb::eth::v2::{balance_change::Reason, BigInt as PbBigInt, Call, TransactionTrace, TransactionTraceStatus};
+
+    fn change(address: u8, ordinal: u64, new: u64, reason: Reason) -> BalanceChange {
+        BalanceChange {
+            address: vec![address; 20],
+            old_value: Some(PbBigInt { bytes: vec![0xff] }),
+            new_value: Some(PbBigInt {
+                bytes: new.to_be_bytes().to_vec(),
+            }),
+            reason: reason as i32,
+            ordinal,
+        }
+    }
+
+    fn trx(status: TransactionTraceStatus, balance_changes: Vec<BalanceChange>) -> TransactionTrace {
+        TransactionTrace {
+            status: status as i32,
+            calls: vec![Call {
+                balance_changes,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn takes_the_new_value_with_the_highest_ordinal() {
+        let block = Block {
+            balance_changes: vec![change(0xaa, 9, 30, Reason::RewardMineBlock)],
+            transaction_traces: vec![trx(
+                TransactionTraceStatus::Succeeded,
+                vec![
+                    change(0xaa, 5, 20, Reason::Transfer),
+                    change(0xaa, 2, 10, Reason::GasBuy),
+                    change(0xbb, 3, 7, Reason::Transfer),
+                ],
+            )],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            final_balances(&block),
+            vec![(vec![0xaa; 20], BigInt::from(30)), (vec![0xbb; 20], BigInt::from(7))]
+        );
+    }
+
+    #[test]
+    fn change_to_zero_is_a_zero_balance() {
+        let emptied = BalanceChange {
+            new_value: None,
+            ..change(0xaa, 4, 0, Reason::Transfer)
+        };
+        let block = Block {
+            transaction_traces: vec![trx(TransactionTraceStatus::Succeeded, vec![change(0xaa, 1, 10, Reason::GasBuy), emptied])],
+            ..Default::default()
+        };
+
+        assert_eq!(final_balances(&block), vec![(vec![0xaa; 20], BigInt::zero())]);
+    }
+
+    #[test]
+    fn failed_transactions_only_keep_gas_changes() {
+        let block = Block {
+            transaction_traces: vec![trx(
+                TransactionTraceStatus::Reverted,
+                vec![
+                    change(0xaa, 1, 10, Reason::GasBuy),
+                    change(0xaa, 2, 99, Reason::Transfer),
+                    change(0xbb, 3, 99, Reason::Transfer),
+                ],
+            )],
+            ..Default::default()
+        };
+
+        assert_eq!(final_balances(&block), vec![(vec![0xaa; 20], BigInt::from(10))]);
+    }
+}
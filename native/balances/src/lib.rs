@@ -1,9 +1,12 @@
+mod applied_changes;
 mod calls;
+mod final_balances;
 mod utils;
-use proto::pb::evm::balances::v1::{Balance, BalanceChange, BalanceChanges, Events};
+use common::rpc::parse_chunk_size;
+use proto::pb::evm::balances::v1::{Balance, BalanceChange, BalanceChanges, Events, NativeBalanceChanges};
 use std::collections::HashSet;
 use substreams::errors::Error;
-use substreams_ethereum::pb::eth::v2::Block;
+use substreams_ethereum::pb::eth::v2::{block::DetailLevel, Block};
 
 use crate::{
     calls::batch_eth_balance_of,
@@ -98,8 +101,47 @@ pub fn map_balance_changes(block: Block) -> Result<BalanceChanges, Error> {
 
 #[substreams::handlers::map]
 pub fn map_events(params: String, block: Block, balance_changes: BalanceChanges) -> Result<Events, Error> {
+    rpc_balances(&params, &block, balance_changes)
+}
+
+/// Same output as `map_events`, without `eth_getBalance` on EXTENDED blocks: the final balances are
+/// the `new_value` of each account's last balance change. BASE blocks (Avalanche and others) carry
+/// no balance changes and fall back to RPC.
+#[substreams::handlers::map]
+pub fn map_events_from_block(params: String, block: Block, balance_changes: BalanceChanges) -> Result<Events, Error> {
+    if block.detail_level() != DetailLevel::DetaillevelExtended {
+        return rpc_balances(&params, &block, balance_changes);
+    }
+
+    let balances: Vec<Balance> = final_balances::final_balances(&block)
+        .into_iter()
+        .map(|(address, amount)| Balance {
+            contract: None,
+            address,
+            amount: amount.to_string(),
+        })
+        .collect();
+
+    substreams::log::info!("Emitted {} balance events", balances.len());
+    Ok(Events {
+        balances,
+        ..Default::default()
+    })
+}
+
+/// Every native balance change of an EXTENDED block (old/new value, reason, ordinal), tied to its
+/// transaction and call. BASE blocks carry no balance changes and emit nothing.
+#[substreams::handlers::map]
+pub fn map_native_balance_changes(block: Block) -> Result<NativeBalanceChanges, Error> {
+    let balance_changes = applied_changes::native_balance_changes(&block);
+    substreams::log::info!("Emitted {} native balance changes", balance_changes.balance_changes.len());
+    Ok(balance_changes)
+}
+
+/// `eth_getBalance` of every account of `map_balance_changes` at this block.
+fn rpc_balances(params: &str, block: &Block, balance_changes: BalanceChanges) -> Result<Events, Error> {
     let mut events = Events::default();
-    let chunk_size = params.parse::<usize>().expect("Failed to parse chunk_size");
+    let chunk_size = parse_chunk_size(params)?;
 
     let accounts: Vec<Vec<u8>> = balance_changes.balance_changes.into_iter().map(|bc| bc.address).collect();
 
@@ -1,4 +1,5 @@
-use substreams_ethereum::pb::eth::v2::{balance_change::Reason, BalanceChange, Call, TransactionTrace, TransactionTraceStatus};
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2::{balance_change::Reason, BalanceChange, BigInt as PbBigInt, Call, TransactionTrace, TransactionTraceStatus};
 
 pub fn is_failed_transaction(trx: &TransactionTrace) -> bool {
     let status = trx.status();
@@ -41,3 +42,14 @@ pub fn is_valid_balance_change(balance_change: &BalanceChange) -> bool {
     }
     true
 }
+
+/// Balance changes of EXTENDED blocks omit zero values (`None`), so a missing old or new value is a
+/// zero balance: changes to or from zero are kept, only changes to the same value are dropped.
+pub fn is_balance_change(balance_change: &BalanceChange) -> bool {
+    balance_value(balance_change.old_value.as_ref()) != balance_value(balance_change.new_value.as_ref())
+}
+
+/// Balance of a change value, a missing value being a zero balance.
+pub fn balance_value(value: Option<&PbBigInt>) -> BigInt {
+    value.map_or_else(BigInt::zero, |value| BigInt::from_unsigned_bytes_be(&value.bytes))
+}
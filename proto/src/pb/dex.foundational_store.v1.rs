@@ -9,6 +9,9 @@ pub struct Pool {
     pub tokens: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
     #[prost(bytes="vec", tag="2")]
     pub factory: ::prost::alloc::vec::Vec<u8>,
+    /// set when a factory allowlist is configured, `false` for pools of unlisted factories
+    #[prost(bool, optional, tag="3")]
+    pub verified: ::core::option::Option<bool>,
 }
 /// A pool surfaced from the legacy `store_pools` store, keyed by its hex-encoded address.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -27,4 +30,224 @@ pub struct Pools {
     #[prost(message, repeated, tag="1")]
     pub pools: ::prost::alloc::vec::Vec<PoolCreated>,
 }
+/// Versioned pool metadata: `Pool` plus the protocol and its pool-creation parameters, so consumers
+/// do not have to re-read pool-creation events. `Pool` (v1) is still produced for existing consumers.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolV2 {
+    #[prost(enumeration="Protocol", tag="1")]
+    pub protocol: i32,
+    #[prost(bytes="vec", repeated, tag="2")]
+    pub tokens: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="3")]
+    pub factory: ::prost::alloc::vec::Vec<u8>,
+    /// set when a factory allowlist is configured, `false` for pools of unlisted factories
+    #[prost(bool, optional, tag="4")]
+    pub verified: ::core::option::Option<bool>,
+    /// Unset for protocols without pool-creation parameters (Uniswap V1/V2, Balancer, Bancor, CurveFi, SunPump).
+    #[prost(oneof="pool_v2::Params", tags="10, 11, 12, 13, 14")]
+    pub params: ::core::option::Option<pool_v2::Params>,
+}
+/// Nested message and enum types in `PoolV2`.
+pub mod pool_v2 {
+    /// Unset for protocols without pool-creation parameters (Uniswap V1/V2, Balancer, Bancor, CurveFi, SunPump).
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Params {
+        #[prost(message, tag="10")]
+        UniswapV3(super::UniswapV3Params),
+        #[prost(message, tag="11")]
+        UniswapV4(super::UniswapV4Params),
+        #[prost(message, tag="12")]
+        Aerodrome(super::AerodromeParams),
+        #[prost(message, tag="13")]
+        Traderjoe(super::TraderJoeParams),
+        #[prost(message, tag="14")]
+        KyberElastic(super::KyberElasticParams),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UniswapV3Params {
+    /// uint24, hundredths of a bip (e.g., 3000 represents 0.30%)
+    #[prost(uint32, tag="1")]
+    pub fee: u32,
+    /// int24
+    #[prost(int32, tag="2")]
+    pub tick_spacing: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UniswapV4Params {
+    /// uint24, hundredths of a bip, `0x800000` flags a dynamic-fee pool
+    #[prost(uint32, tag="1")]
+    pub fee: u32,
+    /// int24
+    #[prost(int32, tag="2")]
+    pub tick_spacing: i32,
+    /// address, or address(0) if none
+    #[prost(bytes="vec", tag="3")]
+    pub hooks: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AerodromeParams {
+    #[prost(bool, tag="1")]
+    pub stable: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TraderJoeParams {
+    #[prost(uint32, tag="1")]
+    pub bin_step: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KyberElasticParams {
+    #[prost(uint32, tag="1")]
+    pub swap_fee_units: u32,
+    #[prost(int32, tag="2")]
+    pub tick_distance: i32,
+}
+/// A pool surfaced from the legacy `store_pools_v2` store, keyed by its hex-encoded address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolCreatedV2 {
+    /// hex-encoded pool address (lowercase, no `0x`)
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub pool: ::core::option::Option<PoolV2>,
+}
+/// All pools first seen in a single block (one entry per `set_if_not_exists` store delta).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolsV2 {
+    #[prost(message, repeated, tag="1")]
+    pub pools: ::prost::alloc::vec::Vec<PoolCreatedV2>,
+}
+/// Uniswap V4 hook permissions, decoded from the lowest 14 bits of the hooks address (`Hooks.sol`).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HookPermissions {
+    #[prost(bool, tag="1")]
+    pub before_initialize: bool,
+    #[prost(bool, tag="2")]
+    pub after_initialize: bool,
+    #[prost(bool, tag="3")]
+    pub before_add_liquidity: bool,
+    #[prost(bool, tag="4")]
+    pub after_add_liquidity: bool,
+    #[prost(bool, tag="5")]
+    pub before_remove_liquidity: bool,
+    #[prost(bool, tag="6")]
+    pub after_remove_liquidity: bool,
+    #[prost(bool, tag="7")]
+    pub before_swap: bool,
+    #[prost(bool, tag="8")]
+    pub after_swap: bool,
+    #[prost(bool, tag="9")]
+    pub before_donate: bool,
+    #[prost(bool, tag="10")]
+    pub after_donate: bool,
+    #[prost(bool, tag="11")]
+    pub before_swap_returns_delta: bool,
+    #[prost(bool, tag="12")]
+    pub after_swap_returns_delta: bool,
+    #[prost(bool, tag="13")]
+    pub after_add_liquidity_returns_delta: bool,
+    #[prost(bool, tag="14")]
+    pub after_remove_liquidity_returns_delta: bool,
+}
+/// A Uniswap V4 pool initialized with a hooks contract.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HookPool {
+    #[prost(bytes="vec", tag="1")]
+    pub hooks: ::prost::alloc::vec::Vec<u8>,
+    /// 32-byte pool id
+    #[prost(bytes="vec", tag="2")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    /// PoolManager
+    #[prost(bytes="vec", tag="3")]
+    pub factory: ::prost::alloc::vec::Vec<u8>,
+    /// permission bits of `hooks` (lowest 14 bits of the address)
+    #[prost(uint32, tag="4")]
+    pub flags: u32,
+    #[prost(message, optional, tag="5")]
+    pub permissions: ::core::option::Option<HookPermissions>,
+}
+/// Hooked pools initialized in a single block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HookPools {
+    #[prost(message, repeated, tag="1")]
+    pub pools: ::prost::alloc::vec::Vec<HookPool>,
+}
+/// Protocol of a pool, numbered as `dex.swaps.v1.Protocol`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Protocol {
+    Unspecified = 0,
+    Aerodrome = 1,
+    Balancer = 2,
+    Bancor = 3,
+    Cow = 4,
+    Curvefi = 5,
+    Dodo = 6,
+    KyberElastic = 7,
+    Sunpump = 8,
+    Traderjoe = 9,
+    UniswapV1 = 10,
+    UniswapV2 = 11,
+    UniswapV3 = 12,
+    UniswapV4 = 13,
+    Woofi = 14,
+}
+impl Protocol {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Protocol::Unspecified => "PROTOCOL_UNSPECIFIED",
+            Protocol::Aerodrome => "PROTOCOL_AERODROME",
+            Protocol::Balancer => "PROTOCOL_BALANCER",
+            Protocol::Bancor => "PROTOCOL_BANCOR",
+            Protocol::Cow => "PROTOCOL_COW",
+            Protocol::Curvefi => "PROTOCOL_CURVEFI",
+            Protocol::Dodo => "PROTOCOL_DODO",
+            Protocol::KyberElastic => "PROTOCOL_KYBER_ELASTIC",
+            Protocol::Sunpump => "PROTOCOL_SUNPUMP",
+            Protocol::Traderjoe => "PROTOCOL_TRADERJOE",
+            Protocol::UniswapV1 => "PROTOCOL_UNISWAP_V1",
+            Protocol::UniswapV2 => "PROTOCOL_UNISWAP_V2",
+            Protocol::UniswapV3 => "PROTOCOL_UNISWAP_V3",
+            Protocol::UniswapV4 => "PROTOCOL_UNISWAP_V4",
+            Protocol::Woofi => "PROTOCOL_WOOFI",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PROTOCOL_UNSPECIFIED" => Some(Self::Unspecified),
+            "PROTOCOL_AERODROME" => Some(Self::Aerodrome),
+            "PROTOCOL_BALANCER" => Some(Self::Balancer),
+            "PROTOCOL_BANCOR" => Some(Self::Bancor),
+            "PROTOCOL_COW" => Some(Self::Cow),
+            "PROTOCOL_CURVEFI" => Some(Self::Curvefi),
+            "PROTOCOL_DODO" => Some(Self::Dodo),
+            "PROTOCOL_KYBER_ELASTIC" => Some(Self::KyberElastic),
+            "PROTOCOL_SUNPUMP" => Some(Self::Sunpump),
+            "PROTOCOL_TRADERJOE" => Some(Self::Traderjoe),
+            "PROTOCOL_UNISWAP_V1" => Some(Self::UniswapV1),
+            "PROTOCOL_UNISWAP_V2" => Some(Self::UniswapV2),
+            "PROTOCOL_UNISWAP_V3" => Some(Self::UniswapV3),
+            "PROTOCOL_UNISWAP_V4" => Some(Self::UniswapV4),
+            "PROTOCOL_WOOFI" => Some(Self::Woofi),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
@@ -0,0 +1,92 @@
+// @generated
+// This file is @generated by prost-build.
+/// MEV patterns detected over the normalized swaps (`dex.swaps.v1`) of a block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Events {
+    #[prost(message, repeated, tag = "1")]
+    pub sandwiches: ::prost::alloc::vec::Vec<Sandwich>,
+    #[prost(message, repeated, tag = "2")]
+    pub arbitrages: ::prost::alloc::vec::Vec<Arbitrage>,
+}
+/// Swap located by its position in the block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Leg {
+    #[prost(bytes = "vec", tag = "1")]
+    pub transaction_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub tx_index: u32,
+    #[prost(bytes = "vec", tag = "3")]
+    pub tx_from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "4")]
+    pub log_ordinal: u64,
+    #[prost(bytes = "vec", tag = "5")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub input_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "7")]
+    pub input_amount: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "8")]
+    pub output_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "9")]
+    pub output_amount: ::prost::alloc::string::String,
+}
+/// Net token balance change of the searcher, negative for a loss.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Profit {
+    #[prost(bytes = "vec", tag = "1")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Front-run and back-run by the same searcher around one or more victims on a single pool.
+/// Victims trade in the front-run direction, the back-run trades in the opposite direction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Sandwich {
+    #[prost(bytes = "vec", tag = "1")]
+    pub factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    /// transaction sender of the front-run and back-run
+    #[prost(bytes = "vec", tag = "3")]
+    pub attacker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub front_run: ::core::option::Option<Leg>,
+    #[prost(message, repeated, tag = "5")]
+    pub victims: ::prost::alloc::vec::Vec<Leg>,
+    #[prost(message, optional, tag = "6")]
+    pub back_run: ::core::option::Option<Leg>,
+    /// for both pool tokens
+    #[prost(message, repeated, tag = "7")]
+    pub profits: ::prost::alloc::vec::Vec<Profit>,
+}
+/// Route of a single transaction that starts and ends in the same token.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Arbitrage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub transaction_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub tx_index: u32,
+    #[prost(bytes = "vec", tag = "3")]
+    pub tx_from: ::prost::alloc::vec::Vec<u8>,
+    /// ordinal of the first hop
+    #[prost(uint64, tag = "4")]
+    pub log_ordinal: u64,
+    /// start and end token of the route
+    #[prost(bytes = "vec", tag = "5")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    /// token path, `token` at both ends
+    #[prost(bytes = "vec", repeated, tag = "6")]
+    pub route: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// pool of each hop
+    #[prost(bytes = "vec", repeated, tag = "7")]
+    pub pools: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// output minus input of `token`
+    #[prost(message, repeated, tag = "8")]
+    pub profits: ::prost::alloc::vec::Vec<Profit>,
+}
+// @@protoc_insertion_point(module)
@@ -43,7 +43,7 @@ pub struct Log {
     pub call: ::core::option::Option<Call>,
     #[prost(uint32, tag = "6")]
     pub block_index: u32,
-    #[prost(oneof = "log::Log", tags = "10, 11, 12")]
+    #[prost(oneof = "log::Log", tags = "10, 11, 12, 13, 14, 15")]
     pub log: ::core::option::Option<log::Log>,
 }
 /// Nested message and enum types in `Log`.
@@ -57,6 +57,12 @@ pub mod log {
         SwapFee(super::SwapFee),
         #[prost(message, tag = "12")]
         Initialize(super::Initialize),
+        #[prost(message, tag = "13")]
+        LiquidityAdded(super::LiquidityAdded),
+        #[prost(message, tag = "14")]
+        LiquidityRemoved(super::LiquidityRemoved),
+        #[prost(message, tag = "15")]
+        UnresolvedSwap(super::UnresolvedSwap),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -104,6 +110,53 @@ pub struct Swap {
     pub output_token: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag = "8")]
     pub output_amount: ::prost::alloc::string::String,
+    /// USD value of `input_amount`, set by `dex-swaps-prices` (empty when unpriced)
+    #[prost(string, tag = "9")]
+    pub input_usd: ::prost::alloc::string::String,
+    /// USD value of `output_amount`, set by `dex-swaps-prices` (empty when unpriced)
+    #[prost(string, tag = "10")]
+    pub output_usd: ::prost::alloc::string::String,
+    /// `<project>_<version>` of `factory` in the `common::projects` registry (e.g. `sushiswap_v2`), empty when unknown
+    #[prost(string, tag = "11")]
+    pub project: ::prost::alloc::string::String,
+    /// Uniswap V4 hooks contract of the pool, empty for other protocols and pools without hooks
+    #[prost(bytes = "vec", tag = "12")]
+    pub hooks: ::prost::alloc::vec::Vec<u8>,
+    /// `verified` of the pool in `store_pools_v2`: `false` for pools of unlisted factories (`unlisted=tag`), unset without an allowlist
+    #[prost(bool, optional, tag = "13")]
+    pub verified: ::core::option::Option<bool>,
+}
+/// Swap of a pool without metadata in `store_pools` (created before the start block, or by a
+/// factory that is not indexed), so its tokens and direction are unknown.
+///
+/// When the event names the traded tokens (Balancer, Bancor, SunPump, the ETH side of Uniswap V1),
+/// `token0` / `amount0` is the input and `token1` / `amount1` the output. CurveFi reports the sold /
+/// bought coins, whose addresses are unknown.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnresolvedSwap {
+    /// decoder that matched the event (forks sharing a signature report the first one)
+    #[prost(enumeration = "Protocol", tag = "1")]
+    pub protocol: i32,
+    /// empty when the pool is not in `store_pools`
+    #[prost(bytes = "vec", tag = "2")]
+    pub factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    /// signed token0 delta of the pool, positive when paid in
+    #[prost(string, tag = "5")]
+    pub amount0: ::prost::alloc::string::String,
+    /// signed token1 delta of the pool, positive when paid in
+    #[prost(string, tag = "6")]
+    pub amount1: ::prost::alloc::string::String,
+    /// token of `amount0` when the event names it, empty otherwise
+    #[prost(bytes = "vec", tag = "7")]
+    pub token0: ::prost::alloc::vec::Vec<u8>,
+    /// token of `amount1` when the event names it, empty otherwise
+    #[prost(bytes = "vec", tag = "8")]
+    pub token1: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -114,8 +167,15 @@ pub struct SwapFee {
     pub factory: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "3")]
     pub pool: ::prost::alloc::vec::Vec<u8>,
+    /// hundredths of a bip; Uniswap V4 dynamic-fee pools report the fee charged on swaps (LP + protocol fee)
     #[prost(uint32, tag = "4")]
     pub fee: u32,
+    /// `<project>_<version>` of `factory` in the `common::projects` registry (e.g. `sushiswap_v2`), empty when unknown
+    #[prost(string, tag = "5")]
+    pub project: ::prost::alloc::string::String,
+    /// block ordinal from which `fee` applies (the ordinal of the log that set it)
+    #[prost(uint64, tag = "6")]
+    pub effective_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -126,6 +186,161 @@ pub struct Initialize {
     pub factory: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "3")]
     pub pool: ::prost::alloc::vec::Vec<u8>,
+    /// `<project>_<version>` of `factory` in the `common::projects` registry (e.g. `sushiswap_v2`), empty when unknown
+    #[prost(string, tag = "4")]
+    pub project: ::prost::alloc::string::String,
+}
+/// Liquidity deposited into a pool. `amounts\[i\]` is the amount of `tokens\[i\]`, in pool order.
+/// `amounts` is empty when the event does not report token amounts (Uniswap V4).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityAdded {
+    #[prost(enumeration = "Protocol", tag = "1")]
+    pub protocol: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub provider: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub tokens: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, repeated, tag = "6")]
+    pub amounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// liquidity units, only for concentrated liquidity pools (Uniswap V3/V4, Kyber Elastic)
+    #[prost(string, tag = "7")]
+    pub liquidity: ::prost::alloc::string::String,
+}
+/// Liquidity withdrawn from a pool. Same layout as `LiquidityAdded`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityRemoved {
+    #[prost(enumeration = "Protocol", tag = "1")]
+    pub protocol: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub provider: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub tokens: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, repeated, tag = "6")]
+    pub amounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// liquidity units, only for concentrated liquidity pools (Uniswap V3/V4, Kyber Elastic)
+    #[prost(string, tag = "7")]
+    pub liquidity: ::prost::alloc::string::String,
+}
+/// Output of `map_pool_states`: pool state snapshots in block order.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolStates {
+    #[prost(message, repeated, tag = "1")]
+    pub pool_states: ::prost::alloc::vec::Vec<PoolState>,
+}
+/// State of a pool right after the log at `ordinal`. Unique per (`pool`, `ordinal`).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolState {
+    #[prost(enumeration = "Protocol", tag = "1")]
+    pub protocol: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub transaction_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "5")]
+    pub ordinal: u64,
+    #[prost(uint32, tag = "6")]
+    pub block_index: u32,
+    #[prost(oneof = "pool_state::State", tags = "10, 11, 12")]
+    pub state: ::core::option::Option<pool_state::State>,
+}
+/// Nested message and enum types in `PoolState`.
+pub mod pool_state {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum State {
+        #[prost(message, tag = "10")]
+        Reserves(super::Reserves),
+        #[prost(message, tag = "11")]
+        ConcentratedLiquidity(super::ConcentratedLiquidity),
+        #[prost(message, tag = "12")]
+        ActiveBin(super::ActiveBin),
+    }
+}
+/// Constant product reserves (Uniswap V2 / Aerodrome `Sync`). `reserves\[i\]` is the balance of `tokens\[i\]`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Reserves {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub tokens: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, repeated, tag = "2")]
+    pub reserves: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Concentrated liquidity price state (Uniswap V3/V4 `Swap`).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConcentratedLiquidity {
+    #[prost(string, tag = "1")]
+    pub sqrt_price_x96: ::prost::alloc::string::String,
+    /// in-range liquidity
+    #[prost(string, tag = "2")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub tick: i32,
+}
+/// Liquidity Book active bin (TraderJoe `Swap`).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActiveBin {
+    #[prost(uint32, tag = "1")]
+    pub active_id: u32,
+}
+/// Output of `map_trades`: user trades reconstructed from the swaps of `map_events`, in block order.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trades {
+    #[prost(message, repeated, tag = "1")]
+    pub trades: ::prost::alloc::vec::Vec<Trade>,
+}
+/// Swaps of one transaction chained into a single user trade (e.g. USDC -> WETH -> PEPE): every hop
+/// spends the exact output (token and amount) of the previous hop. A direct swap is a one-hop trade,
+/// so summing trades counts routed volume once.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trade {
+    #[prost(bytes = "vec", tag = "1")]
+    pub transaction_hash: ::prost::alloc::vec::Vec<u8>,
+    /// `block_index` of the first hop
+    #[prost(uint32, tag = "2")]
+    pub block_index: u32,
+    /// `user` of the first hop
+    #[prost(bytes = "vec", tag = "3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    /// input of the first hop
+    #[prost(bytes = "vec", tag = "4")]
+    pub input_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "5")]
+    pub input_amount: ::prost::alloc::string::String,
+    /// output of the last hop
+    #[prost(bytes = "vec", tag = "6")]
+    pub output_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "7")]
+    pub output_amount: ::prost::alloc::string::String,
+    /// token path from `input_token` to `output_token` (one more entry than hops)
+    #[prost(bytes = "vec", repeated, tag = "8")]
+    pub route: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// pool of each hop
+    #[prost(bytes = "vec", repeated, tag = "9")]
+    pub pools: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// protocol of each hop
+    #[prost(enumeration = "Protocol", repeated, tag = "10")]
+    pub protocols: ::prost::alloc::vec::Vec<i32>,
+    /// log ordinal of each hop `Swap`
+    #[prost(uint64, repeated, tag = "11")]
+    pub ordinals: ::prost::alloc::vec::Vec<u64>,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -0,0 +1,121 @@
+// @generated
+// This file is @generated by prost-build.
+/// Allowance changes of the block, sorted by ordinal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Events {
+    #[prost(message, repeated, tag="1")]
+    pub changes: ::prost::alloc::vec::Vec<AllowanceChange>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AllowanceChange {
+    #[prost(bytes="vec", tag="1")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub ordinal: u64,
+    #[prost(enumeration="Source", tag="3")]
+    pub source: i32,
+    /// Permit2 contract (`None` for ERC-20 allowances)
+    #[prost(bytes="vec", optional, tag="4")]
+    pub permit2: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="5")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    /// uint256: new allowance, or the amount spent (`*_TRANSFER_FROM`)
+    #[prost(string, tag="8")]
+    pub amount: ::prost::alloc::string::String,
+    /// Permit2 only (uint48 timestamp)
+    #[prost(uint64, optional, tag="9")]
+    pub expiration: ::core::option::Option<u64>,
+}
+/// Current state of the allowances changed in the block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Allowances {
+    #[prost(message, repeated, tag="1")]
+    pub allowances: ::prost::alloc::vec::Vec<Allowance>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Allowance {
+    /// Permit2 contract (`None` for ERC-20 allowances)
+    #[prost(bytes="vec", optional, tag="1")]
+    pub permit2: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="2")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    /// uint256: last approved amount minus the amount spent since (0 = revoked)
+    #[prost(string, tag="5")]
+    pub amount: ::prost::alloc::string::String,
+    /// Permit2 only (uint48 timestamp)
+    #[prost(uint64, optional, tag="6")]
+    pub expiration: ::core::option::Option<u64>,
+    /// approved amount >= 2^255 (2^159 for Permit2), not decremented by most tokens
+    #[prost(bool, tag="7")]
+    pub unlimited: bool,
+    /// last change of the block
+    #[prost(enumeration="Source", tag="8")]
+    pub source: i32,
+    /// transaction of the last change of the block
+    #[prost(bytes="vec", tag="9")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Source {
+    Unspecified = 0,
+    /// event Approval(address indexed owner, address indexed spender, uint256 value)
+    Approval = 1,
+    /// EIP-2612 permit(owner, spender, value, deadline, v, r, s) or DAI permit(holder, spender, nonce, expiry, allowed, v, r, s)
+    Permit = 2,
+    /// transferFrom(from, to, amount) by the spender, without an Approval event
+    TransferFrom = 3,
+    /// event Approval(address indexed owner, address indexed token, address indexed spender, uint160 amount, uint48 expiration)
+    Permit2Approval = 4,
+    /// event Permit(address indexed owner, address indexed token, address indexed spender, uint160 amount, uint48 expiration, uint48 nonce)
+    Permit2Permit = 5,
+    /// event Lockdown(address indexed owner, address token, address spender): amount = 0
+    Permit2Lockdown = 6,
+    /// Permit2 transferFrom(from, to, amount, token) by the spender
+    Permit2TransferFrom = 7,
+}
+impl Source {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Source::Unspecified => "SOURCE_UNSPECIFIED",
+            Source::Approval => "SOURCE_APPROVAL",
+            Source::Permit => "SOURCE_PERMIT",
+            Source::TransferFrom => "SOURCE_TRANSFER_FROM",
+            Source::Permit2Approval => "SOURCE_PERMIT2_APPROVAL",
+            Source::Permit2Permit => "SOURCE_PERMIT2_PERMIT",
+            Source::Permit2Lockdown => "SOURCE_PERMIT2_LOCKDOWN",
+            Source::Permit2TransferFrom => "SOURCE_PERMIT2_TRANSFER_FROM",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SOURCE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SOURCE_APPROVAL" => Some(Self::Approval),
+            "SOURCE_PERMIT" => Some(Self::Permit),
+            "SOURCE_TRANSFER_FROM" => Some(Self::TransferFrom),
+            "SOURCE_PERMIT2_APPROVAL" => Some(Self::Permit2Approval),
+            "SOURCE_PERMIT2_PERMIT" => Some(Self::Permit2Permit),
+            "SOURCE_PERMIT2_LOCKDOWN" => Some(Self::Permit2Lockdown),
+            "SOURCE_PERMIT2_TRANSFER_FROM" => Some(Self::Permit2TransferFrom),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
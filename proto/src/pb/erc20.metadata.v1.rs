@@ -0,0 +1,26 @@
+// @generated
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Events {
+    #[prost(message, repeated, tag = "1")]
+    pub metadata: ::prost::alloc::vec::Vec<Metadata>,
+}
+/// Metadata of a token contract, fetched once when the contract is first seen in a transfer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Metadata {
+    /// token contract address
+    #[prost(bytes = "vec", tag = "1")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    /// name() -> string (or bytes32)
+    #[prost(string, optional, tag = "2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    /// symbol() -> string (or bytes32)
+    #[prost(string, optional, tag = "3")]
+    pub symbol: ::core::option::Option<::prost::alloc::string::String>,
+    /// decimals() -> uint8, unset when missing or out of range
+    #[prost(int32, optional, tag = "4")]
+    pub decimals: ::core::option::Option<i32>,
+}
+// @@protoc_insertion_point(module)
@@ -188,6 +188,44 @@ pub mod log {
         StethWithdrawalsReceived(super::StethWithdrawalsReceived),
         #[prost(message, tag="120")]
         StethLidoLocatorSet(super::StethLidoLocatorSet),
+        /// ========== DAI / USDS Events (Sky) ==========
+        #[prost(message, tag="130")]
+        UsdsRely(super::UsdsRely),
+        #[prost(message, tag="131")]
+        UsdsDeny(super::UsdsDeny),
+        #[prost(message, tag="132")]
+        UsdsJoin(super::UsdsJoin),
+        #[prost(message, tag="133")]
+        UsdsExit(super::UsdsExit),
+        /// ========== PYUSD Events (Paxos) ==========
+        #[prost(message, tag="140")]
+        PyusdSupplyIncreased(super::PyusdSupplyIncreased),
+        #[prost(message, tag="141")]
+        PyusdSupplyDecreased(super::PyusdSupplyDecreased),
+        #[prost(message, tag="142")]
+        PyusdSupplyControllerSet(super::PyusdSupplyControllerSet),
+        #[prost(message, tag="143")]
+        PyusdAddressFrozen(super::PyusdAddressFrozen),
+        #[prost(message, tag="144")]
+        PyusdAddressUnfrozen(super::PyusdAddressUnfrozen),
+        #[prost(message, tag="145")]
+        PyusdFrozenAddressWiped(super::PyusdFrozenAddressWiped),
+        /// ========== USDe Events (Ethena) ==========
+        #[prost(message, tag="160")]
+        UsdeMinterUpdated(super::UsdeMinterUpdated),
+        #[prost(message, tag="161")]
+        UsdeBlacklistAdded(super::UsdeBlacklistAdded),
+        #[prost(message, tag="162")]
+        UsdeBlacklistRemoved(super::UsdeBlacklistRemoved),
+        #[prost(message, tag="163")]
+        UsdeLockedAmountRedistributed(super::UsdeLockedAmountRedistributed),
+        /// ========== FDUSD Events (First Digital) ==========
+        #[prost(message, tag="170")]
+        FdusdOwnershipTransferred(super::FdusdOwnershipTransferred),
+        #[prost(message, tag="171")]
+        FdusdPaused(super::FdusdPaused),
+        #[prost(message, tag="172")]
+        FdusdUnpaused(super::FdusdUnpaused),
     }
 }
 /// Call metadata (only available on chains with DetailLevel: EXTENDED)
@@ -867,6 +905,174 @@ pub struct StethLidoLocatorSet {
     #[prost(bytes="vec", tag="1")]
     pub lido_locator: ::prost::alloc::vec::Vec<u8>,
 }
+// ============================================
+// DAI / USDS Events (Sky)
+// ============================================
+/// event Rely(address indexed usr), or the DAI `rely(address)` LogNote
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdsRely {
+    #[prost(bytes="vec", tag="1")]
+    pub usr: ::prost::alloc::vec::Vec<u8>,
+}
+/// event Deny(address indexed usr), or the DAI `deny(address)` LogNote
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdsDeny {
+    #[prost(bytes="vec", tag="1")]
+    pub usr: ::prost::alloc::vec::Vec<u8>,
+}
+/// event Join(address indexed caller, address indexed usr, uint256 wad) (UsdsJoin: burns from caller), or the DaiJoin `join(address,uint256)` LogNote
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdsJoin {
+    #[prost(bytes="vec", tag="1")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub usr: ::prost::alloc::vec::Vec<u8>,
+    /// uint256
+    #[prost(string, tag="3")]
+    pub wad: ::prost::alloc::string::String,
+    /// DAI / USDS of the known join adapters, empty otherwise
+    #[prost(bytes="vec", tag="4")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+}
+/// event Exit(address indexed caller, address indexed usr, uint256 wad) (UsdsJoin: mints to usr), or the DaiJoin `exit(address,uint256)` LogNote
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdsExit {
+    #[prost(bytes="vec", tag="1")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub usr: ::prost::alloc::vec::Vec<u8>,
+    /// uint256
+    #[prost(string, tag="3")]
+    pub wad: ::prost::alloc::string::String,
+    /// DAI / USDS of the known join adapters, empty otherwise
+    #[prost(bytes="vec", tag="4")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+}
+// ============================================
+// PYUSD Events (Paxos)
+// ============================================
+/// event SupplyIncreased(address indexed to, uint256 value)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PyusdSupplyIncreased {
+    #[prost(bytes="vec", tag="1")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    /// uint256
+    #[prost(string, tag="2")]
+    pub value: ::prost::alloc::string::String,
+}
+/// event SupplyDecreased(address indexed from, uint256 value)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PyusdSupplyDecreased {
+    #[prost(bytes="vec", tag="1")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    /// uint256
+    #[prost(string, tag="2")]
+    pub value: ::prost::alloc::string::String,
+}
+/// event SupplyControllerSet(address indexed oldSupplyController, address indexed newSupplyController)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PyusdSupplyControllerSet {
+    #[prost(bytes="vec", tag="1")]
+    pub old_supply_controller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub new_supply_controller: ::prost::alloc::vec::Vec<u8>,
+}
+/// event AddressFrozen(address indexed addr)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PyusdAddressFrozen {
+    #[prost(bytes="vec", tag="1")]
+    pub addr: ::prost::alloc::vec::Vec<u8>,
+}
+/// event AddressUnfrozen(address indexed addr)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PyusdAddressUnfrozen {
+    #[prost(bytes="vec", tag="1")]
+    pub addr: ::prost::alloc::vec::Vec<u8>,
+}
+/// event FrozenAddressWiped(address indexed addr)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PyusdFrozenAddressWiped {
+    #[prost(bytes="vec", tag="1")]
+    pub addr: ::prost::alloc::vec::Vec<u8>,
+}
+// ============================================
+// USDe Events (Ethena)
+// ============================================
+/// event MinterUpdated(address indexed newMinter, address indexed oldMinter)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdeMinterUpdated {
+    #[prost(bytes="vec", tag="1")]
+    pub new_minter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub old_minter: ::prost::alloc::vec::Vec<u8>,
+}
+/// event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender) (sUSDe FULL/SOFT_RESTRICTED_STAKER_ROLE only)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdeBlacklistAdded {
+    #[prost(bytes="vec", tag="1")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag="2")]
+    pub full_restriction: bool,
+}
+/// event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender) (sUSDe FULL/SOFT_RESTRICTED_STAKER_ROLE only)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdeBlacklistRemoved {
+    #[prost(bytes="vec", tag="1")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag="2")]
+    pub full_restriction: bool,
+}
+/// event LockedAmountRedistributed(address indexed from, address indexed to, uint256 amount) (sUSDe)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdeLockedAmountRedistributed {
+    #[prost(bytes="vec", tag="1")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    /// uint256
+    #[prost(string, tag="3")]
+    pub amount: ::prost::alloc::string::String,
+}
+// ============================================
+// FDUSD Events (First Digital, FDUSD contract only)
+// ============================================
+/// event OwnershipTransferred(address indexed previousOwner, address indexed newOwner) (the owner mints and burns)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FdusdOwnershipTransferred {
+    #[prost(bytes="vec", tag="1")]
+    pub previous_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub new_owner: ::prost::alloc::vec::Vec<u8>,
+}
+/// event Paused(address account)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FdusdPaused {
+    #[prost(bytes="vec", tag="1")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+}
+/// event Unpaused(address account)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FdusdUnpaused {
+    #[prost(bytes="vec", tag="1")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CallType {
@@ -5,6 +5,18 @@
 pub struct Events {
     #[prost(message, repeated, tag="1")]
     pub balances: ::prost::alloc::vec::Vec<Balance>,
+    /// store-based balances only: RPC reconciliation sample
+    #[prost(message, repeated, tag="2")]
+    pub drifts: ::prost::alloc::vec::Vec<BalanceDrift>,
+    /// balanceOf lookups skipped after retries
+    #[prost(message, repeated, tag="3")]
+    pub failed_lookups: ::prost::alloc::vec::Vec<FailedLookup>,
+    /// stETH only: shares of the holders whose shares moved
+    #[prost(message, repeated, tag="4")]
+    pub shares: ::prost::alloc::vec::Vec<Shares>,
+    /// stETH only: share rate updates of the block
+    #[prost(message, repeated, tag="5")]
+    pub share_rates: ::prost::alloc::vec::Vec<ShareRate>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -33,4 +45,210 @@ pub struct BalanceChange {
     #[prost(bytes="vec", tag="2")]
     pub address: ::prost::alloc::vec::Vec<u8>,
 }
+/// Store-based balance that differs from `balanceOf` at the same block (missed issuer events,
+/// rebasing/fee-on-transfer tokens, or history before the module's start block).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceDrift {
+    #[prost(bytes="vec", optional, tag="1")]
+    pub contract: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="2")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    /// running balance from the store
+    #[prost(string, tag="3")]
+    pub amount: ::prost::alloc::string::String,
+    /// balanceOf(address account) -> uint256
+    #[prost(string, tag="4")]
+    pub rpc_amount: ::prost::alloc::string::String,
+}
+/// Shares of a shares-based token (stETH) holder at the end of the block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Shares {
+    #[prost(bytes="vec", tag="1")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    /// sharesOf(address account) -> uint256
+    #[prost(string, tag="3")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Share rate of a shares-based token (stETH) at the end of the block. The balance of every holder is
+/// `shares * pooled_ether / total_shares` (rounded down), with its last `Shares` row.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShareRate {
+    #[prost(bytes="vec", tag="1")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    /// uint256
+    #[prost(string, tag="2")]
+    pub pooled_ether: ::prost::alloc::string::String,
+    /// uint256
+    #[prost(string, tag="3")]
+    pub total_shares: ::prost::alloc::string::String,
+}
+ `balanceOf` could not be fetched; no `Balance` is emitted for it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedLookup {
+    #[prost(bytes="vec", optional, tag="1")]
+    pub contract: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="2")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="LookupFailure", tag="3")]
+    pub reason: i32,
+    /// last RPC error of `LOOKUP_FAILURE_RPC_ERROR`
+    #[prost(string, tag="4")]
+    pub error: ::prost::alloc::string::String,
+}
+/// Native balance changes of an EXTENDED block in execution (ordinal) order. Changes rolled back by
+/// the chain (non-gas changes of failed transactions and state-reverted calls) are skipped, so the
+/// `new_value - old_value` of an address sum up to its balance.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NativeBalanceChanges {
+    #[prost(message, repeated, tag="1")]
+    pub balance_changes: ::prost::alloc::vec::Vec<NativeBalanceChange>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NativeBalanceChange {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    /// uint256
+    #[prost(string, tag="2")]
+    pub old_value: ::prost::alloc::string::String,
+    /// uint256
+    #[prost(string, tag="3")]
+    pub new_value: ::prost::alloc::string::String,
+    #[prost(enumeration="Reason", tag="4")]
+    pub reason: i32,
+    #[prost(uint64, tag="5")]
+    pub ordinal: u64,
+    /// `None` for block-level changes (rewards, withdrawals) and system calls
+    #[prost(bytes="vec", optional, tag="6")]
+    pub tx_hash: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// index of the transaction in the block
+    #[prost(uint32, optional, tag="7")]
+    pub tx_index: ::core::option::Option<u32>,
+    /// `None` for block-level changes
+    #[prost(uint32, optional, tag="8")]
+    pub call_index: ::core::option::Option<u32>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LookupFailure {
+    Unspecified = 0,
+    /// the call kept failing, even alone in its batch
+    RpcError = 1,
+    /// the call reverted
+    Reverted = 2,
+    /// the response is not a uint256
+    Undecodable = 3,
+}
+impl LookupFailure {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LookupFailure::Unspecified => "LOOKUP_FAILURE_UNSPECIFIED",
+            LookupFailure::RpcError => "LOOKUP_FAILURE_RPC_ERROR",
+            LookupFailure::Reverted => "LOOKUP_FAILURE_REVERTED",
+            LookupFailure::Undecodable => "LOOKUP_FAILURE_UNDECODABLE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LOOKUP_FAILURE_UNSPECIFIED" => Some(Self::Unspecified),
+            "LOOKUP_FAILURE_RPC_ERROR" => Some(Self::RpcError),
+            "LOOKUP_FAILURE_REVERTED" => Some(Self::Reverted),
+            "LOOKUP_FAILURE_UNDECODABLE" => Some(Self::Undecodable),
+            _ => None,
+        }
+    }
+}
+/// Firehose `BalanceChange.Reason` (same values as `native.transfers.v1.Reason`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Reason {
+    Unknown = 0,
+    RewardMineUncle = 1,
+    RewardMineBlock = 2,
+    DaoRefundContract = 3,
+    DaoAdjustBalance = 4,
+    Transfer = 5,
+    GenesisBalance = 6,
+    GasBuy = 7,
+    RewardTransactionFee = 8,
+    GasRefund = 9,
+    TouchAccount = 10,
+    SuicideRefund = 11,
+    CallBalanceOverride = 12,
+    SuicideWithdraw = 13,
+    RewardFeeReset = 14,
+    Burn = 15,
+    Withdrawal = 16,
+    RewardBlobFee = 17,
+    IncreaseMint = 18,
+    Revert = 19,
+}
+impl Reason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Reason::Unknown => "REASON_UNKNOWN",
+            Reason::RewardMineUncle => "REASON_REWARD_MINE_UNCLE",
+            Reason::RewardMineBlock => "REASON_REWARD_MINE_BLOCK",
+            Reason::DaoRefundContract => "REASON_DAO_REFUND_CONTRACT",
+            Reason::DaoAdjustBalance => "REASON_DAO_ADJUST_BALANCE",
+            Reason::Transfer => "REASON_TRANSFER",
+            Reason::GenesisBalance => "REASON_GENESIS_BALANCE",
+            Reason::GasBuy => "REASON_GAS_BUY",
+            Reason::RewardTransactionFee => "REASON_REWARD_TRANSACTION_FEE",
+            Reason::GasRefund => "REASON_GAS_REFUND",
+            Reason::TouchAccount => "REASON_TOUCH_ACCOUNT",
+            Reason::SuicideRefund => "REASON_SUICIDE_REFUND",
+            Reason::CallBalanceOverride => "REASON_CALL_BALANCE_OVERRIDE",
+            Reason::SuicideWithdraw => "REASON_SUICIDE_WITHDRAW",
+            Reason::RewardFeeReset => "REASON_REWARD_FEE_RESET",
+            Reason::Burn => "REASON_BURN",
+            Reason::Withdrawal => "REASON_WITHDRAWAL",
+            Reason::RewardBlobFee => "REASON_REWARD_BLOB_FEE",
+            Reason::IncreaseMint => "REASON_INCREASE_MINT",
+            Reason::Revert => "REASON_REVERT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REASON_UNKNOWN" => Some(Self::Unknown),
+            "REASON_REWARD_MINE_UNCLE" => Some(Self::RewardMineUncle),
+            "REASON_REWARD_MINE_BLOCK" => Some(Self::RewardMineBlock),
+            "REASON_DAO_REFUND_CONTRACT" => Some(Self::DaoRefundContract),
+            "REASON_DAO_ADJUST_BALANCE" => Some(Self::DaoAdjustBalance),
+            "REASON_TRANSFER" => Some(Self::Transfer),
+            "REASON_GENESIS_BALANCE" => Some(Self::GenesisBalance),
+            "REASON_GAS_BUY" => Some(Self::GasBuy),
+            "REASON_REWARD_TRANSACTION_FEE" => Some(Self::RewardTransactionFee),
+            "REASON_GAS_REFUND" => Some(Self::GasRefund),
+            "REASON_TOUCH_ACCOUNT" => Some(Self::TouchAccount),
+            "REASON_SUICIDE_REFUND" => Some(Self::SuicideRefund),
+            "REASON_CALL_BALANCE_OVERRIDE" => Some(Self::CallBalanceOverride),
+            "REASON_SUICIDE_WITHDRAW" => Some(Self::SuicideWithdraw),
+            "REASON_REWARD_FEE_RESET" => Some(Self::RewardFeeReset),
+            "REASON_BURN" => Some(Self::Burn),
+            "REASON_WITHDRAWAL" => Some(Self::Withdrawal),
+            "REASON_REWARD_BLOB_FEE" => Some(Self::RewardBlobFee),
+            "REASON_INCREASE_MINT" => Some(Self::IncreaseMint),
+            "REASON_REVERT" => Some(Self::Revert),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
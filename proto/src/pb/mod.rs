@@ -49,6 +49,13 @@ pub mod dex {
             // @@protoc_insertion_point(dex.foundational_store.v1)
         }
     }
+    pub mod mev {
+        // @@protoc_insertion_point(attribute:dex.mev.v1)
+        pub mod v1 {
+            include!("dex.mev.v1.rs");
+            // @@protoc_insertion_point(dex.mev.v1)
+        }
+    }
     pub mod swaps {
         // @@protoc_insertion_point(attribute:dex.swaps.v1)
         pub mod v1 {
@@ -72,6 +79,20 @@ pub mod erc1155 {
     }
 }
 pub mod erc20 {
+    pub mod allowances {
+        // @@protoc_insertion_point(attribute:erc20.allowances.v1)
+        pub mod v1 {
+            include!("erc20.allowances.v1.rs");
+            // @@protoc_insertion_point(erc20.allowances.v1)
+        }
+    }
+    pub mod metadata {
+        // @@protoc_insertion_point(attribute:erc20.metadata.v1)
+        pub mod v1 {
+            include!("erc20.metadata.v1.rs");
+            // @@protoc_insertion_point(erc20.metadata.v1)
+        }
+    }
     pub mod supply {
         // @@protoc_insertion_point(attribute:erc20.supply.v1)
         pub mod v1 {
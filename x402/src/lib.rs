@@ -1,6 +1,8 @@
+use common::index::block_keys;
 use proto::pb::evm::x402::v1 as pb;
 use substreams::errors::Error;
 use substreams::hex;
+use substreams::pb::sf::substreams::index::v1::Keys;
 use substreams_abis::standard::erc20::events as erc20_events;
 use substreams_abis::tokens::erc20::usdc::fiattoken_v2_2::{events as usdc_events, functions as usdc_functions};
 use substreams_ethereum::pb::eth::v2::{Block, Call, Log, TransactionTrace};
@@ -29,6 +31,12 @@ struct TransferLog {
     amount: String,
 }
 
+/// Block index of `evt_sig` / `evt_addr` keys, used as the `blockFilter` of `map_events`.
+#[substreams::handlers::map]
+pub fn index_events(block: Block) -> Result<Keys, Error> {
+    Ok(block_keys(&block))
+}
+
 #[substreams::handlers::map]
 pub fn map_events(block: Block) -> Result<pb::Events, Error> {
     let mut events = pb::Events::default();